@@ -0,0 +1,20 @@
+//! what the beasts of every level cost per tick, run with `cargo bench`
+
+use beast1984::{bench::tick_cost, common::levels::Level};
+
+/// how many ticks are measured per level
+const TICKS: u32 = 200;
+
+fn main() {
+	let mut level = Some(Level::One);
+	while let Some(current_level) = level {
+		let cost = tick_cost(current_level, TICKS);
+
+		println!(
+			"Level {current_level}: {} common beasts, {} super beasts, {} hatched beasts, {} ticks, {:?} per tick",
+			cost.common_beasts, cost.super_beasts, cost.hatched_beasts, cost.ticks, cost.per_tick,
+		);
+
+		level = current_level.next();
+	}
+}
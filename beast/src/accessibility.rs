@@ -0,0 +1,216 @@
+//! this module contains the text announcements of the accessibility mode for players using screen readers
+
+use std::time::{Duration, Instant};
+
+use crate::{
+	Coord,
+	events::{Entity, EventKind, GameEvent, Subscriber},
+};
+
+/// how long an announcement is shown before the nearest beast is announced again
+const ANNOUNCEMENT_DURATION: Duration = Duration::from_secs(2);
+/// the seconds remaining at which we warn about the time running out
+const TIME_WARNINGS: [u64; 4] = [60, 30, 20, 10];
+
+/// keeps track of what to announce in the status line
+#[derive(Debug, Default)]
+pub struct Announcer {
+	/// the last announcement and when it was made
+	current: Option<(String, Instant)>,
+	/// the last time warning we announced so every warning is only made once per level
+	last_time_warning: Option<u64>,
+	/// the announcements that haven't been handed out to the renderer yet
+	fresh: Vec<String>,
+}
+
+impl Announcer {
+	/// create a new instance of the announcer
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// announce a message
+	pub fn announce(&mut self, message: impl Into<String>) {
+		let message = message.into();
+		self.fresh.push(message.clone());
+		self.current = Some((message, Instant::now()));
+	}
+
+	/// the announcements made since the last call, every announcement is only handed out once
+	pub fn take_new(&mut self) -> Vec<String> {
+		std::mem::take(&mut self.fresh)
+	}
+
+	/// announce the time running out once the seconds remaining pass one of the warnings
+	pub fn time_warning(&mut self, secs_remaining: u64) {
+		if let Some(warning) = TIME_WARNINGS.iter().copied().filter(|warning| secs_remaining <= *warning).min()
+			&& self.last_time_warning != Some(warning)
+		{
+			self.last_time_warning = Some(warning);
+			self.announce(format!("{warning} seconds left"));
+		}
+	}
+
+	/// forget everything for a new level, announcements not handed out yet are kept
+	pub fn reset(&mut self) {
+		self.current = None;
+		self.last_time_warning = None;
+	}
+
+	/// the message for the status line, the nearest beast is announced unless something happened recently
+	pub fn message(&self, player: Coord, beasts: impl Iterator<Item = Coord>) -> String {
+		match &self.current {
+			Some((message, instant)) if instant.elapsed() < ANNOUNCEMENT_DURATION => message.clone(),
+			_ => nearest_beast(player, beasts),
+		}
+	}
+}
+
+impl Subscriber for Announcer {
+	fn notify(&mut self, event: &GameEvent) {
+		let message = match event.kind {
+			EventKind::Killed { entity, .. } => match entity {
+				Entity::CommonBeast => String::from("Beast killed"),
+				Entity::SuperBeast => String::from("Super beast killed"),
+				Entity::Egg => String::from("Egg destroyed"),
+				Entity::HatchedBeast => String::from("Hatched beast killed"),
+				Entity::Player => return,
+			},
+			EventKind::PlayerKilled { lives: 1, .. } => String::from("Life lost, 1 life left"),
+			EventKind::PlayerKilled { lives, .. } => format!("Life lost, {lives} lives left"),
+			EventKind::EggHatching { .. } => String::from("An egg is about to hatch"),
+			EventKind::EggHatched { .. } => String::from("An egg hatched"),
+			_ => return,
+		};
+		self.announce(message);
+	}
+}
+
+/// describe where the nearest beast is relative to the player like `Nearest beast 3 up 5 left`
+pub fn nearest_beast(player: Coord, beasts: impl Iterator<Item = Coord>) -> String {
+	let Some(beast) = beasts.min_by_key(|beast| beast.row.abs_diff(player.row) + beast.column.abs_diff(player.column))
+	else {
+		return String::from("No beasts left");
+	};
+
+	let mut directions = Vec::new();
+	if beast.row < player.row {
+		directions.push(format!("{} up", player.row - beast.row));
+	} else if beast.row > player.row {
+		directions.push(format!("{} down", beast.row - player.row));
+	}
+	if beast.column < player.column {
+		directions.push(format!("{} left", player.column - beast.column));
+	} else if beast.column > player.column {
+		directions.push(format!("{} right", beast.column - player.column));
+	}
+
+	format!("Nearest beast {}", directions.join(" "))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn nearest_beast_test() {
+		let player = Coord { column: 10, row: 10 };
+		let beasts = [Coord { column: 30, row: 2 }, Coord { column: 5, row: 13 }];
+
+		assert_eq!(
+			nearest_beast(player, beasts.into_iter()),
+			"Nearest beast 3 down 5 left",
+			"The closest beast should be described"
+		);
+		assert_eq!(
+			nearest_beast(player, [Coord { column: 12, row: 10 }].into_iter()),
+			"Nearest beast 2 right",
+			"Directions without distance should be left out"
+		);
+		assert_eq!(nearest_beast(player, [].into_iter()), "No beasts left", "An empty board should be announced");
+	}
+
+	#[test]
+	fn announce_test() {
+		let mut announcer = Announcer::new();
+		let player = Coord { column: 0, row: 0 };
+		let beasts = [Coord { column: 0, row: 1 }];
+
+		assert_eq!(
+			announcer.message(player, beasts.into_iter()),
+			"Nearest beast 1 down",
+			"Without events the nearest beast should be announced"
+		);
+		announcer.announce("Beast killed");
+		assert_eq!(announcer.message(player, beasts.into_iter()), "Beast killed", "Events should be announced first");
+		announcer.reset();
+		assert_eq!(
+			announcer.message(player, beasts.into_iter()),
+			"Nearest beast 1 down",
+			"Resetting should drop the event"
+		);
+	}
+
+	#[test]
+	fn take_new_test() {
+		let mut announcer = Announcer::new();
+		announcer.announce("Beast killed");
+		announcer.time_warning(59);
+		announcer.reset();
+
+		assert_eq!(
+			announcer.take_new(),
+			vec![String::from("Beast killed"), String::from("60 seconds left")],
+			"Every announcement should be handed out in order"
+		);
+		assert!(announcer.take_new().is_empty(), "Announcements should only be handed out once");
+	}
+
+	#[test]
+	fn notify_test() {
+		let mut announcer = Announcer::new();
+		let player = Coord { column: 0, row: 0 };
+
+		announcer.notify(&GameEvent {
+			tick: 0,
+			kind: EventKind::PlayerMoved {
+				dir: crate::Dir::Up,
+				position: player,
+			},
+		});
+		assert_eq!(announcer.message(player, [].into_iter()), "No beasts left", "Moving should not be announced");
+		announcer.notify(&GameEvent {
+			tick: 0,
+			kind: EventKind::Killed {
+				entity: Entity::SuperBeast,
+				position: player,
+			},
+		});
+		assert_eq!(announcer.message(player, [].into_iter()), "Super beast killed", "Kills should be announced");
+		announcer.notify(&GameEvent {
+			tick: 0,
+			kind: EventKind::PlayerKilled {
+				by: Entity::CommonBeast,
+				position: player,
+				lives: 4,
+			},
+		});
+		assert_eq!(announcer.message(player, [].into_iter()), "Life lost, 4 lives left", "Lost lives should be announced");
+	}
+
+	#[test]
+	fn time_warning_test() {
+		let mut announcer = Announcer::new();
+		let player = Coord { column: 0, row: 0 };
+
+		announcer.time_warning(90);
+		assert_eq!(announcer.message(player, [].into_iter()), "No beasts left", "There should be no warning early on");
+		announcer.time_warning(59);
+		assert_eq!(announcer.message(player, [].into_iter()), "60 seconds left", "The warning should be announced");
+		announcer.announce("Egg destroyed");
+		announcer.time_warning(58);
+		assert_eq!(announcer.message(player, [].into_iter()), "Egg destroyed", "Every warning should only be made once");
+		announcer.time_warning(9);
+		assert_eq!(announcer.message(player, [].into_iter()), "10 seconds left", "Later warnings should be announced");
+	}
+}
@@ -0,0 +1,223 @@
+//! this module contains how the behaviour of a beast decides where it heads for each move
+
+use rand::seq::SliceRandom;
+
+use crate::{
+	BOARD_HEIGHT, BOARD_WIDTH, Coord, Dir, Tile,
+	beasts::BeastAction,
+	board::Board,
+	common::levels::Behaviour,
+	pathing::{get_next_coord, is_crush_tile},
+};
+
+/// how many tiles ahead of the player an ambushing beast aims
+const AMBUSH_LEAD: usize = 4;
+/// how many ticks a scatter cycle lasts
+const SCATTER_PERIOD: u64 = 60;
+/// how many ticks at the start of a scatter cycle a beast spends heading for its corner
+const SCATTER_TICKS: u64 = 15;
+/// how far away the player has to be before a wandering beast roams around
+const WANDER_DISTANCE: usize = 12;
+
+/// what beasts know about the player when they pick where to go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hunt {
+	/// where the player is
+	pub player_position: Coord,
+	/// the direction the player last moved in
+	pub player_dir: Option<Dir>,
+	/// the number of ticks since the level started
+	pub tick: u64,
+	/// if beasts keep out of the way of blocks the player could squish them with
+	pub danger_aware: bool,
+}
+
+/// where a beast heads for this move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+	/// straight for the player
+	Player,
+	/// for a tile on the board
+	Tile(Coord),
+	/// anywhere
+	Random,
+}
+
+/// the corner of the board closest to a position, that's where scattering beasts retreat to
+pub fn home_corner(position: Coord) -> Coord {
+	Coord {
+		column: if position.column < BOARD_WIDTH / 2 {
+			0
+		} else {
+			BOARD_WIDTH - 1
+		},
+		row: if position.row < BOARD_HEIGHT / 2 {
+			0
+		} else {
+			BOARD_HEIGHT - 1
+		},
+	}
+}
+
+/// pick where a beast with a behaviour heads for this move
+pub fn pick_target(behaviour: Behaviour, position: Coord, home: Coord, hunt: &Hunt) -> Target {
+	let target = match behaviour {
+		Behaviour::Chase => Target::Player,
+		Behaviour::Ambush => {
+			let mut ahead = hunt.player_position;
+			if let Some(dir) = hunt.player_dir {
+				for _ in 0..AMBUSH_LEAD {
+					ahead = get_next_coord(&ahead, &dir).unwrap_or(ahead);
+				}
+			}
+			Target::Tile(ahead)
+		},
+		Behaviour::Scatter if hunt.tick % SCATTER_PERIOD < SCATTER_TICKS => Target::Tile(home),
+		Behaviour::Scatter => Target::Player,
+		Behaviour::Wander => {
+			let distance =
+				position.column.abs_diff(hunt.player_position.column).max(position.row.abs_diff(hunt.player_position.row));
+			if distance > WANDER_DISTANCE {
+				Target::Random
+			} else {
+				Target::Player
+			}
+		},
+	};
+
+	match target {
+		// a beast already standing on its target goes for the player instead
+		Target::Tile(coord) if coord == position || coord == hunt.player_position => Target::Player,
+		_ => target,
+	}
+}
+
+/// move a beast onto the first of the options it can walk onto, eating the player if they stand there
+pub fn step(board: &mut Board, position: &mut Coord, tile: Tile, options: Vec<Coord>) -> BeastAction {
+	for coord in options {
+		match board[&coord] {
+			Tile::Player => {
+				board[&coord] = tile;
+				board[&*position] = Tile::Empty;
+				*position = coord;
+				return BeastAction::PlayerKilled;
+			},
+			Tile::Empty => {
+				board[&coord] = tile;
+				board[&*position] = Tile::Empty;
+				*position = coord;
+				return BeastAction::Moved;
+			},
+			_ => {},
+		}
+	}
+
+	BeastAction::Stayed
+}
+
+/// move the tiles the player could squish a beast on with their next move to the back of the options for danger aware
+/// beasts so they only go there when there is no other way
+pub fn prefer_safe(board: &Board, mut options: Vec<Coord>, beast: Tile, hunt: &Hunt) -> Vec<Coord> {
+	if hunt.danger_aware {
+		options.sort_by_cached_key(|coord| is_crush_tile(board, coord, beast, &hunt.player_position));
+	}
+	options
+}
+
+/// all tiles around a position in random order for beasts that roam around
+pub fn shuffled_neighbors(position: &Coord) -> Vec<Coord> {
+	let mut neighbors = (position.row.saturating_sub(1)..=(position.row + 1).min(BOARD_HEIGHT - 1))
+		.flat_map(|row| {
+			(position.column.saturating_sub(1)..=(position.column + 1).min(BOARD_WIDTH - 1))
+				.map(move |column| Coord { column, row })
+		})
+		.filter(|coord| coord != position)
+		.collect::<Vec<Coord>>();
+	neighbors.shuffle(&mut rand::rng());
+	neighbors
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn hunt(player_dir: Option<Dir>, tick: u64) -> Hunt {
+		Hunt {
+			player_position: Coord { column: 10, row: 10 },
+			player_dir,
+			tick,
+			danger_aware: false,
+		}
+	}
+
+	#[test]
+	fn home_corner_test() {
+		assert_eq!(home_corner(Coord { column: 3, row: 4 }), Coord { column: 0, row: 0 }, "Top left belongs home top left");
+		assert_eq!(
+			home_corner(Coord { column: 40, row: 20 }),
+			Coord {
+				column: BOARD_WIDTH - 1,
+				row: BOARD_HEIGHT - 1
+			},
+			"Bottom right belongs home bottom right"
+		);
+	}
+
+	#[test]
+	fn pick_target_test() {
+		let position = Coord { column: 30, row: 10 };
+		let home = Coord { column: 49, row: 0 };
+
+		assert_eq!(pick_target(Behaviour::Chase, position, home, &hunt(None, 0)), Target::Player, "Chasers chase");
+		assert_eq!(
+			pick_target(Behaviour::Ambush, position, home, &hunt(Some(Dir::Right), 0)),
+			Target::Tile(Coord { column: 14, row: 10 }),
+			"Ambushers should aim ahead of the player"
+		);
+		assert_eq!(
+			pick_target(Behaviour::Ambush, position, home, &hunt(None, 0)),
+			Target::Player,
+			"Ambushers should chase a player that hasn't moved yet"
+		);
+		assert_eq!(
+			pick_target(Behaviour::Scatter, position, home, &hunt(None, 3)),
+			Target::Tile(home),
+			"Scatterers should head home at the start of a cycle"
+		);
+		assert_eq!(
+			pick_target(Behaviour::Scatter, position, home, &hunt(None, SCATTER_TICKS)),
+			Target::Player,
+			"Scatterers should chase for the rest of a cycle"
+		);
+		assert_eq!(
+			pick_target(Behaviour::Wander, position, home, &hunt(None, 0)),
+			Target::Random,
+			"Wanderers should roam while the player is far"
+		);
+		assert_eq!(
+			pick_target(Behaviour::Wander, Coord { column: 12, row: 12 }, home, &hunt(None, 0)),
+			Target::Player,
+			"Wanderers should chase a player close by"
+		);
+	}
+
+	#[test]
+	fn step_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let mut position = Coord { column: 1, row: 1 };
+		board[&position] = Tile::CommonBeast;
+		board[&Coord { column: 2, row: 1 }] = Tile::Block;
+
+		let action = step(
+			&mut board,
+			&mut position,
+			Tile::CommonBeast,
+			vec![Coord { column: 2, row: 1 }, Coord { column: 2, row: 2 }],
+		);
+		assert_eq!(action, BeastAction::Moved, "The beast should move");
+		assert_eq!(position, Coord { column: 2, row: 2 }, "The beast should skip options it can't walk onto");
+		assert_eq!(board[&position], Tile::CommonBeast, "The beast should be on its new tile");
+		assert_eq!(board[&Coord { column: 1, row: 1 }], Tile::Empty, "The old tile should be empty");
+		assert_eq!(shuffled_neighbors(&Coord { column: 0, row: 0 }).len(), 3, "Corners have three neighbors");
+	}
+}
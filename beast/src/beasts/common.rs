@@ -1,6 +1,6 @@
 //! this module contains the Beast trait with a couple default implmentation of helper functions
 
-use std::{cmp::Ordering, collections::HashMap};
+use std::cmp::Ordering;
 
 use crate::{BOARD_HEIGHT, BOARD_WIDTH, Coord, Tile, board::Board};
 
@@ -330,26 +330,6 @@ pub trait Beast {
 
 		result
 	}
-
-	/// return the Chebyshev distance on a 2D board
-	fn heuristic(a: &Coord, b: &Coord) -> i32 {
-		let distance_column = (a.column as i32 - b.column as i32).abs();
-		let distance_row = (a.row as i32 - b.row as i32).abs();
-
-		distance_column.max(distance_row)
-	}
-
-	/// reconstructs the path from start to goal using the came_from map
-	fn reconstruct_path(came_from: &HashMap<Coord, Coord>, mut current: Coord) -> Vec<Coord> {
-		let mut reconstructed_path = vec![current];
-		while let Some(&prev) = came_from.get(&current) {
-			current = prev;
-			reconstructed_path.push(current);
-		}
-		reconstructed_path.reverse();
-
-		reconstructed_path
-	}
 }
 
 #[cfg(test)]
@@ -1105,33 +1085,4 @@ mod test {
 			"Boundary test: Bottom-right corner should properly clamp coordinates and remove duplicates"
 		);
 	}
-
-	#[test]
-	fn heuristic_test() {
-		let a = Coord { column: 3, row: 4 };
-		let b = Coord { column: 6, row: 8 };
-		// max(|6-3|, |8-4|) = max(3, 4) = 4
-		assert_eq!(DummyBeast::heuristic(&a, &b), 4, "We have calculated the heuristic distance between two coordinates");
-
-		let a = Coord { column: 5, row: 5 };
-		let b = Coord { column: 2, row: 7 };
-		// max(|2-5|, |7-5|) = max(3, 2) = 3
-		assert_eq!(DummyBeast::heuristic(&a, &b), 3, "We have calculated the heuristic distance between two coordinates");
-	}
-
-	#[test]
-	fn reconstruct_path_test() {
-		let mut came_from = HashMap::new();
-		let start = Coord { column: 1, row: 1 };
-		let mid1 = Coord { column: 2, row: 1 };
-		let mid2 = Coord { column: 3, row: 2 };
-		let goal = Coord { column: 3, row: 3 };
-
-		came_from.insert(mid1, start);
-		came_from.insert(mid2, mid1);
-		came_from.insert(goal, mid2);
-
-		let path = DummyBeast::reconstruct_path(&came_from, goal);
-		assert_eq!(path, vec![start, mid1, mid2, goal], "We have reconstructed the path from start to goal");
-	}
 }
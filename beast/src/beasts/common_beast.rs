@@ -4,16 +4,45 @@ use rand::seq::SliceRandom;
 
 use crate::{
 	Coord, Tile,
-	beasts::{Beast, BeastAction},
+	beasts::{
+		Beast, BeastAction, Cooldown, Hunt, Target, home_corner, pick_target, prefer_safe, shuffled_neighbors, step,
+	},
 	board::Board,
+	common::levels::Behaviour,
 };
 
 /// the common beast is the simplest beast out there
+#[derive(Debug, Clone)]
 pub struct CommonBeast {
 	pub position: Coord,
+	/// how long the beast has waited since its last move
+	pub cooldown: Cooldown,
+	pub behaviour: Behaviour,
+	home: Coord,
 }
 
 impl CommonBeast {
+	/// give the beast a behaviour, it retreats to the corner closest to where it starts when scattering
+	pub fn with_behaviour(mut self, behaviour: Behaviour) -> Self {
+		self.behaviour = behaviour;
+		self.home = home_corner(self.position);
+		self
+	}
+
+	/// move the common beast towards wherever its behaviour tells it to go
+	pub fn advance_as(&mut self, board: &mut Board, hunt: &Hunt) -> BeastAction {
+		let options = match pick_target(self.behaviour, self.position, self.home, hunt) {
+			Target::Player if !hunt.danger_aware => return self.advance(board, hunt.player_position),
+			Target::Player => {
+				Self::shuffle_movements(Self::get_walkable_coords(board, &self.position, &hunt.player_position, false))
+			},
+			Target::Tile(coord) => Self::shuffle_movements(Self::get_walkable_coords(board, &self.position, &coord, false)),
+			Target::Random => shuffled_neighbors(&self.position),
+		};
+		let options = prefer_safe(board, options, Tile::CommonBeast, hunt);
+		step(board, &mut self.position, Tile::CommonBeast, options)
+	}
+
 	/// a function to shuffle the choices of where to go when two options are equal
 	fn shuffle_movements(mut coords: Vec<Coord>) -> Vec<Coord> {
 		let mut rng = rand::rng();
@@ -27,7 +56,12 @@ impl CommonBeast {
 impl Beast for CommonBeast {
 	/// create a new instance of the common beast
 	fn new(position: Coord) -> Self {
-		Self { position }
+		Self {
+			position,
+			behaviour: Behaviour::Chase,
+			home: home_corner(position),
+			cooldown: Cooldown::default(),
+		}
 	}
 
 	// this is the simplest path finding that I could come up with
@@ -233,4 +267,61 @@ mod tests {
 	fn get_score_test() {
 		assert_eq!(CommonBeast::get_score(), 2, "CommonBeast score should be 6");
 	}
+
+	#[test]
+	fn advance_as_scatter_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let beast_position = Coord { column: 5, row: 5 };
+		let player_position = Coord { column: 10, row: 10 };
+
+		board[&beast_position] = Tile::CommonBeast;
+		board[&player_position] = Tile::Player;
+
+		let mut beast = CommonBeast::new(beast_position).with_behaviour(Behaviour::Scatter);
+		let hunt = Hunt {
+			player_position,
+			player_dir: None,
+			tick: 0,
+			danger_aware: false,
+		};
+		let result = beast.advance_as(&mut board, &hunt);
+
+		assert_eq!(result, BeastAction::Moved, "The beast has moved");
+		assert_eq!(beast.position, Coord { column: 4, row: 4 }, "The beast should retreat to its corner");
+		assert_eq!(board[&beast.position], Tile::CommonBeast, "The beast tile was placed correctly");
+	}
+
+	#[test]
+	fn advance_as_danger_aware_test() {
+		//   10 11 12 13
+		//  9       ├┤
+		// 10 ◀▶░░    ▓▓
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let beast_position = Coord { column: 12, row: 9 };
+		let player_position = Coord { column: 10, row: 10 };
+
+		board[&beast_position] = Tile::CommonBeast;
+		board[&player_position] = Tile::Player;
+		board[&Coord { column: 11, row: 10 }] = Tile::Block;
+		board[&Coord { column: 13, row: 10 }] = Tile::StaticBlock;
+
+		let hunt = Hunt {
+			player_position,
+			player_dir: None,
+			tick: 0,
+			danger_aware: true,
+		};
+
+		// the beast picks between two equally good tiles at random so it has to keep out of the crush line every time
+		for _ in 0..20 {
+			let mut board = board;
+			let mut beast = CommonBeast::new(beast_position);
+			assert_eq!(beast.advance_as(&mut board, &hunt), BeastAction::Moved, "The beast should move");
+			assert_eq!(
+				beast.position,
+				Coord { column: 11, row: 9 },
+				"A danger aware beast should still close in but keep out of the crush line"
+			);
+		}
+	}
 }
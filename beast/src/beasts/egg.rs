@@ -1,6 +1,6 @@
 //! this module contains the logic for eggs ○○ which ae later turned into hatched beasts
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use crate::{Coord, common::levels::LevelConfig};
 
@@ -47,6 +47,17 @@ impl Egg {
 		}
 	}
 
+	/// the seconds left until the egg hatches rounded up so it shows 1 until the very end
+	pub fn secs_until_hatched(&self, level: &LevelConfig) -> u64 {
+		let remaining = level.egg_hatching_time.saturating_sub(self.instant.elapsed());
+		remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0)
+	}
+
+	/// push the hatching back, used while the game is paused
+	pub fn delay(&mut self, duration: Duration) {
+		self.instant += duration;
+	}
+
 	/// killing an egg will give the player this score
 	pub fn get_score() -> u16 {
 		1
@@ -56,7 +67,7 @@ impl Egg {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use std::time::Duration;
+	use crate::common::levels::Level;
 
 	#[test]
 	fn egg_creation_test() {
@@ -81,20 +92,42 @@ mod tests {
 		let mut egg = Egg::new(position, now);
 
 		let level = LevelConfig {
-			blocks: 10,
-			static_blocks: 5,
-			common_beasts: 3,
-			super_beasts: 1,
-			eggs: 4,
 			egg_hatching_time: Duration::from_secs(100),
-			beast_starting_distance: 5,
-			time: Duration::from_secs(300),
-			completion_score: 100,
+			..Level::One.get_config()
 		};
 
 		assert_eq!(egg.hatch(level), HatchingState::Incubating, "The egg is still incubating");
 	}
 
+	#[test]
+	fn egg_delay_test() {
+		let position = Coord { column: 5, row: 10 };
+		let mut egg = Egg::new(position, Instant::now() - Duration::from_secs(120));
+
+		let level = LevelConfig {
+			egg_hatching_time: Duration::from_secs(100),
+			..Level::One.get_config()
+		};
+
+		egg.delay(Duration::from_secs(60));
+		assert_eq!(egg.hatch(level), HatchingState::Incubating, "A delayed egg should not hatch yet");
+	}
+
+	#[test]
+	fn egg_secs_until_hatched_test() {
+		let position = Coord { column: 5, row: 10 };
+		let level = LevelConfig {
+			egg_hatching_time: Duration::from_secs(100),
+			..Level::One.get_config()
+		};
+
+		let egg = Egg::new(position, Instant::now() - Duration::from_secs(30));
+		assert_eq!(egg.secs_until_hatched(&level), 70, "The seconds left should be rounded up");
+
+		let egg = Egg::new(position, Instant::now() - Duration::from_secs(120));
+		assert_eq!(egg.secs_until_hatched(&level), 0, "Eggs past their time have no seconds left");
+	}
+
 	#[test]
 	fn egg_hatch_hatching_test() {
 		let position = Coord { column: 5, row: 10 };
@@ -102,15 +135,8 @@ mod tests {
 		let mut egg = Egg::new(position, past_time);
 
 		let level = LevelConfig {
-			blocks: 10,
-			static_blocks: 5,
-			common_beasts: 3,
-			super_beasts: 1,
-			eggs: 4,
 			egg_hatching_time: Duration::from_secs(100),
-			beast_starting_distance: 5,
-			time: Duration::from_secs(300),
-			completion_score: 100,
+			..Level::One.get_config()
 		};
 
 		assert_eq!(
@@ -138,15 +164,8 @@ mod tests {
 		let mut egg = Egg::new(position, past_time);
 
 		let level = LevelConfig {
-			blocks: 10,
-			static_blocks: 5,
-			common_beasts: 3,
-			super_beasts: 1,
-			eggs: 4,
 			egg_hatching_time: Duration::from_secs(100),
-			beast_starting_distance: 5,
-			time: Duration::from_secs(300),
-			completion_score: 100,
+			..Level::One.get_config()
 		};
 
 		assert_eq!(
@@ -0,0 +1,295 @@
+//! this module contains the flow field the beasts read their next step from instead of each searching for the player
+
+use std::{collections::VecDeque, ops::Index};
+
+use crate::{BOARD_HEIGHT, BOARD_WIDTH, Coord, Dir, Tile, board::Board, pathing::get_end_of_block_chain};
+
+/// the distance of tiles the player can't be reached from
+pub const UNREACHABLE: u16 = u16::MAX;
+
+/// how the beasts reading a flow field get around the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Movement {
+	/// only onto empty tiles like the super beast
+	Walking,
+	/// also onto blocks that can be pushed out of the way like the hatched beast
+	Pushing,
+}
+
+/// the number of steps from every tile of the board to the player in a flat array indexed by `Coord`
+pub struct FlowField {
+	movement: Movement,
+	budget: usize,
+	distances: [u16; BOARD_WIDTH * BOARD_HEIGHT],
+	queue: VecDeque<Coord>,
+}
+
+impl Index<&Coord> for FlowField {
+	type Output = u16;
+
+	fn index(&self, coord: &Coord) -> &Self::Output {
+		&self.distances[coord.row * BOARD_WIDTH + coord.column]
+	}
+}
+
+impl FlowField {
+	/// create an empty field that has to be updated before it's read
+	pub fn new(movement: Movement) -> Self {
+		Self {
+			movement,
+			budget: usize::MAX,
+			distances: [UNREACHABLE; BOARD_WIDTH * BOARD_HEIGHT],
+			queue: VecDeque::with_capacity(BOARD_WIDTH * BOARD_HEIGHT),
+		}
+	}
+
+	/// stop the search after expanding this many tiles so the cost of a tick stays predictable on dense boards, tiles
+	/// further away stay unreachable and beasts there fall back to a greedy move
+	pub fn with_budget(mut self, budget: usize) -> Self {
+		self.budget = budget;
+		self
+	}
+
+	/// compute the distance to the player for every tile with a breadth first search walking backwards from the player
+	pub fn update(&mut self, board: &Board, goal: Coord) {
+		self.distances.fill(UNREACHABLE);
+		self.queue.clear();
+
+		self.distances[goal.row * BOARD_WIDTH + goal.column] = 0;
+		self.queue.push_back(goal);
+
+		let mut expanded = 0;
+		while let Some(current) = self.queue.pop_front() {
+			expanded += 1;
+			if expanded > self.budget {
+				break;
+			}
+
+			let distance = self[&current] + 1;
+			for neighbor in Self::neighbors(&current) {
+				let index = neighbor.row * BOARD_WIDTH + neighbor.column;
+				if self.distances[index] == UNREACHABLE
+					&& matches!(
+						(self.movement, board[&neighbor]),
+						(Movement::Walking, Tile::Empty) | (Movement::Pushing, Tile::Empty | Tile::Block)
+					) && Self::can_enter(board, &neighbor, &current, self.movement)
+				{
+					self.distances[index] = distance;
+					self.queue.push_back(neighbor);
+				}
+			}
+		}
+	}
+
+	/// if a beast moving this way can step from one tile onto its neighbor
+	pub fn can_enter(board: &Board, from: &Coord, to: &Coord, movement: Movement) -> bool {
+		match (movement, board[to]) {
+			(_, Tile::Empty | Tile::Player) => true,
+			(Movement::Pushing, Tile::Block) => Self::get_push_dir(from, to).is_some_and(|dir| {
+				get_end_of_block_chain(board, to, &dir)
+					.is_some_and(|(end_coord, _)| matches!(board[&end_coord], Tile::Empty | Tile::Player))
+			}),
+			_ => false,
+		}
+	}
+
+	/// the direction a block is pushed in when stepping onto it, blocks can't be pushed diagonally
+	pub fn get_push_dir(from: &Coord, to: &Coord) -> Option<Dir> {
+		match (to.column as isize - from.column as isize, to.row as isize - from.row as isize) {
+			(0, -1) => Some(Dir::Up),
+			(1, 0) => Some(Dir::Right),
+			(0, 1) => Some(Dir::Down),
+			(-1, 0) => Some(Dir::Left),
+			_ => None,
+		}
+	}
+
+	/// all tiles around a position (8-directional) that are on the board
+	fn neighbors(position: &Coord) -> impl Iterator<Item = Coord> {
+		let columns = position.column.saturating_sub(1)..=(position.column + 1).min(BOARD_WIDTH - 1);
+		let rows = position.row.saturating_sub(1)..=(position.row + 1).min(BOARD_HEIGHT - 1);
+
+		rows
+			.flat_map(move |row| columns.clone().map(move |column| Coord { column, row }))
+			.filter(move |coord| coord != position)
+	}
+
+	/// the neighbor a beast should step onto to get closer to the player, picking the first of equally close options,
+	/// tiles to avoid are only picked when no other option can be entered
+	pub fn next_step_avoiding(
+		&self,
+		board: &Board,
+		position: &Coord,
+		options: Vec<Coord>,
+		avoid: impl Fn(&Coord) -> bool,
+	) -> Option<Coord> {
+		options
+			.into_iter()
+			.filter(|coord| self[coord] != UNREACHABLE && Self::can_enter(board, position, coord, self.movement))
+			.min_by_key(|coord| (avoid(coord), self[coord]))
+	}
+
+	/// the steps from a position towards the player along the field, at most `length` of them with each step picked
+	/// like `next_step_avoiding` from the options of the tile before it
+	pub fn path_avoiding(
+		&self,
+		board: &Board,
+		position: &Coord,
+		length: usize,
+		options: impl Fn(&Coord) -> Vec<Coord>,
+		avoid: impl Fn(&Coord) -> bool,
+	) -> Vec<Coord> {
+		let mut path = Vec::with_capacity(length);
+		let mut current = *position;
+		while path.len() < length && self[&current] != 0 {
+			let Some(next) = self.next_step_avoiding(board, &current, options(&current), &avoid) else {
+				break;
+			};
+			path.push(next);
+			current = next;
+		}
+		path
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn update_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let player_position = Coord { column: 4, row: 4 };
+		board[&player_position] = Tile::Player;
+
+		// partial wall
+		for row in 0..3 {
+			board[&Coord { column: 2, row }] = Tile::StaticBlock;
+		}
+
+		let mut field = FlowField::new(Movement::Walking);
+		field.update(&board, player_position);
+
+		assert_eq!(field[&player_position], 0, "The player should be zero steps away");
+		assert_eq!(field[&Coord { column: 3, row: 3 }], 1, "Diagonal neighbors should be one step away");
+		assert_eq!(field[&Coord { column: 0, row: 0 }], 5, "The field should count the steps around the wall");
+		assert_eq!(field[&Coord { column: 2, row: 0 }], UNREACHABLE, "Walls should never be reachable");
+	}
+
+	#[test]
+	fn update_enclosed_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let player_position = Coord { column: 4, row: 4 };
+		board[&player_position] = Tile::Player;
+
+		// complete wall
+		for row in 0..BOARD_HEIGHT {
+			board[&Coord { column: 2, row }] = Tile::Block;
+		}
+
+		let mut field = FlowField::new(Movement::Walking);
+		field.update(&board, player_position);
+		assert_eq!(field[&Coord { column: 0, row: 4 }], UNREACHABLE, "Walking beasts can't get through blocks");
+
+		let mut field = FlowField::new(Movement::Pushing);
+		field.update(&board, player_position);
+		assert_eq!(field[&Coord { column: 1, row: 4 }], 3, "Pushing beasts should get through blocks");
+	}
+
+	#[test]
+	fn update_budget_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let player_position = Coord { column: 0, row: 0 };
+		board[&player_position] = Tile::Player;
+
+		let mut field = FlowField::new(Movement::Walking).with_budget(1);
+		field.update(&board, player_position);
+
+		assert_eq!(field[&Coord { column: 1, row: 1 }], 1, "The tiles around the first expanded tile should be reached");
+		assert_eq!(field[&Coord { column: 2, row: 2 }], UNREACHABLE, "Tiles past the budget should stay unreachable");
+	}
+
+	#[test]
+	fn path_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let player_position = Coord { column: 5, row: 0 };
+		board[&player_position] = Tile::Player;
+
+		let mut field = FlowField::new(Movement::Walking);
+		field.update(&board, player_position);
+
+		let options = |coord: &Coord| FlowField::neighbors(coord).collect::<Vec<Coord>>();
+		assert_eq!(
+			field.path_avoiding(&board, &Coord { column: 0, row: 0 }, 3, options, |_| false),
+			vec![
+				Coord { column: 1, row: 0 },
+				Coord { column: 2, row: 0 },
+				Coord { column: 3, row: 0 }
+			],
+			"The path should be cut off at the given length"
+		);
+		assert_eq!(
+			field.path_avoiding(&board, &Coord { column: 3, row: 0 }, 8, options, |_| false),
+			vec![Coord { column: 4, row: 0 }, player_position],
+			"The path should end at the player"
+		);
+	}
+
+	#[test]
+	fn can_enter_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let from = Coord { column: 5, row: 5 };
+		board[&Coord { column: 6, row: 5 }] = Tile::Block;
+		board[&Coord { column: 7, row: 5 }] = Tile::Block;
+		board[&Coord { column: 6, row: 6 }] = Tile::Block;
+		board[&Coord { column: 5, row: 6 }] = Tile::Block;
+		board[&Coord { column: 5, row: 7 }] = Tile::StaticBlock;
+
+		assert!(
+			FlowField::can_enter(&board, &from, &Coord { column: 6, row: 5 }, Movement::Pushing),
+			"Block chains with space behind them can be pushed"
+		);
+		assert!(
+			!FlowField::can_enter(&board, &from, &Coord { column: 6, row: 5 }, Movement::Walking),
+			"Walking beasts can't push blocks"
+		);
+		assert!(
+			!FlowField::can_enter(&board, &from, &Coord { column: 6, row: 6 }, Movement::Pushing),
+			"Blocks can't be pushed diagonally"
+		);
+		assert!(
+			!FlowField::can_enter(&board, &from, &Coord { column: 5, row: 6 }, Movement::Pushing),
+			"Blocks can't be pushed into static blocks"
+		);
+	}
+
+	#[test]
+	fn next_step_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let beast_position = Coord { column: 1, row: 1 };
+		let player_position = Coord { column: 1, row: 5 };
+		board[&beast_position] = Tile::SuperBeast;
+		board[&player_position] = Tile::Player;
+		board[&Coord { column: 1, row: 2 }] = Tile::StaticBlock;
+
+		let mut field = FlowField::new(Movement::Walking);
+		field.update(&board, player_position);
+
+		let options = vec![
+			Coord { column: 1, row: 2 },
+			Coord { column: 0, row: 2 },
+			Coord { column: 2, row: 2 },
+			Coord { column: 1, row: 0 },
+		];
+		assert_eq!(
+			field.next_step_avoiding(&board, &beast_position, options, |_| false),
+			Some(Coord { column: 0, row: 2 }),
+			"The first of the closest tiles that can be entered should be picked"
+		);
+		assert_eq!(
+			field.next_step_avoiding(&board, &beast_position, vec![Coord { column: 1, row: 2 }], |_| false),
+			None,
+			"There is no step when no option can be entered"
+		);
+	}
+}
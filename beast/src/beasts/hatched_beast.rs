@@ -1,20 +1,47 @@
 //! this module contains the logiv for hatched beasts ╬╬
 
-use std::{cmp::Ordering, collections::HashMap};
+use std::{cmp::Ordering, collections::VecDeque};
 
 use crate::{
 	Coord, Dir, Tile,
-	beasts::{Beast, BeastAction},
+	beasts::{Beast, BeastAction, Cooldown, FlowField, Hunt, Movement},
 	board::Board,
-	pathing::{get_end_of_block_chain, get_next_coord},
+	pathing::{get_end_of_block_chain, get_next_coord, is_crush_tile},
 };
 
+/// how many tiles the flow field of the hatched beasts expands at most per tick
+pub const HATCHED_SEARCH_BUDGET: usize = 600;
+/// how many steps of its path a hatched beast remembers
+const PATH_LENGTH: usize = 8;
+/// how far the player can move before a hatched beast looks for a new path
+const REPATH_DISTANCE: usize = 3;
+
 /// the hatched beasts are most advanced in how it finds the player and can move blocks and even squish the player with blocks
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HatchedBeast {
 	pub position: Coord,
+	/// how long the beast has waited since its last move
+	pub cooldown: Cooldown,
+	path: VecDeque<Coord>,
+	path_target: Coord,
 }
 
 impl HatchedBeast {
+	/// if the cached path is used up, blocked by a change on the board, doesn't start next to the beast anymore, the
+	/// player moved too far from where it leads or a danger aware beast would step onto a tile the player can squish it on
+	pub fn needs_path(&self, board: &Board, player_position: Coord, danger_aware: bool) -> bool {
+		let player_distance =
+			self.path_target.column.abs_diff(player_position.column).max(self.path_target.row.abs_diff(player_position.row));
+
+		self.path.is_empty()
+			|| player_distance > REPATH_DISTANCE
+			|| std::iter::once(&self.position).chain(&self.path).zip(&self.path).any(|(from, to)| {
+				from.column.abs_diff(to.column).max(from.row.abs_diff(to.row)) != 1
+					|| !FlowField::can_enter(board, from, to, Movement::Pushing)
+			}) || danger_aware
+			&& self.path.front().is_some_and(|coord| is_crush_tile(board, coord, Tile::HatchedBeast, &player_position))
+	}
+
 	fn get_dir(from_position: Coord, to_position: Coord) -> Dir {
 		match (to_position.column.cmp(&from_position.column), to_position.row.cmp(&from_position.row)) {
 			(Ordering::Equal, Ordering::Greater) => {
@@ -41,110 +68,29 @@ impl HatchedBeast {
 		}
 	}
 
-	fn is_diagonal(from_position: Coord, to_position: Coord) -> bool {
-		from_position.column != to_position.column && from_position.row != to_position.row
+	/// move the hatched beast along a flow field leading to the player, keeping out of the crush lines of the player when
+	/// the beasts are danger aware
+	pub fn advance_as(&mut self, board: &mut Board, field: &FlowField, hunt: &Hunt) -> BeastAction {
+		self.follow(board, field, hunt.player_position, hunt.danger_aware)
 	}
 
-	fn astar_with_block_pushing(&self, board: &Board, player_position: Coord) -> Option<Vec<Coord>> {
-		let start = self.position;
-		let goal = player_position;
-
-		let mut open_set = vec![start];
-		let mut came_from: HashMap<Coord, Coord> = HashMap::new();
-
-		let mut g_score: HashMap<Coord, i32> = HashMap::new();
-		g_score.insert(start, 0);
-
-		let mut f_score: HashMap<Coord, i32> = HashMap::new();
-		f_score.insert(start, Self::heuristic(&start, &goal));
-
-		while !open_set.is_empty() {
-			let current = *open_set.iter().min_by_key(|coord| f_score.get(coord).unwrap_or(&i32::MAX)).unwrap();
-
-			if current == goal {
-				return Some(Self::reconstruct_path(&came_from, current));
-			}
-
-			open_set.retain(|&c| c != current);
-
-			// generate neighbors, including those requiring block pushing
-			for next_coord in Self::get_walkable_coords(board, &current, &player_position, false) {
-				let mut valid_move = false;
-				let mut squishes_player = false;
-
-				match board[&next_coord] {
-					Tile::Empty | Tile::Player => {
-						// direct movement to empty space or player
-						valid_move = true;
-					},
-					Tile::Block => {
-						// check if block can be pushed
-						if !Self::is_diagonal(current, next_coord) {
-							let dir = Self::get_dir(current, next_coord);
-							if let Some((end_coord, _)) = get_end_of_block_chain(board, &next_coord, &dir) {
-								match board[&end_coord] {
-									Tile::Empty => {
-										// block can be pushed into empty space
-										valid_move = true;
-									},
-									Tile::Player => {
-										// block can be pushed to squish player
-										valid_move = true;
-										squishes_player = true;
-									},
-									_ => {
-										// block can't be pushed (hits obstacle)
-										valid_move = false;
-									},
-								}
-							}
-						} else {
-							// block move on diagonals is not allowed
-							valid_move = false;
-						}
-					},
-					_ => {
-						// not a valid move
-						valid_move = false;
-					},
-				}
-
-				if valid_move {
-					let tentative_g_score = g_score.get(&current).unwrap_or(&i32::MAX) + 1;
-
-					if tentative_g_score < *g_score.get(&next_coord).unwrap_or(&i32::MAX) {
-						came_from.insert(next_coord, current);
-						g_score.insert(next_coord, tentative_g_score);
-
-						// if move squishes player, prioritize it by reducing heuristic
-						let h_score = if squishes_player {
-							Self::heuristic(&next_coord, &goal) - 10 // prioritize squishing
-						} else {
-							Self::heuristic(&next_coord, &goal)
-						};
-
-						f_score.insert(next_coord, tentative_g_score + h_score);
-
-						if !open_set.contains(&next_coord) {
-							open_set.push(next_coord);
-						}
-					}
-				}
-			}
-		}
-
-		None
+	/// move the hatched beast one step along a flow field leading to the player
+	pub fn advance_with(&mut self, board: &mut Board, field: &FlowField, player_position: Coord) -> BeastAction {
+		self.follow(board, field, player_position, false)
 	}
-}
 
-impl Beast for HatchedBeast {
-	/// create a new instance of hatched beast
-	fn new(position: Coord) -> Self {
-		Self { position }
-	}
+	/// kill or squish the player when in reach or else follow the path, danger aware beasts only plan their path over
+	/// tiles the player could squish them on next move when there is no other way closer
+	fn follow(
+		&mut self,
+		board: &mut Board,
+		field: &FlowField,
+		player_position: Coord,
+		danger_aware: bool,
+	) -> BeastAction {
+		let in_danger =
+			|board: &Board, coord: &Coord| danger_aware && is_crush_tile(board, coord, Tile::HatchedBeast, &player_position);
 
-	/// call this method to move the hatched beast per tick
-	fn advance(&mut self, board: &mut Board, player_position: Coord) -> BeastAction {
 		// 1. check if player can be killed
 		for next_coord in Self::get_walkable_coords(board, &self.position, &player_position, true) {
 			if board[&next_coord] == Tile::Player {
@@ -175,62 +121,71 @@ impl Beast for HatchedBeast {
 			}
 		}
 
-		// 3. try to find a path using A* that considers block pushing
-		if let Some(path) = self.astar_with_block_pushing(board, player_position) {
-			if path.len() > 1 {
-				// the first item is our own position
-				let next_step = path[1];
-
-				match board[&next_step] {
-					Tile::Player => {
-						// this code path should not be hit since we check for it in step 1
-						board[&next_step] = Tile::HatchedBeast;
-						board[&self.position] = Tile::Empty;
-						self.position = next_step;
-						return BeastAction::PlayerKilled;
-					},
-					Tile::Empty => {
-						board[&next_step] = Tile::HatchedBeast;
-						board[&self.position] = Tile::Empty;
-						self.position = next_step;
-						return BeastAction::Moved;
-					},
-					Tile::Block => {
-						let dir = Self::get_dir(self.position, next_step);
-						if let Some((end_coord, _)) = get_end_of_block_chain(board, &next_step, &dir) {
-							match board[&end_coord] {
-								Tile::Empty => {
-									board[&self.position] = Tile::Empty;
-									board[&next_step] = Tile::HatchedBeast;
-									board[&end_coord] = Tile::Block;
-									self.position = next_step;
-									return BeastAction::Moved;
-								},
-								Tile::Player => {
-									if get_next_coord(&end_coord, &dir)
-										.is_none_or(|coord| board[&coord] == Tile::Block || board[&coord] == Tile::StaticBlock)
-									{
-										// this code path should also not be hit since we check for it in step 2
-										board[&self.position] = Tile::Empty;
-										board[&next_step] = Tile::HatchedBeast;
-										board[&end_coord] = Tile::Block;
-										self.position = next_step;
-										return BeastAction::PlayerKilled;
-									}
-								},
-								_ => {},
-							}
+		// 3. follow the cached path or a new one from the flow field which considers block pushing
+		if self.needs_path(board, player_position, danger_aware) {
+			self.path = field
+				.path_avoiding(
+					board,
+					&self.position,
+					PATH_LENGTH,
+					|coord| Self::get_walkable_coords(board, coord, &player_position, false),
+					|coord| in_danger(board, coord),
+				)
+				.into();
+			self.path_target = player_position;
+		}
+		if let Some(next_step) = self.path.pop_front() {
+			match board[&next_step] {
+				Tile::Player => {
+					// this code path should not be hit since we check for it in step 1
+					board[&next_step] = Tile::HatchedBeast;
+					board[&self.position] = Tile::Empty;
+					self.position = next_step;
+					return BeastAction::PlayerKilled;
+				},
+				Tile::Empty => {
+					board[&next_step] = Tile::HatchedBeast;
+					board[&self.position] = Tile::Empty;
+					self.position = next_step;
+					return BeastAction::Moved;
+				},
+				Tile::Block => {
+					let dir = Self::get_dir(self.position, next_step);
+					if let Some((end_coord, _)) = get_end_of_block_chain(board, &next_step, &dir) {
+						match board[&end_coord] {
+							Tile::Empty => {
+								board[&self.position] = Tile::Empty;
+								board[&next_step] = Tile::HatchedBeast;
+								board[&end_coord] = Tile::Block;
+								self.position = next_step;
+								return BeastAction::Moved;
+							},
+							Tile::Player
+								if get_next_coord(&end_coord, &dir)
+									.is_none_or(|coord| board[&coord] == Tile::Block || board[&coord] == Tile::StaticBlock) =>
+							{
+								// this code path should also not be hit since we check for it in step 2
+								board[&self.position] = Tile::Empty;
+								board[&next_step] = Tile::HatchedBeast;
+								board[&end_coord] = Tile::Block;
+								self.position = next_step;
+								return BeastAction::PlayerKilled;
+							},
+							_ => {},
 						}
-					},
-					_ => {
-						// MAYBE: squish other beasts? I don't like the idea of it right now
-					},
-				}
+					}
+				},
+				_ => {
+					// MAYBE: squish other beasts? I don't like the idea of it right now
+				},
 			}
+			self.path.clear();
 		}
 
 		// 4. when there is no path we at least still go towards the player
-		for neighbor in Self::get_walkable_coords(board, &self.position, &player_position, true) {
+		let mut neighbors = Self::get_walkable_coords(board, &self.position, &player_position, true);
+		neighbors.sort_by_cached_key(|coord| in_danger(board, coord));
+		for neighbor in neighbors {
 			match board[&neighbor] {
 				Tile::Player => {
 					// this code path should not be hit since we check for it in step 1
@@ -251,6 +206,27 @@ impl Beast for HatchedBeast {
 
 		BeastAction::Stayed
 	}
+}
+
+impl Beast for HatchedBeast {
+	/// create a new instance of hatched beast
+	fn new(position: Coord) -> Self {
+		Self {
+			position,
+			path: VecDeque::new(),
+			path_target: position,
+			cooldown: Cooldown::default(),
+		}
+	}
+
+	/// call this method to move the hatched beast per tick
+	fn advance(&mut self, board: &mut Board, player_position: Coord) -> BeastAction {
+		let mut field = FlowField::new(Movement::Pushing).with_budget(HATCHED_SEARCH_BUDGET);
+		if self.needs_path(board, player_position, false) {
+			field.update(board, player_position);
+		}
+		self.advance_with(board, &field, player_position)
+	}
 
 	/// the score killing the hatched beast will yield
 	fn get_score() -> u16 {
@@ -1288,4 +1264,104 @@ mod tests {
 		);
 		assert_eq!(board[&beast.position], Tile::HatchedBeast, "The beast tile is in the correct position on the board");
 	}
+
+	#[test]
+	fn needs_path_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let beast_position = Coord { column: 0, row: 10 };
+		let player_position = Coord { column: 20, row: 10 };
+		board[&beast_position] = Tile::HatchedBeast;
+		board[&player_position] = Tile::Player;
+
+		let mut beast = HatchedBeast::new(beast_position);
+		assert!(beast.needs_path(&board, player_position, false), "A new beast has no path yet");
+
+		let mut field = FlowField::new(Movement::Pushing);
+		field.update(&board, player_position);
+		assert_eq!(beast.advance_with(&mut board, &field, player_position), BeastAction::Moved, "The beast should move");
+		assert_eq!(beast.path.len(), PATH_LENGTH - 1, "The rest of the path should be cached");
+		assert!(!beast.needs_path(&board, player_position, false), "The cached path should be reused");
+
+		board[&Coord { column: 5, row: 20 }] = Tile::StaticBlock;
+		assert!(!beast.needs_path(&board, player_position, false), "Changes away from the path should not matter");
+
+		assert!(
+			beast.needs_path(&board, Coord { column: 20, row: 15 }, false),
+			"The path should be recomputed when the player moved far"
+		);
+
+		board[&beast.path[1]] = Tile::StaticBlock;
+		assert!(beast.needs_path(&board, player_position, false), "The path should be recomputed when it is blocked");
+	}
+
+	#[test]
+	fn advance_as_danger_aware_test() {
+		//   10 11 12 13
+		//  9         ╬╬
+		// 10 ◀▶░░    ▓▓
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let beast_position = Coord { column: 13, row: 9 };
+		let player_position = Coord { column: 10, row: 10 };
+
+		board[&beast_position] = Tile::HatchedBeast;
+		board[&player_position] = Tile::Player;
+		board[&Coord { column: 11, row: 10 }] = Tile::Block;
+		board[&Coord { column: 13, row: 10 }] = Tile::StaticBlock;
+
+		let mut field = FlowField::new(Movement::Pushing);
+		field.update(&board, player_position);
+		let mut hunt = Hunt {
+			player_position,
+			player_dir: None,
+			tick: 0,
+			danger_aware: false,
+		};
+
+		let mut beast = HatchedBeast::new(beast_position);
+		let mut reckless_board = board;
+		beast.advance_as(&mut reckless_board, &field, &hunt);
+		assert_eq!(beast.position, Coord { column: 12, row: 10 }, "The beast should walk straight into the crush line");
+
+		hunt.danger_aware = true;
+		let mut beast = HatchedBeast::new(beast_position);
+		beast.advance_as(&mut board, &field, &hunt);
+		assert_eq!(
+			beast.position,
+			Coord { column: 12, row: 9 },
+			"A danger aware beast should still close in but keep out of the crush line"
+		);
+		assert!(
+			!beast.path.iter().any(|coord| is_crush_tile(&board, coord, Tile::HatchedBeast, &player_position)),
+			"The planned path should keep out of the crush line"
+		);
+	}
+
+	#[test]
+	fn needs_path_danger_aware_test() {
+		//   10 11 12 13 14
+		// 10 ◀▶░░
+		// 11          ╬╬
+		// 12             ╬╬
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let beast_position = Coord { column: 14, row: 12 };
+		let player_position = Coord { column: 10, row: 10 };
+		board[&beast_position] = Tile::HatchedBeast;
+		board[&player_position] = Tile::Player;
+		board[&Coord { column: 11, row: 10 }] = Tile::Block;
+
+		let mut field = FlowField::new(Movement::Pushing);
+		field.update(&board, player_position);
+		let mut beast = HatchedBeast::new(beast_position);
+		beast.advance_with(&mut board, &field, player_position);
+		assert_eq!(beast.path.front(), Some(&Coord { column: 12, row: 10 }), "The path should lead next to the block");
+		assert!(!beast.needs_path(&board, player_position, true), "A safe path should be reused");
+
+		// the next step turns into a crush tile without blocking the path or the player moving
+		board[&Coord { column: 13, row: 10 }] = Tile::StaticBlock;
+		assert!(!beast.needs_path(&board, player_position, false), "A beast that isn't danger aware keeps its path");
+		assert!(
+			beast.needs_path(&board, player_position, true),
+			"A danger aware beast should look for a new path when its next step is a crush tile"
+		);
+	}
 }
@@ -14,3 +14,15 @@ pub use hatched_beast::*;
 
 pub mod super_beast;
 pub use super_beast::*;
+
+pub mod schedule;
+pub use schedule::*;
+
+pub mod flow_field;
+pub use flow_field::*;
+
+pub mod moves;
+pub use moves::*;
+
+pub mod behaviour;
+pub use behaviour::*;
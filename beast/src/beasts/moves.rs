@@ -0,0 +1,217 @@
+//! this module contains the moves beasts plan against the board at the start of a tick and how they are resolved so the
+//! outcome of a tick doesn't depend on the order the beasts are stored in
+
+use std::collections::HashSet;
+
+use crate::{
+	Coord, Tile,
+	beasts::{FlowField, Mover},
+	board::Board,
+	pathing::{get_end_of_block_chain, get_next_coord},
+};
+
+/// a move a beast planned against the board as it was at the start of the tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+	/// the kind of beast moving
+	pub mover: Mover,
+	/// where the beast stands
+	pub from: Coord,
+	/// where the beast wants to go
+	pub to: Coord,
+	/// where the chain of blocks the beast pushes ends up
+	pub push_end: Option<Coord>,
+}
+
+impl Move {
+	/// plan a move by letting a beast advance on a scratch copy of the snapshot, `advance` returns where the beast went
+	/// and the scratch board is put back the way it was for the next beast
+	pub fn plan(
+		mover: Mover,
+		from: Coord,
+		scratch: &mut Board,
+		snapshot: &Board,
+		advance: impl FnOnce(&mut Board) -> Coord,
+	) -> Option<Self> {
+		let to = advance(scratch);
+		let push_end = if snapshot[&to] == Tile::Block {
+			FlowField::get_push_dir(&from, &to)
+				.and_then(|dir| get_end_of_block_chain(snapshot, &to, &dir))
+				.map(|(end_coord, _)| end_coord)
+		} else {
+			None
+		};
+
+		for coord in [Some(from), Some(to), push_end].into_iter().flatten() {
+			scratch[&coord] = snapshot[&coord];
+		}
+
+		(to != from).then_some(Self {
+			mover,
+			from,
+			to,
+			push_end,
+		})
+	}
+
+	/// every tile the move needs, that is the tile the beast moves onto and all tiles of the block chain it pushes
+	pub fn claims(&self) -> Vec<Coord> {
+		let mut claims = vec![self.to];
+		if let Some(push_end) = self.push_end
+			&& let Some(dir) = FlowField::get_push_dir(&self.from, &self.to)
+		{
+			let mut coord = self.to;
+			while coord != push_end {
+				let Some(next_coord) = get_next_coord(&coord, &dir) else {
+					break;
+				};
+				claims.push(next_coord);
+				coord = next_coord;
+			}
+		}
+		claims
+	}
+
+	/// if the move ends with the player eaten or squished
+	pub fn kills(&self, player_position: Coord) -> bool {
+		self.to == player_position || self.push_end == Some(player_position)
+	}
+
+	/// carry out the move on the board
+	pub fn apply(&self, board: &mut Board) {
+		board[&self.from] = Tile::Empty;
+		board[&self.to] = self.mover.tile();
+		if let Some(push_end) = self.push_end {
+			board[&push_end] = Tile::Block;
+		}
+	}
+}
+
+/// pick the moves that can happen together, when two moves need the same tile the beast further up and then further
+/// left wins and the other beast stays where it is
+pub fn resolve(mut moves: Vec<Move>) -> Vec<Move> {
+	moves.sort_by_key(|planned| (planned.from.row, planned.from.column));
+
+	let mut claimed = HashSet::new();
+	moves
+		.into_iter()
+		.filter(|planned| {
+			let claims = planned.claims();
+			if claims.iter().any(|coord| claimed.contains(coord)) {
+				false
+			} else {
+				claimed.extend(claims);
+				true
+			}
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{BOARD_HEIGHT, BOARD_WIDTH};
+
+	#[test]
+	fn plan_test() {
+		let mut snapshot = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let from = Coord { column: 1, row: 1 };
+		let to = Coord { column: 2, row: 1 };
+		snapshot[&from] = Tile::HatchedBeast;
+		snapshot[&to] = Tile::Block;
+		snapshot[&Coord { column: 3, row: 1 }] = Tile::Block;
+		let mut scratch = snapshot;
+
+		let planned = Move::plan(Mover::Hatched, from, &mut scratch, &snapshot, |board| {
+			board[&from] = Tile::Empty;
+			board[&to] = Tile::HatchedBeast;
+			board[&Coord { column: 4, row: 1 }] = Tile::Block;
+			to
+		});
+
+		assert_eq!(
+			planned,
+			Some(Move {
+				mover: Mover::Hatched,
+				from,
+				to,
+				push_end: Some(Coord { column: 4, row: 1 }),
+			}),
+			"The push should be part of the planned move"
+		);
+		assert_eq!(scratch.buffer, snapshot.buffer, "The scratch board should be put back");
+		assert_eq!(
+			Move::plan(Mover::Hatched, from, &mut scratch, &snapshot, |_| from),
+			None,
+			"Beasts that stay don't move"
+		);
+	}
+
+	#[test]
+	fn claims_test() {
+		let planned = Move {
+			mover: Mover::Hatched,
+			from: Coord { column: 1, row: 1 },
+			to: Coord { column: 1, row: 2 },
+			push_end: Some(Coord { column: 1, row: 4 }),
+		};
+
+		assert_eq!(
+			planned.claims(),
+			vec![
+				Coord { column: 1, row: 2 },
+				Coord { column: 1, row: 3 },
+				Coord { column: 1, row: 4 }
+			],
+			"A push should claim the whole block chain"
+		);
+		assert!(planned.kills(Coord { column: 1, row: 4 }), "Squishing the player should kill them");
+		assert!(!planned.kills(Coord { column: 1, row: 5 }), "Players behind the chain are safe");
+	}
+
+	#[test]
+	fn resolve_test() {
+		let target = Coord { column: 5, row: 5 };
+		let upper = Move {
+			mover: Mover::Common,
+			from: Coord { column: 6, row: 4 },
+			to: target,
+			push_end: None,
+		};
+		let lower = Move {
+			mover: Mover::Super,
+			from: Coord { column: 4, row: 6 },
+			to: target,
+			push_end: None,
+		};
+		let elsewhere = Move {
+			mover: Mover::Common,
+			from: Coord { column: 0, row: 9 },
+			to: Coord { column: 0, row: 8 },
+			push_end: None,
+		};
+
+		assert_eq!(resolve(vec![lower, upper, elsewhere]), vec![upper, elsewhere], "The upper beast should win the tile");
+		assert_eq!(
+			resolve(vec![elsewhere, upper, lower]),
+			vec![upper, elsewhere],
+			"The order of the moves should not matter"
+		);
+	}
+
+	#[test]
+	fn apply_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let planned = Move {
+			mover: Mover::Super,
+			from: Coord { column: 1, row: 1 },
+			to: Coord { column: 2, row: 2 },
+			push_end: None,
+		};
+		board[&planned.from] = Tile::SuperBeast;
+		planned.apply(&mut board);
+
+		assert_eq!(board[&planned.from], Tile::Empty, "The beast should leave its tile");
+		assert_eq!(board[&planned.to], Tile::SuperBeast, "The beast should arrive on its new tile");
+	}
+}
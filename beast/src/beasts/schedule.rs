@@ -0,0 +1,139 @@
+//! this module contains the schedule that counts the ticks of a level and the cooldown every beast moves on so each
+//! kind of beast can move at its own speed
+
+use crate::{Tile, common::levels::LevelConfig};
+
+/// the kinds of beasts that move on their own schedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mover {
+	Common,
+	Super,
+	Hatched,
+}
+
+impl Mover {
+	/// the tile this kind of beast stands on
+	pub fn tile(&self) -> Tile {
+		match self {
+			Self::Common => Tile::CommonBeast,
+			Self::Super => Tile::SuperBeast,
+			Self::Hatched => Tile::HatchedBeast,
+		}
+	}
+}
+
+/// counts the ticks of a level to know which beasts move next
+#[derive(Debug, Default)]
+pub struct Schedule {
+	tick: u64,
+}
+
+impl Schedule {
+	/// create a new schedule at the start of a level
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// start counting from the beginning for a new level
+	pub fn reset(&mut self) {
+		self.tick = 0;
+	}
+
+	/// the number of ticks since the level started
+	pub fn ticks(&self) -> u64 {
+		self.tick
+	}
+
+	/// move on to the next tick
+	pub fn tick(&mut self) {
+		self.tick += 1;
+	}
+
+	/// every how many ticks a kind of beast moves, at half speed they wait twice as long between moves
+	pub fn interval(mover: Mover, config: &LevelConfig, half_speed: bool) -> u64 {
+		let interval = match mover {
+			Mover::Common => config.common_move_interval,
+			Mover::Super => config.super_move_interval,
+			Mover::Hatched => config.hatched_move_interval,
+		};
+		u64::from(interval.max(1)) * if half_speed { 2 } else { 1 }
+	}
+}
+
+/// counts the ticks a single beast waits between its moves so a beast that hatches mid level waits a full interval
+/// before its first move instead of moving with the others
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cooldown {
+	waited: u64,
+}
+
+impl Cooldown {
+	/// if the beast moves on the next tick
+	pub fn is_due(&self, interval: u64) -> bool {
+		self.waited + 1 >= interval
+	}
+
+	/// count one tick, returns if the beast moves on it and starts waiting again
+	pub fn tick(&mut self, interval: u64) -> bool {
+		if self.is_due(interval) {
+			self.waited = 0;
+			true
+		} else {
+			self.waited += 1;
+			false
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::common::levels::Level;
+
+	fn moves(mover: Mover, half_speed: bool) -> Vec<u64> {
+		let interval = Schedule::interval(mover, &Level::One.get_config(), half_speed);
+		let mut cooldown = Cooldown::default();
+		(0..12).filter(|_| cooldown.tick(interval)).collect()
+	}
+
+	#[test]
+	fn interval_test() {
+		assert_eq!(
+			Schedule::interval(Mover::Common, &Level::One.get_config(), false),
+			5,
+			"Common beasts should start at the 5 ticks all beasts moved on before they got their own speed"
+		);
+		assert_eq!(moves(Mover::Common, false), vec![4, 9], "Common beasts should move every 5 ticks on level one");
+		assert_eq!(moves(Mover::Super, false), vec![4, 9], "Super beasts should move every 5 ticks on level one");
+		assert_eq!(moves(Mover::Hatched, false), vec![4, 9], "Hatched beasts should move every 5 ticks on level one");
+		assert_eq!(moves(Mover::Hatched, true), vec![9], "Slow beasts should wait twice as long");
+		assert_eq!(
+			Schedule::interval(Mover::Hatched, &Level::Ten.get_config(), false),
+			2,
+			"Beasts should move faster on later levels"
+		);
+	}
+
+	#[test]
+	fn cooldown_test() {
+		let mut early = Cooldown::default();
+		for _ in 0..3 {
+			early.tick(4);
+		}
+		let mut hatched = Cooldown::default();
+
+		assert!(early.tick(4), "A beast should move once its interval is up");
+		assert!(!hatched.tick(4), "A beast that just hatched should wait for its own interval");
+		assert!(!early.is_due(4), "A beast should start waiting again after it moved");
+	}
+
+	#[test]
+	fn reset_test() {
+		let mut schedule = Schedule::new();
+		schedule.tick();
+		schedule.tick();
+		schedule.reset();
+		schedule.tick();
+		assert_eq!(schedule.ticks(), 1, "The schedule should start over");
+	}
+}
@@ -1,104 +1,95 @@
 //! this module contains the super beast ╟╢ logic
 
-use std::collections::HashMap;
-
 use crate::{
 	Coord, Tile,
-	beasts::{Beast, BeastAction},
+	beasts::{
+		Beast, BeastAction, Cooldown, FlowField, Hunt, Movement, Target, home_corner, pick_target, prefer_safe,
+		shuffled_neighbors, step,
+	},
 	board::Board,
+	common::levels::Behaviour,
+	pathing::is_crush_tile,
 };
 
 /// the super beast is more advanced than the common beast in how it finds the player
+#[derive(Debug, Clone)]
 pub struct SuperBeast {
 	pub position: Coord,
+	/// how long the beast has waited since its last move
+	pub cooldown: Cooldown,
+	pub behaviour: Behaviour,
+	home: Coord,
 }
 
 impl SuperBeast {
-	fn astar(board: &Board, start: Coord, goal: &Coord) -> Option<Vec<Coord>> {
-		let mut open_set = vec![start];
-
-		let mut came_from: HashMap<Coord, Coord> = HashMap::new();
-
-		let mut g_score: HashMap<Coord, i32> = HashMap::new();
-		g_score.insert(start, 0);
-
-		let mut f_score: HashMap<Coord, i32> = HashMap::new();
-		f_score.insert(start, Self::heuristic(&start, goal));
-
-		while !open_set.is_empty() {
-			let current = *open_set.iter().min_by_key(|coord| f_score.get(coord).unwrap_or(&i32::MAX)).unwrap();
-
-			if current == *goal {
-				return Some(Self::reconstruct_path(&came_from, current));
-			}
-
-			open_set.retain(|&c| c != current);
-
-			for neighbor in Self::get_walkable_coords(board, &current, goal, true) {
-				let tentative_g_score = g_score.get(&current).unwrap_or(&i32::MAX) + 1;
-				if tentative_g_score < *g_score.get(&neighbor).unwrap_or(&i32::MAX) {
-					came_from.insert(neighbor, current);
-					g_score.insert(neighbor, tentative_g_score);
-					f_score.insert(neighbor, tentative_g_score + Self::heuristic(&neighbor, goal));
-					if !open_set.contains(&neighbor) {
-						open_set.push(neighbor);
-					}
-				}
-			}
-		}
-
-		None
+	/// give the beast a behaviour, it retreats to the corner closest to where it starts when scattering
+	pub fn with_behaviour(mut self, behaviour: Behaviour) -> Self {
+		self.behaviour = behaviour;
+		self.home = home_corner(self.position);
+		self
 	}
-}
 
-impl Beast for SuperBeast {
-	/// create a new instance of the super beast
-	fn new(position: Coord) -> Self {
-		Self { position }
+	/// move the super beast along the flow field when chasing or else towards wherever its behaviour tells it to go
+	pub fn advance_as(&mut self, board: &mut Board, field: &FlowField, hunt: &Hunt) -> BeastAction {
+		let options = match pick_target(self.behaviour, self.position, self.home, hunt) {
+			Target::Player if !hunt.danger_aware => return self.advance_with(board, field, hunt.player_position),
+			Target::Player => return self.follow(board, field, hunt.player_position, true),
+			Target::Tile(coord) => Self::get_walkable_coords(board, &self.position, &coord, true),
+			Target::Random => shuffled_neighbors(&self.position),
+		};
+		let options = prefer_safe(board, options, Tile::SuperBeast, hunt);
+		step(board, &mut self.position, Tile::SuperBeast, options)
 	}
 
-	/// call this method to move the super beast per tick
-	fn advance(&mut self, board: &mut Board, player_position: Coord) -> BeastAction {
-		if let Some(path) = Self::astar(board, self.position, &player_position) {
-			if path.len() > 1 {
-				// the first item is our own position
-				let next_step = path[1];
+	/// move the super beast one step along a flow field leading to the player
+	pub fn advance_with(&mut self, board: &mut Board, field: &FlowField, player_position: Coord) -> BeastAction {
+		self.follow(board, field, player_position, false)
+	}
 
-				match board[&next_step] {
+	/// follow the flow field, danger aware beasts only step onto tiles the player could squish them on next move when
+	/// there is no other way closer
+	fn follow(
+		&mut self,
+		board: &mut Board,
+		field: &FlowField,
+		player_position: Coord,
+		danger_aware: bool,
+	) -> BeastAction {
+		let options = Self::get_walkable_coords(board, &self.position, &player_position, false);
+		if let Some(next_step) = field.next_step_avoiding(board, &self.position, options, |coord| {
+			danger_aware && is_crush_tile(board, coord, Tile::SuperBeast, &player_position)
+		}) {
+			match board[&next_step] {
+				Tile::Player => {
+					board[&next_step] = Tile::SuperBeast;
+					board[&self.position] = Tile::Empty;
+					self.position = next_step;
+					return BeastAction::PlayerKilled;
+				},
+				Tile::Empty => {
+					board[&next_step] = Tile::SuperBeast;
+					board[&self.position] = Tile::Empty;
+					self.position = next_step;
+					return BeastAction::Moved;
+				},
+				_ => {},
+			}
+		} else {
+			// when there is no path we at least still go towards the player
+			for neighbor in Self::get_walkable_coords(board, &self.position, &player_position, true) {
+				match board[&neighbor] {
 					Tile::Player => {
-						board[&next_step] = Tile::SuperBeast;
+						board[&neighbor] = Tile::SuperBeast;
 						board[&self.position] = Tile::Empty;
-						self.position = next_step;
+						self.position = neighbor;
 						return BeastAction::PlayerKilled;
 					},
 					Tile::Empty => {
-						board[&next_step] = Tile::SuperBeast;
+						board[&neighbor] = Tile::SuperBeast;
 						board[&self.position] = Tile::Empty;
-						self.position = next_step;
+						self.position = neighbor;
 						return BeastAction::Moved;
 					},
-					_ => {},
-				}
-			}
-		} else {
-			// when there is no path we at least still go towards the player
-			for neighbor in Self::get_walkable_coords(board, &self.position, &player_position, true) {
-				match board[&neighbor] {
-					Tile::Empty | Tile::Player => match board[&neighbor] {
-						Tile::Player => {
-							board[&neighbor] = Tile::SuperBeast;
-							board[&self.position] = Tile::Empty;
-							self.position = neighbor;
-							return BeastAction::PlayerKilled;
-						},
-						Tile::Empty => {
-							board[&neighbor] = Tile::SuperBeast;
-							board[&self.position] = Tile::Empty;
-							self.position = neighbor;
-							return BeastAction::Moved;
-						},
-						_ => {},
-					},
 					Tile::Block
 					| Tile::StaticBlock
 					| Tile::CommonBeast
@@ -112,6 +103,25 @@ impl Beast for SuperBeast {
 
 		BeastAction::Stayed
 	}
+}
+
+impl Beast for SuperBeast {
+	/// create a new instance of the super beast
+	fn new(position: Coord) -> Self {
+		Self {
+			position,
+			behaviour: Behaviour::Chase,
+			home: home_corner(position),
+			cooldown: Cooldown::default(),
+		}
+	}
+
+	/// call this method to move the super beast per tick
+	fn advance(&mut self, board: &mut Board, player_position: Coord) -> BeastAction {
+		let mut field = FlowField::new(Movement::Walking);
+		field.update(board, player_position);
+		self.advance_with(board, &field, player_position)
+	}
 
 	/// the score killing the super beast yields
 	fn get_score() -> u16 {
@@ -132,24 +142,7 @@ mod tests {
 	}
 
 	#[test]
-	fn astar_direct_path_test() {
-		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
-		let beast_position = Coord { column: 0, row: 0 };
-		let player_position = Coord { column: 4, row: 4 };
-
-		board[&beast_position] = Tile::SuperBeast;
-		board[&player_position] = Tile::Player;
-
-		let path = SuperBeast::astar(&board, beast_position, &player_position);
-
-		assert!(path.is_some(), "Path should be calculated");
-		let unwrapped_path = path.unwrap();
-		assert_eq!(unwrapped_path[0], beast_position, "Path should start from the beast's position");
-		assert_eq!(unwrapped_path.last().unwrap(), &player_position, "Path should end at the player's position");
-	}
-
-	#[test]
-	fn astar_path_around_obstacle_test() {
+	fn advance_with_path_around_obstacle_test() {
 		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
 		let beast_position = Coord { column: 0, row: 0 };
 		let player_position = Coord { column: 4, row: 4 };
@@ -162,19 +155,20 @@ mod tests {
 			board[&Coord { column: 2, row }] = Tile::StaticBlock;
 		}
 
-		let path = SuperBeast::astar(&board, beast_position, &player_position);
-
-		assert!(path.is_some(), "A* should find a path around the wall");
-		let unwrapped_path = path.unwrap();
-		assert_eq!(unwrapped_path[0], beast_position, "Path should start from the beast's position");
-		assert_eq!(unwrapped_path.last().unwrap(), &player_position, "Path should end at the player's position");
+		let mut field = FlowField::new(Movement::Walking);
+		field.update(&board, player_position);
 
-		// Verify that the path goes around the obstacle (no coordinates with column = 2)
-		for coord in &unwrapped_path {
-			if coord.row < 3 {
-				assert_ne!(coord.column, 2, "Path should not go through the obstacle");
+		let mut beast = SuperBeast::new(beast_position);
+		let mut steps = 0;
+		while beast.advance_with(&mut board, &field, player_position) == BeastAction::Moved {
+			steps += 1;
+			if beast.position.row < 3 {
+				assert_ne!(beast.position.column, 2, "The beast should not go through the obstacle");
 			}
 		}
+
+		assert_eq!(beast.position, player_position, "The beast should reach the player around the wall");
+		assert_eq!(steps, 4, "The beast should take the shortest way around the wall");
 	}
 
 	#[test]
@@ -215,6 +209,9 @@ mod tests {
 		board[&beast_position] = Tile::SuperBeast;
 		board[&player_position] = Tile::Player;
 
+		let mut field = FlowField::new(Movement::Walking);
+		field.update(&board, player_position);
+
 		let mut beast = SuperBeast::new(beast_position);
 		let result = beast.advance(&mut board, player_position);
 
@@ -223,9 +220,7 @@ mod tests {
 		assert_eq!(board[&beast.position], Tile::SuperBeast, "Beast tile was placed correctly");
 		assert_eq!(board[&beast_position], Tile::Empty, "Beast tile was replaced correctly");
 
-		let old_distance = SuperBeast::heuristic(&beast_position, &player_position);
-		let new_distance = SuperBeast::heuristic(&beast.position, &player_position);
-		assert!(new_distance < old_distance, "New position should be closer to player");
+		assert_eq!(field[&beast.position], 3, "New position should be one step closer to the player");
 	}
 
 	#[test]
@@ -283,4 +278,42 @@ mod tests {
 	fn get_score_test() {
 		assert_eq!(SuperBeast::get_score(), 6, "SuperBeast score should be 6");
 	}
+
+	#[test]
+	fn advance_as_danger_aware_test() {
+		//   10 11 12 13
+		//  9         ╟╢
+		// 10 ◀▶░░    ▓▓
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let beast_position = Coord { column: 13, row: 9 };
+		let player_position = Coord { column: 10, row: 10 };
+
+		board[&beast_position] = Tile::SuperBeast;
+		board[&player_position] = Tile::Player;
+		board[&Coord { column: 11, row: 10 }] = Tile::Block;
+		board[&Coord { column: 13, row: 10 }] = Tile::StaticBlock;
+
+		let mut field = FlowField::new(Movement::Walking);
+		field.update(&board, player_position);
+		let mut hunt = Hunt {
+			player_position,
+			player_dir: None,
+			tick: 0,
+			danger_aware: false,
+		};
+
+		let mut beast = SuperBeast::new(beast_position);
+		let mut reckless_board = board;
+		beast.advance_as(&mut reckless_board, &field, &hunt);
+		assert_eq!(beast.position, Coord { column: 12, row: 10 }, "The beast should walk straight into the crush line");
+
+		hunt.danger_aware = true;
+		let mut beast = SuperBeast::new(beast_position);
+		beast.advance_as(&mut board, &field, &hunt);
+		assert_eq!(
+			beast.position,
+			Coord { column: 12, row: 9 },
+			"A danger aware beast should still close in but keep out of the crush line"
+		);
+	}
 }
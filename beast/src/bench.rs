@@ -0,0 +1,86 @@
+//! what the beasts of a level cost per tick, used by the `tick_cost` bench
+
+use std::time::{Duration, Instant};
+
+use crate::{
+	Tile,
+	beasts::{Beast, BeastAction, FlowField, HATCHED_SEARCH_BUDGET, HatchedBeast, Hunt, Movement},
+	board::Board,
+	common::levels::Level,
+};
+
+/// the cost of the beasts of a level
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TickCost {
+	/// how many common beasts were moved
+	pub common_beasts: usize,
+	/// how many super beasts were moved
+	pub super_beasts: usize,
+	/// how many hatched beasts were moved
+	pub hatched_beasts: usize,
+	/// how many ticks were measured before the player was killed or the ticks ran out
+	pub ticks: u32,
+	/// the time all beasts took per tick
+	pub per_tick: Duration,
+}
+
+/// move all beasts of a level for the given ticks with the eggs hatched right away so the most expensive beasts are
+/// measured too
+pub fn tick_cost(level: Level, ticks: u32) -> TickCost {
+	let level_config = level.get_config();
+	let terrain = Board::generate_terrain(level);
+	let mut board = Board::new(terrain.buffer);
+	let mut common_beasts = terrain.common_beasts;
+	let mut super_beasts = terrain.super_beasts;
+	let mut hatched_beasts = terrain
+		.eggs
+		.iter()
+		.map(|egg| {
+			board[&egg.position] = Tile::HatchedBeast;
+			HatchedBeast::new(egg.position)
+		})
+		.collect::<Vec<HatchedBeast>>();
+	let player_position = terrain.player.position;
+
+	let mut walking = FlowField::new(Movement::Walking);
+	let mut pushing = FlowField::new(Movement::Pushing).with_budget(HATCHED_SEARCH_BUDGET);
+	let mut measured = 0;
+	let start = Instant::now();
+	'ticks: while measured < ticks {
+		let hunt = Hunt {
+			player_position,
+			player_dir: None,
+			tick: u64::from(measured),
+			danger_aware: level_config.danger_aware_beasts,
+		};
+		measured += 1;
+
+		for beast in &mut common_beasts {
+			if beast.advance_as(&mut board, &hunt) == BeastAction::PlayerKilled {
+				break 'ticks;
+			}
+		}
+		walking.update(&board, player_position);
+		for beast in &mut super_beasts {
+			if beast.advance_as(&mut board, &walking, &hunt) == BeastAction::PlayerKilled {
+				break 'ticks;
+			}
+		}
+		if hatched_beasts.iter().any(|beast| beast.needs_path(&board, player_position, level_config.danger_aware_beasts)) {
+			pushing.update(&board, player_position);
+		}
+		for beast in &mut hatched_beasts {
+			if beast.advance_as(&mut board, &pushing, &hunt) == BeastAction::PlayerKilled {
+				break 'ticks;
+			}
+		}
+	}
+
+	TickCost {
+		common_beasts: common_beasts.len(),
+		super_beasts: super_beasts.len(),
+		hatched_beasts: hatched_beasts.len(),
+		ticks: measured,
+		per_tick: start.elapsed() / measured.max(1),
+	}
+}
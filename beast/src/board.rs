@@ -8,7 +8,7 @@ use std::{
 };
 
 use crate::{
-	ANSI_LEFT_BORDER, ANSI_RESET_BG, ANSI_RIGHT_BORDER, BOARD_HEIGHT, BOARD_WIDTH, Coord, PLAYER_START, Tile,
+	ANSI_RESET_BG, BOARD_HEIGHT, BOARD_WIDTH, Coord, LEFT_BORDER, PLAYER_START, RIGHT_BORDER, Tile,
 	beasts::{Beast, CommonBeast, Egg, HatchedBeast, SuperBeast},
 	common::levels::Level,
 	player::Player,
@@ -116,7 +116,8 @@ impl Board {
 
 			let coord = all_positions[i];
 			if placed_super_beasts < level_config.super_beasts {
-				super_beasts.push(SuperBeast::new(coord));
+				super_beasts
+					.push(SuperBeast::new(coord).with_behaviour(level_config.super_beast_behaviour(placed_super_beasts)));
 				buffer[coord.row][coord.column] = Tile::SuperBeast;
 				placed_super_beasts += 1;
 			} else if placed_eggs < level_config.eggs {
@@ -126,7 +127,7 @@ impl Board {
 				buffer[coord.row][coord.column] = Tile::Egg(time);
 				placed_eggs += 1;
 			} else if placed_beasts < level_config.common_beasts {
-				common_beasts.push(CommonBeast::new(coord));
+				common_beasts.push(CommonBeast::new(coord).with_behaviour(level_config.common_beast_behaviour(placed_beasts)));
 				buffer[coord.row][coord.column] = Tile::CommonBeast;
 				placed_beasts += 1;
 			}
@@ -145,16 +146,31 @@ impl Board {
 		}
 	}
 
+	/// the position of the first tile of a kind, scanning row by row
+	pub fn find(&self, tile: Tile) -> Option<Coord> {
+		self
+			.buffer
+			.iter()
+			.enumerate()
+			.find_map(|(row, tiles)| tiles.iter().position(|&item| item == tile).map(|column| Coord { column, row }))
+	}
+
 	/// render the board to the screen
 	pub fn render(&self) -> String {
+		self.render_with(|_, tile| tile.to_string())
+	}
+
+	/// render the board to the screen with each tile drawn by the given function
+	pub fn render_with(&self, render_tile: impl Fn(&Coord, &Tile) -> String) -> String {
 		let mut output = String::with_capacity(BOARD_WIDTH * BOARD_HEIGHT * 2 + BOARD_HEIGHT);
 
-		for row in self.buffer.iter() {
-			write!(output, "{ANSI_LEFT_BORDER}").unwrap_or_else(|_| panic!("Can't write to string buffer"));
-			for tile in row.iter() {
-				write!(output, "{tile}").unwrap_or_else(|_| panic!("Can't write to string buffer"));
+		for (row, tiles) in self.buffer.iter().enumerate() {
+			write!(output, "{LEFT_BORDER}").unwrap_or_else(|_| panic!("Can't write to string buffer"));
+			for (column, tile) in tiles.iter().enumerate() {
+				write!(output, "{}", render_tile(&Coord { column, row }, tile))
+					.unwrap_or_else(|_| panic!("Can't write to string buffer"));
 			}
-			writeln!(output, "{ANSI_RIGHT_BORDER}").unwrap_or_else(|_| panic!("Can't write to string buffer"));
+			writeln!(output, "{RIGHT_BORDER}").unwrap_or_else(|_| panic!("Can't write to string buffer"));
 		}
 		write!(output, "{ANSI_RESET_BG}").unwrap_or_else(|_| panic!("Can't write to string buffer"));
 
@@ -166,7 +182,7 @@ impl Board {
 mod test {
 	use super::*;
 
-	use crate::{common::levels::*, test_common::strip_ansi_border};
+	use crate::{common::levels::*, visible_width};
 
 	#[test]
 	fn new_level_one() {
@@ -859,9 +875,9 @@ mod test {
 		for (i, line) in lines.enumerate() {
 			if i < BOARD_HEIGHT {
 				assert_eq!(
-					strip_ansi_border(line).len(),
-					BOARD_WIDTH * 2,
-					"Board line {i} should render the right amount of line lengths"
+					visible_width(line),
+					BOARD_WIDTH * 2 + 2,
+					"Board line {i} should render the right amount of columns including both borders"
 				);
 			}
 		}
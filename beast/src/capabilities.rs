@@ -0,0 +1,145 @@
+//! this module detects which glyphs and how many colours the terminal we draw on can display
+
+use std::{
+	env,
+	io::{self, IsTerminal},
+	sync::OnceLock,
+};
+
+/// the capabilities detected once at startup
+static CAPABILITIES: OnceLock<Capabilities> = OnceLock::new();
+
+/// how many colours a terminal can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+	/// no colours at all like a VT100 or when `NO_COLOR` is set
+	None,
+	/// the 16 colours of the terminal palette like the Linux console
+	Ansi16,
+	/// the 256 colours of the extended palette
+	Ansi256,
+	/// 24 bit true colour
+	TrueColor,
+}
+
+/// what the terminal can display
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+	/// if the terminal can display the box drawing and block glyphs we use or only ASCII
+	pub unicode: bool,
+	/// how many colours the terminal can display
+	pub colors: ColorDepth,
+}
+
+impl Capabilities {
+	/// everything we could ever draw
+	pub const FULL: Self = Self {
+		unicode: true,
+		colors: ColorDepth::TrueColor,
+	};
+
+	/// detect the capabilities from `TERM`, `COLORTERM`, the locale and `NO_COLOR`
+	pub fn detect() -> Self {
+		// output that isn't a terminal like a pipe or a log file is left alone
+		if !io::stdout().is_terminal() {
+			return Self::FULL;
+		}
+
+		let var = |name: &str| env::var(name).ok().filter(|value| !value.is_empty());
+		let locale = var("LC_ALL").or_else(|| var("LC_CTYPE")).or_else(|| var("LANG"));
+
+		Self::from_env(var("TERM").as_deref(), var("COLORTERM").as_deref(), locale.as_deref(), var("NO_COLOR").is_some())
+	}
+
+	fn from_env(term: Option<&str>, colorterm: Option<&str>, locale: Option<&str>, no_color: bool) -> Self {
+		let term = term.unwrap_or("dumb").to_ascii_lowercase();
+
+		// only the locale says if the terminal decodes UTF-8, `TERM` names the escape sequences and not the font so even
+		// the Linux console, `dumb` and `vt*` emulators get unicode when the user set up a UTF-8 locale
+		let locale = locale.unwrap_or_default().to_ascii_lowercase();
+		let unicode = locale.contains("utf-8") || locale.contains("utf8");
+
+		let colorterm = colorterm.unwrap_or_default().to_ascii_lowercase();
+		let colors = if no_color || term == "dumb" || term.starts_with("vt") {
+			ColorDepth::None
+		} else if colorterm == "truecolor" || colorterm == "24bit" || term.contains("direct") {
+			ColorDepth::TrueColor
+		} else if term.contains("256color") {
+			ColorDepth::Ansi256
+		} else {
+			ColorDepth::Ansi16
+		};
+
+		Self { unicode, colors }
+	}
+}
+
+/// the capabilities of the terminal we draw on
+pub fn current() -> Capabilities {
+	*CAPABILITIES.get_or_init(Capabilities::detect)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn color_depth_test() {
+		assert_eq!(
+			Capabilities::from_env(Some("xterm-256color"), Some("truecolor"), None, false).colors,
+			ColorDepth::TrueColor,
+			"COLORTERM should enable true colour"
+		);
+		assert_eq!(
+			Capabilities::from_env(Some("screen-256color"), None, None, false).colors,
+			ColorDepth::Ansi256,
+			"256color terminals should get the extended palette"
+		);
+		assert_eq!(
+			Capabilities::from_env(Some("linux"), None, None, false).colors,
+			ColorDepth::Ansi16,
+			"The Linux console should get 16 colours"
+		);
+		assert_eq!(
+			Capabilities::from_env(Some("vt100"), None, None, false).colors,
+			ColorDepth::None,
+			"A VT100 should not get any colours"
+		);
+		assert_eq!(Capabilities::from_env(None, None, None, false).colors, ColorDepth::None, "No TERM should be dumb");
+		assert_eq!(
+			Capabilities::from_env(Some("xterm-256color"), Some("truecolor"), None, true).colors,
+			ColorDepth::None,
+			"NO_COLOR should win over everything"
+		);
+	}
+
+	#[test]
+	fn unicode_test() {
+		assert!(
+			Capabilities::from_env(Some("xterm-256color"), None, Some("en_AU.UTF-8"), false).unicode,
+			"A UTF-8 locale should enable unicode"
+		);
+		assert!(
+			Capabilities::from_env(Some("xterm"), None, Some("de_DE.utf8"), false).unicode,
+			"The locale should be matched case-insensitively"
+		);
+		assert!(!Capabilities::from_env(Some("xterm"), None, Some("C"), false).unicode, "The C locale should be ASCII");
+		assert!(!Capabilities::from_env(Some("xterm"), None, None, false).unicode, "No locale should be ASCII");
+		assert!(
+			Capabilities::from_env(Some("linux"), None, Some("en_US.UTF-8"), false).unicode,
+			"The Linux console with a UTF-8 locale should get unicode"
+		);
+		assert!(
+			!Capabilities::from_env(Some("linux"), None, Some("POSIX"), false).unicode,
+			"The Linux console without a UTF-8 locale should be ASCII"
+		);
+		assert!(
+			Capabilities::from_env(Some("vt220"), None, Some("en_US.UTF-8"), false).unicode,
+			"The terminal type should not decide unicode"
+		);
+		assert!(
+			Capabilities::from_env(None, None, Some("en_US.UTF-8"), false).unicode,
+			"A dumb terminal with a UTF-8 locale should get unicode"
+		);
+	}
+}
@@ -22,6 +22,10 @@ pub struct Highscore {
 	pub score: u16,
 	/// the level reached
 	pub level: Level,
+	/// if the score was reached in a practice game that isn't ranked globally, only ever set on local scores and left out
+	/// when it isn't so the format the server stores and sends stays the same
+	#[serde(default, skip_serializing_if = "std::ops::Not::not")]
+	pub practice: bool,
 }
 
 impl Highscore {
@@ -36,19 +40,25 @@ impl Highscore {
 	}
 
 	/// create a new instance of highscore
-	#[allow(dead_code)]
 	pub fn new(name: &str, score: u16, level: Level) -> Self {
 		Self {
 			timestamp: OffsetDateTime::now_utc(),
 			name: name.to_string(),
 			score,
 			level,
+			practice: false,
 		}
 	}
+
+	/// mark the score as reached in a practice game
+	pub fn with_practice(mut self, practice: bool) -> Self {
+		self.practice = practice;
+		self
+	}
 }
 
 /// the score type
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Score {
 	/// the name of the submitter
 	pub name: String,
@@ -75,6 +85,13 @@ impl Highscores {
 	pub fn ron_to_str(data: &Score) -> Result<String, ron::Error> {
 		ron::to_string(data)
 	}
+
+	/// add a new entry while keeping the scores sorted and capped at [MAX_SCORES]
+	pub fn add(&mut self, entry: Highscore) {
+		self.scores.push(entry);
+		self.scores.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.timestamp.cmp(&b.timestamp)));
+		self.scores.truncate(MAX_SCORES);
+	}
 }
 
 #[cfg(test)]
@@ -97,6 +114,7 @@ mod tests {
 						name: "Alan",
 						score: 666,
 						level: Eight,
+						practice: true,
 					),
 				],
 			)"#;
@@ -109,6 +127,45 @@ mod tests {
 		assert_eq!(highscores.scores[1].name, "Alan", "The second highscore should have the name 'Alan'");
 		assert_eq!(highscores.scores[1].score, 666, "The second highscore should have the score 666");
 		assert_eq!(highscores.scores[1].level, Level::Eight, "The second highscore should have the level eight");
+		assert!(!highscores.scores[0].practice, "Scores without the practice flag should be ranked");
+		assert!(highscores.scores[1].practice, "The second highscore should be a practice score");
+	}
+
+	#[test]
+	fn add_test() {
+		let mut highscores = Highscores { scores: Vec::new() };
+		highscores.add(Highscore::new("Dom", 42, Level::One));
+		highscores.add(Highscore::new("Belle", 666, Level::Two));
+		highscores.add(Highscore::new("Alan", 42, Level::Three));
+
+		assert_eq!(highscores.scores[0].name, "Belle", "The highest score should be first");
+		assert_eq!(highscores.scores[1].name, "Dom", "Equal scores should keep the older entry first");
+		assert_eq!(highscores.scores[2].name, "Alan", "Equal scores should put the newer entry last");
+
+		for _ in 0..MAX_SCORES {
+			highscores.add(Highscore::new("Filler", 1, Level::One));
+		}
+		assert_eq!(highscores.scores.len(), MAX_SCORES, "The scores should be capped at MAX_SCORES");
+		assert_eq!(highscores.scores[0].name, "Belle", "The highest score should survive the cap");
+	}
+
+	#[test]
+	fn practice_serialize_test() {
+		let ranked = Highscores {
+			scores: vec![Highscore::new("Dom", 42, Level::One)],
+		};
+		let practice = Highscores {
+			scores: vec![Highscore::new("Dom", 42, Level::One).with_practice(true)],
+		};
+
+		assert!(
+			!ron::to_string(&ranked).expect("Failed to serialize ranked scores").contains("practice"),
+			"Ranked scores should serialize in the format the server uses"
+		);
+		assert!(
+			ron::to_string(&practice).expect("Failed to serialize practice scores").contains("practice:true"),
+			"Practice scores should keep their mark"
+		);
 	}
 
 	#[test]
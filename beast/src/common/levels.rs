@@ -4,6 +4,9 @@ use serde::{Deserialize, Serialize};
 
 use std::{fmt, time::Duration};
 
+/// the amount of levels in the game
+pub const LEVEL_COUNT: usize = 10;
+
 /// game levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Level {
@@ -46,6 +49,23 @@ impl Level {
 		}
 	}
 
+	/// get the level by its number starting at 1
+	pub fn from_number(number: u8) -> Option<Self> {
+		match number {
+			1 => Some(Self::One),
+			2 => Some(Self::Two),
+			3 => Some(Self::Three),
+			4 => Some(Self::Four),
+			5 => Some(Self::Five),
+			6 => Some(Self::Six),
+			7 => Some(Self::Seven),
+			8 => Some(Self::Eight),
+			9 => Some(Self::Nine),
+			10 => Some(Self::Ten),
+			_ => None,
+		}
+	}
+
 	/// go to the next level
 	pub fn next(&self) -> Option<Self> {
 		match self {
@@ -80,6 +100,19 @@ impl fmt::Display for Level {
 	}
 }
 
+/// how a beast picks where to go, in the spirit of the ghosts of classic maze games
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Behaviour {
+	/// always head straight for the player
+	Chase,
+	/// head for the tiles ahead of where the player is going
+	Ambush,
+	/// every now and then retreat to a corner of the board before chasing again
+	Scatter,
+	/// roam around at random while the player is far away
+	Wander,
+}
+
 /// level configuration
 #[derive(Debug, Clone)]
 pub struct LevelConfig {
@@ -93,6 +126,18 @@ pub struct LevelConfig {
 	pub super_beasts: usize,
 	/// how many eggs are placed on the board
 	pub eggs: usize,
+	/// every how many ticks a common beast moves, lower is faster
+	pub common_move_interval: u8,
+	/// every how many ticks a super beast moves, lower is faster
+	pub super_move_interval: u8,
+	/// every how many ticks a hatched beast moves, lower is faster
+	pub hatched_move_interval: u8,
+	/// the behaviours handed out to the common beasts in turn
+	pub common_beast_behaviours: &'static [Behaviour],
+	/// the behaviours handed out to the super beasts in turn
+	pub super_beast_behaviours: &'static [Behaviour],
+	/// if the beasts keep out of the way of blocks the player could squish them with
+	pub danger_aware_beasts: bool,
 	/// how long it takes for an egg to hatch
 	pub egg_hatching_time: Duration,
 	/// how far away from each other the beasts start
@@ -103,6 +148,22 @@ pub struct LevelConfig {
 	pub completion_score: u16,
 }
 
+impl LevelConfig {
+	/// the behaviour of the common beast placed as number `index`
+	pub fn common_beast_behaviour(&self, index: usize) -> Behaviour {
+		Self::behaviour_in_turn(self.common_beast_behaviours, index)
+	}
+
+	/// the behaviour of the super beast placed as number `index`
+	pub fn super_beast_behaviour(&self, index: usize) -> Behaviour {
+		Self::behaviour_in_turn(self.super_beast_behaviours, index)
+	}
+
+	fn behaviour_in_turn(behaviours: &[Behaviour], index: usize) -> Behaviour {
+		behaviours.get(index % behaviours.len().max(1)).copied().unwrap_or(Behaviour::Chase)
+	}
+}
+
 /// level config for level 1
 pub const LEVEL_ONE: LevelConfig = LevelConfig {
 	blocks: 300,
@@ -110,6 +171,12 @@ pub const LEVEL_ONE: LevelConfig = LevelConfig {
 	common_beasts: 3,
 	super_beasts: 0,
 	eggs: 0,
+	common_move_interval: 5,
+	super_move_interval: 5,
+	hatched_move_interval: 5,
+	common_beast_behaviours: &[Behaviour::Chase],
+	super_beast_behaviours: &[Behaviour::Chase],
+	danger_aware_beasts: false,
 	egg_hatching_time: Duration::from_millis(20000),
 	beast_starting_distance: 16,
 	time: Duration::from_secs(120),
@@ -123,6 +190,12 @@ pub const LEVEL_TWO: LevelConfig = LevelConfig {
 	common_beasts: 5,
 	super_beasts: 0,
 	eggs: 0,
+	common_move_interval: 5,
+	super_move_interval: 5,
+	hatched_move_interval: 4,
+	common_beast_behaviours: &[Behaviour::Chase, Behaviour::Wander],
+	super_beast_behaviours: &[Behaviour::Chase],
+	danger_aware_beasts: false,
 	egg_hatching_time: Duration::from_millis(20000),
 	beast_starting_distance: 42,
 	time: Duration::from_secs(120),
@@ -136,6 +209,12 @@ pub const LEVEL_THREE: LevelConfig = LevelConfig {
 	common_beasts: 12,
 	super_beasts: 0,
 	eggs: 0,
+	common_move_interval: 5,
+	super_move_interval: 4,
+	hatched_move_interval: 4,
+	common_beast_behaviours: &[Behaviour::Chase, Behaviour::Wander, Behaviour::Scatter],
+	super_beast_behaviours: &[Behaviour::Chase],
+	danger_aware_beasts: false,
 	egg_hatching_time: Duration::from_millis(20000),
 	beast_starting_distance: 27,
 	time: Duration::from_secs(240),
@@ -149,6 +228,12 @@ pub const LEVEL_FOUR: LevelConfig = LevelConfig {
 	common_beasts: 10,
 	super_beasts: 1,
 	eggs: 0,
+	common_move_interval: 5,
+	super_move_interval: 4,
+	hatched_move_interval: 3,
+	common_beast_behaviours: &[Behaviour::Chase, Behaviour::Ambush, Behaviour::Wander],
+	super_beast_behaviours: &[Behaviour::Chase],
+	danger_aware_beasts: false,
 	egg_hatching_time: Duration::from_millis(20000),
 	beast_starting_distance: 27,
 	time: Duration::from_secs(240),
@@ -162,6 +247,17 @@ pub const LEVEL_FIVE: LevelConfig = LevelConfig {
 	common_beasts: 10,
 	super_beasts: 3,
 	eggs: 0,
+	common_move_interval: 4,
+	super_move_interval: 4,
+	hatched_move_interval: 3,
+	common_beast_behaviours: &[
+		Behaviour::Chase,
+		Behaviour::Ambush,
+		Behaviour::Scatter,
+		Behaviour::Wander,
+	],
+	super_beast_behaviours: &[Behaviour::Chase, Behaviour::Scatter],
+	danger_aware_beasts: false,
 	egg_hatching_time: Duration::from_millis(20000),
 	beast_starting_distance: 27,
 	time: Duration::from_secs(240),
@@ -175,6 +271,17 @@ pub const LEVEL_SIX: LevelConfig = LevelConfig {
 	common_beasts: 10,
 	super_beasts: 7,
 	eggs: 0,
+	common_move_interval: 4,
+	super_move_interval: 4,
+	hatched_move_interval: 3,
+	common_beast_behaviours: &[
+		Behaviour::Chase,
+		Behaviour::Ambush,
+		Behaviour::Scatter,
+		Behaviour::Wander,
+	],
+	super_beast_behaviours: &[Behaviour::Chase, Behaviour::Ambush, Behaviour::Scatter],
+	danger_aware_beasts: false,
 	egg_hatching_time: Duration::from_millis(20000),
 	beast_starting_distance: 27,
 	time: Duration::from_secs(300),
@@ -188,6 +295,17 @@ pub const LEVEL_SEVEN: LevelConfig = LevelConfig {
 	common_beasts: 5,
 	super_beasts: 1,
 	eggs: 1,
+	common_move_interval: 4,
+	super_move_interval: 3,
+	hatched_move_interval: 3,
+	common_beast_behaviours: &[
+		Behaviour::Chase,
+		Behaviour::Ambush,
+		Behaviour::Scatter,
+		Behaviour::Wander,
+	],
+	super_beast_behaviours: &[Behaviour::Chase],
+	danger_aware_beasts: false,
 	egg_hatching_time: Duration::from_millis(20000),
 	beast_starting_distance: 27,
 	time: Duration::from_secs(300),
@@ -201,6 +319,17 @@ pub const LEVEL_EIGHT: LevelConfig = LevelConfig {
 	common_beasts: 10,
 	super_beasts: 5,
 	eggs: 3,
+	common_move_interval: 4,
+	super_move_interval: 3,
+	hatched_move_interval: 2,
+	common_beast_behaviours: &[
+		Behaviour::Chase,
+		Behaviour::Ambush,
+		Behaviour::Scatter,
+		Behaviour::Wander,
+	],
+	super_beast_behaviours: &[Behaviour::Chase, Behaviour::Ambush, Behaviour::Scatter],
+	danger_aware_beasts: true,
 	egg_hatching_time: Duration::from_millis(20000),
 	beast_starting_distance: 27,
 	time: Duration::from_secs(330),
@@ -214,6 +343,17 @@ pub const LEVEL_NINE: LevelConfig = LevelConfig {
 	common_beasts: 10,
 	super_beasts: 5,
 	eggs: 5,
+	common_move_interval: 3,
+	super_move_interval: 3,
+	hatched_move_interval: 2,
+	common_beast_behaviours: &[
+		Behaviour::Chase,
+		Behaviour::Ambush,
+		Behaviour::Scatter,
+		Behaviour::Wander,
+	],
+	super_beast_behaviours: &[Behaviour::Chase, Behaviour::Ambush, Behaviour::Scatter],
+	danger_aware_beasts: true,
 	egg_hatching_time: Duration::from_millis(17000),
 	beast_starting_distance: 27,
 	time: Duration::from_secs(330),
@@ -227,6 +367,22 @@ pub const LEVEL_TEN: LevelConfig = LevelConfig {
 	common_beasts: 10,
 	super_beasts: 10,
 	eggs: 8,
+	common_move_interval: 3,
+	super_move_interval: 3,
+	hatched_move_interval: 2,
+	common_beast_behaviours: &[
+		Behaviour::Chase,
+		Behaviour::Ambush,
+		Behaviour::Scatter,
+		Behaviour::Wander,
+	],
+	super_beast_behaviours: &[
+		Behaviour::Chase,
+		Behaviour::Ambush,
+		Behaviour::Chase,
+		Behaviour::Scatter,
+	],
+	danger_aware_beasts: true,
 	egg_hatching_time: Duration::from_millis(10000),
 	beast_starting_distance: 27,
 	time: Duration::from_secs(360),
@@ -251,6 +407,15 @@ mod tests {
 		assert_eq!(Level::Ten.to_string(), "10", "This level should render to 10");
 	}
 
+	#[test]
+	fn level_from_number_test() {
+		assert_eq!(Level::from_number(0), None, "There should be no level 0");
+		assert_eq!(Level::from_number(1), Some(Level::One), "The number 1 should be the first level");
+		assert_eq!(Level::from_number(7), Some(Level::Seven), "The number 7 should be level 7");
+		assert_eq!(Level::from_number(10), Some(Level::Ten), "The number 10 should be the last level");
+		assert_eq!(Level::from_number(11), None, "There should be no level after the last level");
+	}
+
 	#[test]
 	fn level_next_test() {
 		assert_eq!(Level::One.next(), Some(Level::Two), "This level should progress to 2");
@@ -264,4 +429,36 @@ mod tests {
 		assert_eq!(Level::Nine.next(), Some(Level::Ten), "This level should progress to 10");
 		assert_eq!(Level::Ten.next(), None, "This level should be the last level");
 	}
+
+	#[test]
+	fn move_interval_test() {
+		let levels =
+			(1..=LEVEL_COUNT as u8).filter_map(Level::from_number).map(|level| level.get_config()).collect::<Vec<_>>();
+		for pair in levels.windows(2) {
+			assert!(
+				pair[1].common_move_interval <= pair[0].common_move_interval
+					&& pair[1].super_move_interval <= pair[0].super_move_interval
+					&& pair[1].hatched_move_interval <= pair[0].hatched_move_interval,
+				"Beasts should never get slower on a later level"
+			);
+		}
+		assert!(
+			levels[LEVEL_COUNT - 1].common_move_interval < levels[0].common_move_interval,
+			"Beasts should be faster on the last level than on the first"
+		);
+		for level in &levels {
+			assert!(
+				level.hatched_move_interval <= level.super_move_interval
+					&& level.super_move_interval <= level.common_move_interval,
+				"Hatched beasts should be the fastest and common beasts the slowest"
+			);
+		}
+	}
+
+	#[test]
+	fn behaviour_in_turn_test() {
+		assert_eq!(LEVEL_ONE.common_beast_behaviour(2), Behaviour::Chase, "The first level should only chase");
+		assert_eq!(LEVEL_TEN.common_beast_behaviour(5), Behaviour::Ambush, "Behaviours should be handed out in turn");
+		assert_eq!(LevelConfig::behaviour_in_turn(&[], 3), Behaviour::Chase, "Beasts without a behaviour should chase");
+	}
 }
@@ -0,0 +1,334 @@
+//! this module loads the user config from `~/.config/beast/config.ron` and applies cli flags on top of it
+
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::HashMap,
+	env, fmt, fs, io,
+	path::{Path, PathBuf},
+};
+
+use crate::{
+	common::levels::Level,
+	keys::{Action, Key, KeyBindings, KeyPreset},
+	renderer::RendererType,
+	xdg,
+};
+
+/// the name of the file inside our config directory
+const FILE_NAME: &str = "config.ron";
+/// the server we submit highscores to unless configured otherwise
+const DEFAULT_HIGHSCORE_URL: &str = "https://dominik-wilkowski.com/beast";
+/// the flags we accept, printed when a flag is wrong
+pub const USAGE: &str = "Usage: beast [FLAGS]
+
+  --name <NAME>            the name used for highscores
+  --highscore-url <URL>    the server the highscores are synced with
+  --offline                never contact the highscore server
+  --level <1-10>           the level to start at
+  --theme <THEME>          the colour theme
+  --keys <PRESET>          the key binding preset
+  --renderer <RENDERER>    how the game is drawn
+  --accessible             announce what happens for screen readers and turn off flashing
+  --slow-beasts            let the beasts move at half speed
+  --training               show the training overlay
+  --event-log <FILE>       write every game event to a file
+  --record <FILE>          record the input to a file
+  --replay <FILE>          replay the input from a file
+  --listen <ADDRESS>       read the input from a TCP socket
+  -v, -V, --version        print the version";
+
+/// the errors that can happen while building the config
+#[derive(Debug)]
+pub enum ConfigError {
+	/// the config file exists but could not be read
+	Read(io::Error),
+	/// the config file could not be parsed
+	Parse(ron::error::SpannedError),
+	/// a cli flag was missing its value or had an invalid one
+	Flag(String),
+	/// a cli flag we don't know
+	UnknownFlag(String),
+}
+
+impl fmt::Display for ConfigError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ConfigError::Read(error) => write!(f, "Failed to read config file: {error}"),
+			ConfigError::Parse(error) => write!(f, "Failed to parse config file: {error}"),
+			ConfigError::Flag(error) => write!(f, "{error}"),
+			ConfigError::UnknownFlag(flag) => write!(f, "The flag {flag} does not exist"),
+		}
+	}
+}
+
+/// the user config, every field is optional in the file and falls back to its default
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+	/// the name used for highscores
+	pub name: Option<String>,
+	/// the server the highscores are synced with
+	pub highscore_url: String,
+	/// if we should stay away from the highscore server
+	pub offline: bool,
+	/// the level a game starts at, anything but the first level is practice and won't be ranked globally
+	pub start_level: Level,
+	/// the name of a built-in colour theme or of a theme file in the `themes` folder of our config directory
+	pub theme: String,
+	/// the key binding preset
+	pub key_bindings: KeyPreset,
+	/// keys that replace the ones of the preset for individual actions
+	pub keys: HashMap<Action, Vec<Key>>,
+	/// how the game is drawn
+	pub renderer: RendererType,
+	/// announce what happens in a status line for screen readers and turn off flashing
+	pub accessibility: bool,
+	/// move the beasts at half their speed
+	pub slow_beasts: bool,
+	/// start with the training overlay shown, games played with it are practice and won't be ranked globally
+	pub training: bool,
+	/// a file every game event is written to as a line of RON
+	pub event_log: Option<PathBuf>,
+	/// a file the keys typed are recorded into so they can be replayed
+	pub record: Option<PathBuf>,
+	/// a file recorded earlier to replay instead of reading the keyboard
+	pub replay: Option<PathBuf>,
+	/// an address like `127.0.0.1:6667` to read input from over TCP instead of the keyboard
+	pub listen: Option<String>,
+}
+
+impl Default for Config {
+	fn default() -> Self {
+		Self {
+			name: None,
+			highscore_url: String::from(DEFAULT_HIGHSCORE_URL),
+			offline: false,
+			start_level: Level::One,
+			theme: String::from("default"),
+			key_bindings: KeyPreset::Default,
+			keys: HashMap::new(),
+			renderer: RendererType::Ansi,
+			accessibility: false,
+			slow_beasts: false,
+			training: false,
+			event_log: None,
+			record: None,
+			replay: None,
+			listen: None,
+		}
+	}
+}
+
+impl Config {
+	/// load the config file from the XDG config directory and apply environment variables on top
+	pub fn load() -> Result<Self, ConfigError> {
+		let mut config = match xdg::config_dir() {
+			Some(dir) => Self::from_file(&dir.join(FILE_NAME))?,
+			None => Self::default(),
+		};
+
+		if let Ok(url) = env::var("HIGHSCORE_URL") {
+			config.highscore_url = url;
+		}
+
+		Ok(config)
+	}
+
+	/// read a config file, a missing file results in the default config
+	pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+		match fs::read_to_string(path) {
+			Ok(content) => Self::ron_from_str(&content),
+			Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+			Err(error) => Err(ConfigError::Read(error)),
+		}
+	}
+
+	/// parse the content of a config file
+	pub fn ron_from_str(content: &str) -> Result<Self, ConfigError> {
+		ron::from_str::<Self>(content).map_err(ConfigError::Parse)
+	}
+
+	/// override the config with the flags passed in via the cli
+	pub fn apply_cli_flags(&mut self, cli_flags: &[String]) -> Result<(), ConfigError> {
+		let mut flags = cli_flags.iter();
+		while let Some(flag) = flags.next() {
+			let mut value =
+				|| flags.next().cloned().ok_or_else(|| ConfigError::Flag(format!("The flag {flag} is missing its value")));
+
+			match flag.as_str() {
+				"--offline" => self.offline = true,
+				"--accessible" => self.accessibility = true,
+				"--slow-beasts" => self.slow_beasts = true,
+				"--training" => self.training = true,
+				"--name" => self.name = Some(value()?),
+				"--highscore-url" => self.highscore_url = value()?,
+				"--level" => {
+					let level = value()?;
+					self.start_level = level
+						.parse::<u8>()
+						.ok()
+						.and_then(Level::from_number)
+						.ok_or_else(|| ConfigError::Flag(format!("The level {level} does not exist")))?;
+				},
+				"--theme" => self.theme = value()?,
+				"--event-log" => self.event_log = Some(PathBuf::from(value()?)),
+				"--record" => self.record = Some(PathBuf::from(value()?)),
+				"--replay" => self.replay = Some(PathBuf::from(value()?)),
+				"--listen" => self.listen = Some(value()?),
+				"--renderer" => {
+					let renderer = value()?;
+					self.renderer = RendererType::from_name(&renderer)
+						.ok_or_else(|| ConfigError::Flag(format!("The renderer {renderer} does not exist")))?;
+				},
+				"--keys" => {
+					let preset = value()?;
+					self.key_bindings = KeyPreset::from_name(&preset)
+						.ok_or_else(|| ConfigError::Flag(format!("The key binding preset {preset} does not exist")))?;
+				},
+				_ => return Err(ConfigError::UnknownFlag(flag.clone())),
+			}
+		}
+
+		Ok(())
+	}
+
+	/// the active key bindings made up of the preset and the individual keys
+	pub fn key_bindings(&self) -> KeyBindings {
+		KeyBindings::from_preset(self.key_bindings).with_overrides(&self.keys)
+	}
+
+	/// the endpoint highscores are fetched from and posted to
+	pub fn highscore_endpoint(&self) -> String {
+		format!("{}/highscore", self.highscore_url.trim_end_matches('/'))
+	}
+
+	/// games started past the first level are practice and only recorded locally
+	pub fn is_ranked(&self) -> bool {
+		!self.offline && self.start_level == Level::One
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn flags(flags: &[&str]) -> Vec<String> {
+		flags.iter().map(|flag| flag.to_string()).collect()
+	}
+
+	#[test]
+	fn ron_from_str_partial_test() {
+		let config = Config::ron_from_str(r#"(name: Some("Dom"), start_level: Three)"#).expect("Failed to parse config");
+
+		assert_eq!(config.name, Some(String::from("Dom")), "The name should be read from the file");
+		assert_eq!(config.start_level, Level::Three, "The start level should be read from the file");
+		assert_eq!(config.highscore_url, DEFAULT_HIGHSCORE_URL, "Missing fields should fall back to their default");
+		assert!(!config.offline, "Missing fields should fall back to their default");
+	}
+
+	#[test]
+	fn ron_from_str_invalid_test() {
+		assert!(Config::ron_from_str("(offline: maybe)").is_err(), "Invalid config files should error");
+	}
+
+	#[test]
+	fn from_file_missing_test() {
+		let config =
+			Config::from_file(&env::temp_dir().join("beast_missing_config.ron")).expect("A missing file should not error");
+		assert_eq!(config, Config::default(), "A missing file should result in the default config");
+	}
+
+	#[test]
+	fn apply_cli_flags_test() {
+		let mut config = Config::ron_from_str(r#"(name: Some("Dom"), highscore_url: "http://localhost:6666")"#)
+			.expect("Failed to parse config");
+		config
+			.apply_cli_flags(&flags(&[
+				"--offline",
+				"--name",
+				"Belle",
+				"--level",
+				"4",
+				"--keys",
+				"hjkl",
+				"--renderer",
+				"plain",
+				"--accessible",
+				"--slow-beasts",
+				"--training",
+				"--event-log",
+				"events.ron",
+				"--replay",
+				"game.replay",
+				"--listen",
+				"127.0.0.1:6667",
+			]))
+			.expect("Valid flags should apply");
+
+		assert!(config.offline, "The offline flag should be applied");
+		assert_eq!(config.name, Some(String::from("Belle")), "The name flag should override the file");
+		assert_eq!(config.start_level, Level::Four, "The level flag should be applied");
+		assert_eq!(config.key_bindings, KeyPreset::Hjkl, "The keys flag should be applied");
+		assert_eq!(config.renderer, RendererType::Plain, "The renderer flag should be applied");
+		assert!(config.accessibility, "The accessible flag should be applied");
+		assert!(config.slow_beasts, "The slow beasts flag should be applied");
+		assert!(config.training, "The training flag should be applied");
+		assert_eq!(config.event_log, Some(PathBuf::from("events.ron")), "The event log flag should be applied");
+		assert_eq!(config.replay, Some(PathBuf::from("game.replay")), "The replay flag should be applied");
+		assert_eq!(config.listen, Some(String::from("127.0.0.1:6667")), "The listen flag should be applied");
+		assert_eq!(config.highscore_url, "http://localhost:6666", "Values without flags should be kept");
+	}
+
+	#[test]
+	fn apply_cli_flags_invalid_test() {
+		assert!(
+			Config::default().apply_cli_flags(&flags(&["--level", "11"])).is_err(),
+			"Levels that don't exist should error"
+		);
+		assert!(Config::default().apply_cli_flags(&flags(&["--name"])).is_err(), "Missing values should error");
+		assert!(
+			Config::default().apply_cli_flags(&flags(&["--keys", "emacs"])).is_err(),
+			"Presets that don't exist should error"
+		);
+		assert!(
+			matches!(
+				Config::default().apply_cli_flags(&flags(&["--offline", "--ofline"])),
+				Err(ConfigError::UnknownFlag(flag)) if flag == "--ofline"
+			),
+			"Unknown flags should error"
+		);
+	}
+
+	#[test]
+	fn key_bindings_test() {
+		let config =
+			Config::ron_from_str("(key_bindings: Wasd, keys: { Pause: [Char('x')] })").expect("Failed to parse config");
+		let key_bindings = config.key_bindings();
+
+		assert_eq!(key_bindings.action(Key::Char('w')), Some(Action::Up), "The preset should be applied");
+		assert_eq!(key_bindings.action(Key::Char('x')), Some(Action::Pause), "The individual keys should be applied");
+		assert_eq!(key_bindings.action(Key::Char('p')), None, "The individual keys should replace the preset");
+	}
+
+	#[test]
+	fn is_ranked_test() {
+		let mut config = Config::default();
+		assert!(config.is_ranked(), "The default config should be ranked");
+
+		config.start_level = Level::Two;
+		assert!(!config.is_ranked(), "Starting past the first level should not be ranked");
+
+		config.start_level = Level::One;
+		config.offline = true;
+		assert!(!config.is_ranked(), "Offline games should not be ranked");
+	}
+
+	#[test]
+	fn highscore_endpoint_test() {
+		let config = Config {
+			highscore_url: String::from("http://localhost:6666/"),
+			..Config::default()
+		};
+		assert_eq!(config.highscore_endpoint(), "http://localhost:6666/highscore", "Trailing slashes should be trimmed");
+	}
+}
@@ -0,0 +1,95 @@
+//! this module splits ANSI text into the characters that are drawn and the escape sequences between them so everything
+//! reading our output agrees on where a sequence ends
+
+use std::str::CharIndices;
+
+/// a piece of ANSI text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token<'a> {
+	/// a character that is drawn or a control character like a line feed
+	Char(char),
+	/// a control sequence like `\x1b[1;31m` with its parameters `1;31` and its final byte `m`
+	Csi { params: &'a str, action: char },
+	/// any other escape sequence like `\x1b7`, these don't change what is drawn
+	Escape,
+}
+
+/// an iterator over the tokens of ANSI text
+#[derive(Debug, Clone)]
+pub struct Tokens<'a> {
+	text: &'a str,
+	chars: CharIndices<'a>,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+	type Item = Token<'a>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (_, c) = self.chars.next()?;
+		if c != '\x1b' {
+			return Some(Token::Char(c));
+		}
+
+		match self.chars.next() {
+			Some((start, '[')) => {
+				let start = start + 1;
+				for (end, c) in self.chars.by_ref() {
+					if ('\x40'..='\x7e').contains(&c) {
+						return Some(Token::Csi {
+							params: &self.text[start..end],
+							action: c,
+						});
+					}
+				}
+				// a sequence cut off at the end of the text
+				Some(Token::Escape)
+			},
+			_ => Some(Token::Escape),
+		}
+	}
+}
+
+/// split ANSI text into tokens
+pub fn tokenize(text: &str) -> Tokens<'_> {
+	Tokens {
+		text,
+		chars: text.char_indices(),
+	}
+}
+
+/// all characters of ANSI text without the escape sequences
+pub fn chars(text: &str) -> impl Iterator<Item = char> + '_ {
+	tokenize(text).filter_map(|token| match token {
+		Token::Char(c) => Some(c),
+		_ => None,
+	})
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn tokenize_test() {
+		assert_eq!(
+			tokenize("a\x1b[1;31mb\x1b[?25l\x1b7\n").collect::<Vec<Token>>(),
+			vec![
+				Token::Char('a'),
+				Token::Csi {
+					params: "1;31",
+					action: 'm'
+				},
+				Token::Char('b'),
+				Token::Csi {
+					params: "?25",
+					action: 'l'
+				},
+				Token::Escape,
+				Token::Char('\n'),
+			],
+			"Characters and sequences should be split apart"
+		);
+		assert_eq!(tokenize("\x1b[12").collect::<Vec<Token>>(), vec![Token::Escape], "Cut off sequences are dropped");
+		assert_eq!(chars("\x1b[34F├┤\x1b[0m").collect::<String>(), "├┤", "Only the characters should be kept");
+	}
+}
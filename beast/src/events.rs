@@ -0,0 +1,201 @@
+//! this module contains the events the game publishes so statistics, announcements, replays and integrations can follow
+//! along without living inside the game loop
+
+use serde::Serialize;
+use std::{
+	fs::File,
+	io::{self, LineWriter, Write},
+	path::Path,
+};
+
+use crate::{Coord, Dir, Tile, common::levels::Level};
+
+/// the things on the board an event can be about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Entity {
+	Player,
+	CommonBeast,
+	SuperBeast,
+	Egg,
+	HatchedBeast,
+}
+
+impl Entity {
+	/// the entity a tile on the board stands for
+	pub fn from_tile(tile: Tile) -> Option<Self> {
+		match tile {
+			Tile::Player => Some(Self::Player),
+			Tile::CommonBeast => Some(Self::CommonBeast),
+			Tile::SuperBeast => Some(Self::SuperBeast),
+			Tile::Egg(_) | Tile::EggHatching(_) => Some(Self::Egg),
+			Tile::HatchedBeast => Some(Self::HatchedBeast),
+			Tile::Empty | Tile::Block | Tile::StaticBlock => None,
+		}
+	}
+}
+
+/// what happened in the game
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum EventKind {
+	/// a level started
+	LevelStarted { level: Level },
+	/// the player moved to a new position
+	PlayerMoved { dir: Dir, position: Coord },
+	/// the player squished something
+	Killed { entity: Entity, position: Coord },
+	/// an egg started hatching
+	EggHatching { position: Coord },
+	/// an egg hatched into a hatched beast
+	EggHatched { position: Coord },
+	/// the player lost a life where they stood
+	PlayerKilled { by: Entity, position: Coord, lives: u8 },
+	/// all beasts of the level are gone
+	LevelComplete { level: Level, time_bonus: u16 },
+	/// the player ran out of lives or time
+	GameOver { level: Level, score: u16 },
+	/// the last level was completed
+	GameWon { score: u16 },
+}
+
+/// an event with the game tick it happened in
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameEvent {
+	/// the number of game ticks since the game was started
+	pub tick: u64,
+	/// what happened
+	pub kind: EventKind,
+}
+
+/// something that wants to know what happens in the game
+pub trait Subscriber: Send {
+	/// called for every event in the order they were published
+	fn notify(&mut self, event: &GameEvent);
+}
+
+/// collects the events published by the game and hands them to every subscriber
+#[derive(Default)]
+pub struct EventBus {
+	tick: u64,
+	pending: Vec<GameEvent>,
+	subscribers: Vec<Box<dyn Subscriber>>,
+}
+
+impl EventBus {
+	/// create a new bus without subscribers
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// add a subscriber that is notified of every event from now on
+	pub fn subscribe(&mut self, subscriber: Box<dyn Subscriber>) {
+		self.subscribers.push(subscriber);
+	}
+
+	/// count a game tick
+	pub fn tick(&mut self) {
+		self.tick += 1;
+	}
+
+	/// publish an event, it is handed to the subscribers with the next dispatch
+	pub fn publish(&mut self, kind: EventKind) {
+		self.pending.push(GameEvent { tick: self.tick, kind });
+	}
+
+	/// hand the events published since the last dispatch to all subscribers and return them
+	pub fn dispatch(&mut self) -> Vec<GameEvent> {
+		let events = std::mem::take(&mut self.pending);
+		for event in &events {
+			for subscriber in &mut self.subscribers {
+				subscriber.notify(event);
+			}
+		}
+		events
+	}
+}
+
+/// a subscriber that writes every event as a line of RON for replays and external tools
+pub struct EventLog<W: Write + Send> {
+	writer: W,
+}
+
+impl EventLog<LineWriter<File>> {
+	/// create the log file, an existing file is replaced
+	pub fn create(path: &Path) -> io::Result<Self> {
+		Ok(Self::new(LineWriter::new(File::create(path)?)))
+	}
+}
+
+impl<W: Write + Send> EventLog<W> {
+	/// log into any writer
+	pub fn new(writer: W) -> Self {
+		Self { writer }
+	}
+}
+
+impl<W: Write + Send> Subscriber for EventLog<W> {
+	fn notify(&mut self, event: &GameEvent) {
+		if let Ok(line) = ron::to_string(event) {
+			// a log that can't be written to shouldn't stop the game
+			let _ = writeln!(self.writer, "{line}");
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::sync::{Arc, Mutex};
+
+	struct Recorder(Arc<Mutex<Vec<GameEvent>>>);
+
+	impl Subscriber for Recorder {
+		fn notify(&mut self, event: &GameEvent) {
+			self.0.lock().unwrap().push(event.clone());
+		}
+	}
+
+	#[test]
+	fn dispatch_test() {
+		let events = Arc::new(Mutex::new(Vec::new()));
+		let mut bus = EventBus::new();
+		bus.subscribe(Box::new(Recorder(Arc::clone(&events))));
+
+		bus.publish(EventKind::LevelStarted { level: Level::One });
+		bus.tick();
+		bus.tick();
+		bus.publish(EventKind::Killed {
+			entity: Entity::CommonBeast,
+			position: Coord { column: 3, row: 4 },
+		});
+		assert!(events.lock().unwrap().is_empty(), "Events should only be handed out on dispatch");
+
+		let dispatched = bus.dispatch();
+		assert_eq!(dispatched.len(), 2, "All pending events should be dispatched");
+		assert_eq!(*events.lock().unwrap(), dispatched, "Subscribers should get every event in order");
+		assert_eq!(dispatched[1].tick, 2, "Events should be stamped with the tick they happened in");
+		assert!(bus.dispatch().is_empty(), "Events should only be dispatched once");
+	}
+
+	#[test]
+	fn event_log_test() {
+		let mut log = EventLog::new(Vec::new());
+		log.notify(&GameEvent {
+			tick: 7,
+			kind: EventKind::EggHatched {
+				position: Coord { column: 1, row: 2 },
+			},
+		});
+
+		assert_eq!(
+			String::from_utf8(log.writer).unwrap(),
+			"(tick:7,kind:EggHatched(position:(column:1,row:2)))\n",
+			"Every event should be written as a line of RON"
+		);
+	}
+
+	#[test]
+	fn from_tile_test() {
+		assert_eq!(Entity::from_tile(Tile::SuperBeast), Some(Entity::SuperBeast), "Beasts should be entities");
+		assert_eq!(Entity::from_tile(Tile::Block), None, "Blocks should not be entities");
+	}
+}
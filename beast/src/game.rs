@@ -1,22 +1,40 @@
 //! this module contains the main struct that orchestrates the game
 
 use std::{
-	io::{self, Read},
+	env,
 	sync::mpsc,
 	thread,
 	time::{Duration, Instant},
 };
+use time::OffsetDateTime;
 
 use crate::{
-	ANSI_BOLD, ANSI_LEFT_BORDER, ANSI_RESET, ANSI_RESET_BG, ANSI_RESET_FONT, ANSI_RIGHT_BORDER, BOARD_HEIGHT,
-	BOARD_WIDTH, Dir, LOGO, Tile,
-	beasts::{Beast, BeastAction, CommonBeast, Egg, HatchedBeast, HatchingState, SuperBeast},
+	BOARD_HEIGHT, BOARD_WIDTH, Coord, Dir, Tile,
+	accessibility::Announcer,
+	beasts::{
+		Beast, CommonBeast, Egg, FlowField, HATCHED_SEARCH_BUDGET, HatchedBeast, HatchingState, Hunt, Move, Movement,
+		Mover, Schedule, SuperBeast, resolve,
+	},
 	board::Board,
-	common::levels::Level,
+	common::levels::{LEVEL_COUNT, Level, LevelConfig},
+	config::Config,
+	events::{Entity, EventBus, EventKind, EventLog, Subscriber},
 	help::Help,
 	highscore::{Highscore, State},
+	input::{self, KeyEvent},
+	input_source::{self, InputSource},
+	keys::{Action, Key, KeyBindings},
+	local_highscore::LocalHighscores,
+	pathing::{get_next_coord, is_squishable},
 	player::{Player, PlayerAction},
-	stty::{RawMode, install_raw_mode_signal_handler},
+	renderer::{
+		self, Flash, Frame, Hud, Line, MIN_VIEWPORT_COLUMNS, MIN_VIEWPORT_ROWS, Overlay, Page, Renderer, RendererType,
+		ScreenType, Span, Training,
+	},
+	screen,
+	statistics::Statistics,
+	terminal::{self, RawMode, install_panic_hook, install_raw_mode_signal_handler},
+	theme,
 };
 
 /// the height of the board
@@ -27,10 +45,23 @@ pub const ANSI_FRAME_SIZE: usize = 1;
 pub const ANSI_HEADER_HEIGHT: usize = 4;
 /// the height of the footer
 pub const ANSI_FOOTER_HEIGHT: usize = 2;
+/// the smallest terminal width the whole board and the logo fit in
+pub const FULL_TERMINAL_WIDTH: usize = ANSI_FRAME_SIZE + (BOARD_WIDTH * 2) + ANSI_FRAME_SIZE;
+/// the smallest terminal height the whole board and the logo fit in
+pub const FULL_TERMINAL_HEIGHT: usize =
+	ANSI_HEADER_HEIGHT + ANSI_FRAME_SIZE + BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT + 2; // the extra space at the top and bottom
+/// the smallest terminal width the game can be played in by scrolling the board
+pub const MIN_TERMINAL_WIDTH: usize = ANSI_FRAME_SIZE + (MIN_VIEWPORT_COLUMNS * 2) + ANSI_FRAME_SIZE;
+/// the smallest terminal height the game can be played in by scrolling the board
+pub const MIN_TERMINAL_HEIGHT: usize = ANSI_FRAME_SIZE + MIN_VIEWPORT_ROWS + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT;
 /// the time between game ticks
 const TICK_DURATION: Duration = Duration::from_millis(200);
+/// how often we check the terminal size while it's too small
+const RESIZE_POLL_DURATION: Duration = Duration::from_millis(50);
+/// the longest we block waiting for input so we still notice resizes and being resumed quickly
+pub const INPUT_POLL_DURATION: Duration = Duration::from_millis(50);
 
-/// we need the [Beat] to count down when we call the beast advance methods and for animations
+/// we need the [Beat] to count down for animations, every beast moves on its own cooldown
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Beat {
 	One,
@@ -66,6 +97,8 @@ pub enum GameState {
 	Killing(Beat),
 	/// the level is done and we display a modal to give the player a short pause
 	LevelComplete,
+	/// the player paused the game
+	Paused,
 	/// displaying the help screen
 	Help,
 	/// displaying the highscore screen
@@ -100,38 +133,81 @@ pub struct Game {
 	pub player: Player,
 	/// the state the game is in
 	pub state: GameState,
+	/// the statistics of the current game broken down per level
+	pub statistics: Statistics,
+	/// the user config with cli flags applied
+	pub config: Config,
+	key_bindings: KeyBindings,
+	local_highscores: LocalHighscores,
+	local_entry: Option<OffsetDateTime>,
 	beat: Beat,
+	schedule: Schedule,
+	walking_field: FlowField,
+	pushing_field: FlowField,
+	/// the moves the beasts make on the next tick, shared by the tick and the training overlay
+	planned_moves: Vec<Move>,
+	/// the hatched beasts that planned a move as they are after it, keyed by where they stand now
+	planned_hatched: Vec<(Coord, HatchedBeast)>,
+	/// the tick and the board the moves were planned for, the beasts plan again when either changed
+	planned_for: Option<(u64, [[Tile; BOARD_WIDTH]; BOARD_HEIGHT])>,
+	/// if the training overlay is shown
+	training: bool,
+	/// if the training overlay was shown at any point of this game which makes it a practice game
+	training_used: bool,
+	announcer: Announcer,
+	events: EventBus,
 	input_listener: mpsc::Receiver<u8>,
-	_raw_mode: RawMode,
+	renderer: Box<dyn Renderer>,
+	/// the raw mode the terminal is kept in while the game runs, games that don't own the terminal have none
+	_raw_mode: Option<RawMode>,
 }
 
 impl Game {
-	/// create a new instance of the beast game
-	pub fn new() -> Self {
-		let board_terrain_info = Board::generate_terrain(Level::One);
+	/// create a new instance of the beast game reading input from the source picked in the config
+	pub fn new(config: Config) -> Self {
+		let input = input_source::from_config(&config).unwrap_or_else(|error| {
+			eprintln!("The input could not be opened: {error}");
+			std::process::exit(1);
+		});
 
 		install_raw_mode_signal_handler();
-		let _raw_mode = RawMode::enter().unwrap_or_else(|error| {
+		install_panic_hook();
+		let raw_mode = RawMode::enter().unwrap_or_else(|error| {
 			eprintln!("Raw mode could not be entered in this shell: {error}\x1b[?25h",);
 			std::process::exit(1);
 		});
-		let (sender, receiver) = mpsc::channel::<u8>();
-		{
-			let stdin = io::stdin();
-			thread::spawn(move || {
-				let mut lock = stdin.lock();
-				let mut buffer = [0u8; 1];
-				while lock.read_exact(&mut buffer).is_ok() {
-					if sender.send(buffer[0]).is_err() {
-						break;
-					}
-				}
-			});
+		if config.renderer == RendererType::Ansi {
+			screen::enter();
+		}
+
+		Self {
+			_raw_mode: Some(raw_mode),
+			..Self::with_input(config, input)
+		}
+	}
+
+	/// create a new instance of the beast game reading input from any source without setting up the terminal
+	pub fn with_input(config: Config, input: Box<dyn InputSource>) -> Self {
+		let level = config.start_level;
+		let board_terrain_info = Board::generate_terrain(level);
+
+		let mut events = EventBus::new();
+		if let Some(path) = &config.event_log {
+			match EventLog::create(path) {
+				Ok(log) => events.subscribe(Box::new(log)),
+				Err(error) => {
+					eprintln!("The event log {} could not be created: {error}", path.display());
+					std::process::exit(1);
+				},
+			}
 		}
+		events.publish(EventKind::LevelStarted { level });
+
+		let input_listener = input_source::listen(input);
 
 		Self {
 			board: Board::new(board_terrain_info.buffer),
-			level: Level::One,
+			level,
 			level_start: Instant::now(),
 			common_beasts: board_terrain_info.common_beasts,
 			super_beasts: board_terrain_info.super_beasts,
@@ -139,9 +215,25 @@ impl Game {
 			hatched_beasts: board_terrain_info.hatched_beasts,
 			player: board_terrain_info.player,
 			state: GameState::Intro,
+			statistics: Statistics::new(level),
+			key_bindings: config.key_bindings(),
+			renderer: config.renderer.create(config.accessibility),
+			training: config.training,
+			training_used: config.training,
+			config,
+			local_highscores: LocalHighscores::new(),
+			local_entry: None,
 			beat: Beat::One,
-			input_listener: receiver,
-			_raw_mode,
+			schedule: Schedule::new(),
+			walking_field: FlowField::new(Movement::Walking),
+			pushing_field: FlowField::new(Movement::Pushing).with_budget(HATCHED_SEARCH_BUDGET),
+			planned_moves: Vec::new(),
+			planned_hatched: Vec::new(),
+			planned_for: None,
+			announcer: Announcer::new(),
+			events,
+			input_listener,
+			_raw_mode: None,
 		}
 	}
 
@@ -150,6 +242,7 @@ impl Game {
 		let last_tick = Instant::now();
 
 		loop {
+			self.dispatch_events();
 			match self.state {
 				GameState::Intro => {
 					self.handle_intro_state();
@@ -160,6 +253,9 @@ impl Game {
 				GameState::LevelComplete => {
 					self.handle_level_complete();
 				},
+				GameState::Paused => {
+					self.handle_paused_state();
+				},
 				GameState::Help => {
 					self.handle_help_state();
 				},
@@ -176,6 +272,7 @@ impl Game {
 					self.handle_win_state();
 				},
 				GameState::Quit => {
+					screen::leave();
 					println!("Bye...");
 					break;
 				},
@@ -184,26 +281,35 @@ impl Game {
 	}
 
 	fn handle_intro_state(&mut self) {
-		println!("{}", Self::render_intro());
+		let mut page = self.render_intro();
+		self.renderer.render_page(&page);
 
 		loop {
-			if let Ok(byte) = self.input_listener.try_recv() {
-				match byte as char {
-					' ' => {
+			if Self::handle_redraw(self.renderer.as_mut()).is_some() {
+				self.renderer.render_page(&page);
+			}
+
+			if let Some(key) = self.read_key(INPUT_POLL_DURATION) {
+				match (key, self.key_bindings.action(key)) {
+					(_, Some(action @ (Action::Up | Action::Down))) => {
+						page = Self::scroll_page(page, action);
+						self.renderer.render_page(&page);
+					},
+					(Key::Char(' '), _) => {
 						self.level_start = Into::into(Instant::now());
 						self.state = GameState::Playing;
 						break;
 					},
-					'h' | 'H' => {
+					(_, Some(Action::Help)) => {
 						self.level_start = Into::into(Instant::now());
 						self.state = GameState::Help;
 						break;
 					},
-					's' | 'S' => {
+					(_, Some(Action::Highscore)) => {
 						self.state = GameState::HighScore;
 						break;
 					},
-					'q' | 'Q' => {
+					(_, Some(Action::Quit)) => {
 						self.state = GameState::Quit;
 						break;
 					},
@@ -214,96 +320,127 @@ impl Game {
 	}
 
 	fn handle_playing_state(&mut self, mut last_tick: Instant) {
-		print!("{}", self.render_board());
+		self.render_frame(None, None);
 
 		loop {
-			if let Ok(byte) = self.input_listener.try_recv() {
-				if byte == 0x1B {
-					let second = self.input_listener.recv().unwrap_or(0);
-					let third = self.input_listener.recv().unwrap_or(0);
-					if second == b'[' {
-						let mut render = false;
-						let player_action = match third {
-							b'A' => {
-								let player_action = self.player.advance(&mut self.board, &Dir::Up);
-								render = true;
-								player_action
-							},
-							b'C' => {
-								let player_action = self.player.advance(&mut self.board, &Dir::Right);
-								render = true;
-								player_action
-							},
-							b'B' => {
-								let player_action = self.player.advance(&mut self.board, &Dir::Down);
-								render = true;
-								player_action
-							},
-							b'D' => {
-								let player_action = self.player.advance(&mut self.board, &Dir::Left);
-								render = true;
-								player_action
-							},
-							_ => PlayerAction::None,
-						};
-
-						match player_action {
-							PlayerAction::KillCommonBeast(coord) => {
-								self.state = GameState::Killing(Beat::One);
-								if let Some(idx) = self.common_beasts.iter().position(|beast| beast.position == coord) {
-									self.common_beasts.swap_remove(idx);
-								}
-							},
-							PlayerAction::KillSuperBeast(coord) => {
-								self.state = GameState::Killing(Beat::One);
-								if let Some(idx) = self.super_beasts.iter().position(|beast| beast.position == coord) {
-									self.super_beasts.swap_remove(idx);
-								}
-							},
-							PlayerAction::KillEgg(coord) => {
-								self.state = GameState::Killing(Beat::One);
-								if let Some(idx) = self.eggs.iter().position(|egg| egg.position == coord) {
-									self.eggs.swap_remove(idx);
-								}
-							},
-							PlayerAction::KillHatchedBeast(coord) => {
-								self.state = GameState::Killing(Beat::One);
-								if let Some(idx) = self.hatched_beasts.iter().position(|beast| beast.position == coord) {
-									self.hatched_beasts.swap_remove(idx);
-								}
-							},
-							PlayerAction::KillPlayer => {
-								self.state = GameState::Dying(Beat::One);
-							},
-							PlayerAction::None => {},
-						}
+			if let Some(waited) = Self::handle_redraw(self.renderer.as_mut()) {
+				self.pause_timers(waited);
+				self.render_with_state();
+			}
 
-						if render {
-							// the player renders independent from the tick speed of the beasts
-							self.render_with_state();
-						}
+			if let Some(key) = self.read_key(Self::timeout_until(last_tick + TICK_DURATION)) {
+				let dir = match self.key_bindings.action(key) {
+					Some(Action::Up) => Some(Dir::Up),
+					Some(Action::Right) => Some(Dir::Right),
+					Some(Action::Down) => Some(Dir::Down),
+					Some(Action::Left) => Some(Dir::Left),
+					Some(Action::Quit) => {
+						self.state = GameState::Quit;
+						break;
+					},
+					Some(Action::Help) => {
+						self.state = GameState::Help;
+						break;
+					},
+					Some(Action::Highscore) => {
+						self.state = GameState::HighScore;
+						break;
+					},
+					Some(Action::Pause) => {
+						self.state = GameState::Paused;
+						break;
+					},
+					Some(Action::Restart) => {
+						self.start_new_game();
+						break;
+					},
+					Some(Action::Training) => {
+						self.training = !self.training;
+						self.training_used |= self.training;
+						self.render_with_state();
+						None
+					},
+					None => None,
+				};
+
+				if let Some(dir) = dir {
+					let from = self.player.position;
+					let player_action = self.player.advance(&mut self.board, &dir);
+
+					self.statistics.record_player_action(&player_action);
+					if !matches!(player_action, PlayerAction::KillPlayer) && self.player.position != from {
+						self.events.publish(EventKind::PlayerMoved {
+							dir,
+							position: self.player.position,
+						});
 					}
-				} else {
-					match byte as char {
-						'q' | 'Q' => {
-							self.state = GameState::Quit;
-							break;
+					match player_action {
+						PlayerAction::KillCommonBeast(coord) => {
+							self.state = GameState::Killing(Beat::One);
+							self.events.publish(EventKind::Killed {
+								entity: Entity::CommonBeast,
+								position: coord,
+							});
+							if let Some(idx) = self.common_beasts.iter().position(|beast| beast.position == coord) {
+								self.common_beasts.swap_remove(idx);
+							}
+						},
+						PlayerAction::KillSuperBeast(coord) => {
+							self.state = GameState::Killing(Beat::One);
+							self.events.publish(EventKind::Killed {
+								entity: Entity::SuperBeast,
+								position: coord,
+							});
+							if let Some(idx) = self.super_beasts.iter().position(|beast| beast.position == coord) {
+								self.super_beasts.swap_remove(idx);
+							}
+						},
+						PlayerAction::KillEgg(coord) => {
+							self.state = GameState::Killing(Beat::One);
+							self.events.publish(EventKind::Killed {
+								entity: Entity::Egg,
+								position: coord,
+							});
+							if let Some(idx) = self.eggs.iter().position(|egg| egg.position == coord) {
+								self.eggs.swap_remove(idx);
+							}
 						},
-						'h' | 'H' => {
-							self.state = GameState::Help;
-							break;
+						PlayerAction::KillHatchedBeast(coord) => {
+							self.state = GameState::Killing(Beat::One);
+							self.events.publish(EventKind::Killed {
+								entity: Entity::HatchedBeast,
+								position: coord,
+							});
+							if let Some(idx) = self.hatched_beasts.iter().position(|beast| beast.position == coord) {
+								self.hatched_beasts.swap_remove(idx);
+							}
 						},
-						's' | 'S' => {
-							self.state = GameState::HighScore;
-							break;
+						PlayerAction::KillPlayer => {
+							self.state = GameState::Dying(Beat::One);
+							// the player walked into a beast and was respawned so the beast is still where they went
+							if let Some(by) = get_next_coord(&from, &dir).and_then(|coord| Entity::from_tile(self.board[&coord])) {
+								self.events.publish(EventKind::PlayerKilled {
+									by,
+									position: from,
+									lives: self.player.lives,
+								});
+							}
 						},
-						_ => {},
+						PlayerAction::None => {},
 					}
+
+					// the player renders independent from the tick speed of the beasts
+					self.render_with_state();
 				}
 			}
 
 			// end game through time has ran out or no more lives
 			if self.player.lives == 0 || self.get_secs_remaining() == 0 {
+				self.statistics.finish_level(self.get_time_taken(), 0);
+				self.events.publish(EventKind::GameOver {
+					level: self.level,
+					score: self.player.score,
+				});
 				self.state = GameState::GameOver;
 				self.render_with_state();
 				break;
@@ -314,19 +451,28 @@ impl Game {
 				HatchingState::Incubating => true,
 				HatchingState::Hatching(position, instant) => {
 					self.board[&position] = Tile::EggHatching(instant);
+					self.events.publish(EventKind::EggHatching { position });
 					true
 				},
 				HatchingState::Hatched(position) => {
 					self.hatched_beasts.push(HatchedBeast::new(position));
 					self.board[&position] = Tile::HatchedBeast;
+					self.events.publish(EventKind::EggHatched { position });
 					false
 				},
 			});
+			self.announcer.time_warning(self.get_secs_remaining());
 
 			// end game through no more beasts
 			if self.common_beasts.len() + self.super_beasts.len() + self.eggs.len() + self.hatched_beasts.len() == 0 {
 				let secs_remaining = self.get_secs_remaining();
-				self.player.score += secs_remaining as u16 / 10;
+				let time_bonus = secs_remaining as u16 / 10;
+				self.player.score += time_bonus;
+				self.statistics.finish_level(self.get_time_taken(), time_bonus);
+				self.events.publish(EventKind::LevelComplete {
+					level: self.level,
+					time_bonus,
+				});
 
 				self.state = GameState::LevelComplete;
 				break;
@@ -334,33 +480,30 @@ impl Game {
 
 			// game tick
 			if last_tick.elapsed() >= TICK_DURATION {
-				if matches!(self.beat, Beat::Five) {
-					// beast movements
-					for common_beasts in &mut self.common_beasts {
-						if matches!(common_beasts.advance(&mut self.board, self.player.position), BeastAction::PlayerKilled) {
-							self.player.lives -= 1;
-							self.player.respawn(&mut self.board);
-							self.state = GameState::Dying(Beat::One);
-						}
-					}
-					for super_beasts in &mut self.super_beasts {
-						if matches!(super_beasts.advance(&mut self.board, self.player.position), BeastAction::PlayerKilled) {
-							self.player.lives -= 1;
-							self.player.respawn(&mut self.board);
-							self.state = GameState::Dying(Beat::One);
-						}
-					}
-					for hatched_beasts in &mut self.hatched_beasts {
-						if matches!(hatched_beasts.advance(&mut self.board, self.player.position), BeastAction::PlayerKilled) {
-							self.player.lives -= 1;
-							self.player.respawn(&mut self.board);
-							self.state = GameState::Dying(Beat::One);
-						}
-					}
+				// every kind of beast moves at its own speed
+				let killed_by = self.advance_beasts();
+				// the tick counts before rendering so the training overlay shows the moves planned for the next one
+				self.schedule.tick();
+				if let Some(by) = killed_by {
+					let position = self.player.position;
+					self.player.lives -= 1;
+					self.statistics.record_life_lost();
+					self.player.respawn(&mut self.board);
+					self.state = GameState::Dying(Beat::One);
+					self.events.publish(EventKind::PlayerKilled {
+						by,
+						position,
+						lives: self.player.lives,
+					});
 				}
 
 				// end game through no more lives left
 				if self.player.lives == 0 {
+					self.statistics.finish_level(self.get_time_taken(), 0);
+					self.events.publish(EventKind::GameOver {
+						level: self.level,
+						score: self.player.score,
+					});
 					self.state = GameState::GameOver;
 					break;
 				}
@@ -368,34 +511,45 @@ impl Game {
 				// render with Dying and Killing animation
 				self.render_with_state();
 				self.beat = self.beat.next();
+				self.events.tick();
 				last_tick = Instant::now();
 			}
 		}
 	}
 
 	fn handle_death_state(&mut self) {
-		println!("{}", self.render_death_screen());
+		self.record_local_highscore();
+		let mut page = self.render_death_screen();
+		self.renderer.render_page(&page);
 
 		loop {
-			if let Ok(byte) = self.input_listener.try_recv() {
-				match byte as char {
-					' ' => {
+			if Self::handle_redraw(self.renderer.as_mut()).is_some() {
+				self.renderer.render_page(&page);
+			}
+
+			if let Some(key) = self.read_key(INPUT_POLL_DURATION) {
+				match (key, self.key_bindings.action(key)) {
+					(_, Some(action @ (Action::Up | Action::Down))) => {
+						page = Self::scroll_page(page, action);
+						self.renderer.render_page(&page);
+					},
+					(Key::Char(' '), _) => {
 						self.start_new_game();
 						break;
 					},
-					'\n' => {
+					(Key::Enter, _) => {
 						self.state = GameState::EnterHighScore;
 						break;
 					},
-					'h' | 'H' => {
+					(_, Some(Action::Help)) => {
 						self.state = GameState::Help;
 						break;
 					},
-					's' | 'S' => {
+					(_, Some(Action::Highscore)) => {
 						self.state = GameState::HighScore;
 						break;
 					},
-					'q' | 'Q' => {
+					(_, Some(Action::Quit)) => {
 						self.state = GameState::Quit;
 						break;
 					},
@@ -406,28 +560,73 @@ impl Game {
 	}
 
 	fn handle_win_state(&mut self) {
-		println!("{}", self.render_winning_screen());
+		self.record_local_highscore();
+		let mut page = self.render_winning_screen();
+		self.renderer.render_page(&page);
 
 		loop {
-			if let Ok(byte) = self.input_listener.try_recv() {
-				match byte as char {
-					' ' => {
+			if Self::handle_redraw(self.renderer.as_mut()).is_some() {
+				self.renderer.render_page(&page);
+			}
+
+			if let Some(key) = self.read_key(INPUT_POLL_DURATION) {
+				match (key, self.key_bindings.action(key)) {
+					(_, Some(action @ (Action::Up | Action::Down))) => {
+						page = Self::scroll_page(page, action);
+						self.renderer.render_page(&page);
+					},
+					(Key::Char(' '), _) => {
 						self.start_new_game();
 						break;
 					},
-					'\n' => {
+					(Key::Enter, _) => {
 						self.state = GameState::EnterHighScore;
 						break;
 					},
-					'h' | 'H' => {
+					(_, Some(Action::Help)) => {
 						self.state = GameState::Help;
 						break;
 					},
-					's' | 'S' => {
+					(_, Some(Action::Highscore)) => {
 						self.state = GameState::HighScore;
 						break;
 					},
-					'q' | 'Q' => {
+					(_, Some(Action::Quit)) => {
+						self.state = GameState::Quit;
+						break;
+					},
+					_ => {},
+				}
+			}
+		}
+	}
+
+	fn handle_paused_state(&mut self) {
+		let pause = Instant::now();
+		self.render_with_state();
+
+		loop {
+			if Self::handle_redraw(self.renderer.as_mut()).is_some() {
+				self.render_with_state();
+			}
+
+			if let Some(key) = self.read_key(INPUT_POLL_DURATION) {
+				match (key, self.key_bindings.action(key)) {
+					(Key::Char(' '), _) | (_, Some(Action::Pause)) => {
+						self.pause_timers(pause.elapsed());
+						self.state = GameState::Playing;
+						break;
+					},
+					(_, Some(Action::Help)) => {
+						self.pause_timers(pause.elapsed());
+						self.state = GameState::Help;
+						break;
+					},
+					(_, Some(Action::Restart)) => {
+						self.start_new_game();
+						break;
+					},
+					(_, Some(Action::Quit)) => {
 						self.state = GameState::Quit;
 						break;
 					},
@@ -437,86 +636,107 @@ impl Game {
 		}
 	}
 
+	/// award the completion score of the level just finished and move on to the next level or win the game
+	fn complete_level(&mut self) {
+		let completion_score = self.level.get_config().completion_score;
+		self.player.score += completion_score;
+		self.statistics.add_score(completion_score);
+
+		if let Some(level) = self.level.next() {
+			let board_terrain_info = Board::generate_terrain(level);
+			self.level = level;
+			self.board = Board::new(board_terrain_info.buffer);
+			self.level_start = Instant::now();
+			self.common_beasts = board_terrain_info.common_beasts;
+			self.super_beasts = board_terrain_info.super_beasts;
+			self.eggs = board_terrain_info.eggs;
+			self.hatched_beasts = board_terrain_info.hatched_beasts;
+			self.player.position = board_terrain_info.player.position;
+			self.player.last_dir = None;
+			self.statistics.start_level(level);
+			self.announcer.reset();
+			self.schedule.reset();
+			self.events.publish(EventKind::LevelStarted { level });
+			self.state = GameState::Playing;
+		} else {
+			self.events.publish(EventKind::GameWon {
+				score: self.player.score,
+			});
+			self.state = GameState::Won;
+		}
+	}
+
 	fn handle_level_complete(&mut self) {
-		let time = Instant::now();
+		let mut time = Instant::now();
 		let mut last_update = time;
 		let total_duration = 5000;
 
-		print!("{}", Self::alert("LEVEL COMPLETED", 0));
+		let summary = self.get_level_summary();
+		self.render_level_summary(&summary, 0);
 		loop {
+			if let Some(waited) = Self::handle_redraw(self.renderer.as_mut()) {
+				time += waited;
+				self.render_level_summary(&summary, 0);
+			}
+
 			let elapsed = time.elapsed().as_millis();
 			if elapsed > total_duration {
-				if let Some(level) = self.level.next() {
-					let board_terrain_info = Board::generate_terrain(level);
-					self.level = level;
-					self.board = Board::new(board_terrain_info.buffer);
-					self.level_start = Instant::now();
-					self.common_beasts = board_terrain_info.common_beasts;
-					self.super_beasts = board_terrain_info.super_beasts;
-					self.eggs = board_terrain_info.eggs;
-					self.hatched_beasts = board_terrain_info.hatched_beasts;
-					self.player.position = board_terrain_info.player.position;
-					self.player.score += self.level.get_config().completion_score;
-					self.state = GameState::Playing;
-				} else {
-					self.state = GameState::Won;
-				}
+				self.complete_level();
 				break;
 			}
 
 			if last_update.elapsed().as_millis() > 500 {
 				let progress = ((elapsed * 100) / total_duration) as usize + 8;
-				print!("{}", Self::alert("LEVEL COMPLETED", progress));
+				self.render_level_summary(&summary, progress);
 				last_update = Instant::now();
 			}
+
+			// keys pressed during the summary are left for the next level
+			thread::sleep(Self::timeout_until(last_update + Duration::from_millis(500)));
 		}
 	}
 
 	fn handle_help_state(&mut self) {
 		let pause = Instant::now();
-		let mut help = Help::new();
-		println!("{}", help.render());
+		let mut help = Help::new(&self.key_bindings);
+		let mut page = help.render();
+		self.renderer.render_page(&page);
 
 		loop {
-			if let Ok(byte) = self.input_listener.try_recv() {
-				if byte == 0x1B {
-					let second = self.input_listener.recv().unwrap_or(0);
-					let third = self.input_listener.recv().unwrap_or(0);
-					if second == b'[' {
-						let mut render = false;
-						match third {
-							b'C' => {
-								help.next_page();
-								render = true;
-							},
-							b'D' => {
-								help.previous_page();
-								render = true;
-							},
-							_ => {},
-						}
+			if Self::handle_redraw(self.renderer.as_mut()).is_some() {
+				self.renderer.render_page(&page);
+			}
 
-						if render {
-							println!("{}", help.render());
-						}
-					}
-				} else {
-					match byte as char {
-						' ' => {
-							self.level_start += pause.elapsed();
-							self.state = GameState::Playing;
-							break;
-						},
-						's' | 'S' => {
-							self.state = GameState::HighScore;
-							break;
-						},
-						'q' | 'Q' => {
-							self.state = GameState::Quit;
-							break;
-						},
-						_ => {},
-					}
+			if let Some(key) = self.read_key(INPUT_POLL_DURATION) {
+				match (key, self.key_bindings.action(key)) {
+					(Key::Tab, _) | (_, Some(Action::Right)) => {
+						help.next_page();
+						page = help.render();
+						self.renderer.render_page(&page);
+					},
+					(Key::BackTab, _) | (_, Some(Action::Left)) => {
+						help.previous_page();
+						page = help.render();
+						self.renderer.render_page(&page);
+					},
+					(_, Some(action @ (Action::Up | Action::Down))) => {
+						page = Self::scroll_page(page, action);
+						self.renderer.render_page(&page);
+					},
+					(Key::Char(' '), _) => {
+						self.pause_timers(pause.elapsed());
+						self.state = GameState::Playing;
+						break;
+					},
+					(_, Some(Action::Highscore)) => {
+						self.state = GameState::HighScore;
+						break;
+					},
+					(_, Some(Action::Quit)) => {
+						self.state = GameState::Quit;
+						break;
+					},
+					_ => {},
 				}
 			}
 		}
@@ -524,82 +744,294 @@ impl Game {
 
 	fn handle_highscore_state(&mut self) {
 		let pause = Instant::now();
-		let mut highscore = Highscore::new_loading();
-		println!("{}", highscore.render());
+		let mut highscore = Highscore::new_loading(&self.config);
+		let mut page = highscore.render();
+		self.renderer.render_page(&page);
 
 		loop {
-			if let Ok(byte) = self.input_listener.try_recv() {
-				if byte == 0x1B {
-					let second = self.input_listener.recv().unwrap_or(0);
-					let third = self.input_listener.recv().unwrap_or(0);
-					if second == b'[' {
-						let mut render = false;
-						match third {
-							b'A' => {
-								if *highscore.state.lock().unwrap() == State::Idle {
-									highscore.scroll_up();
-									render = true;
-								}
-							},
-							b'B' => {
-								if *highscore.state.lock().unwrap() == State::Idle {
-									highscore.scroll_down();
-									render = true;
-								}
-							},
-							_ => {},
-						}
+			if Self::handle_redraw(self.renderer.as_mut()).is_some() {
+				self.renderer.render_page(&page);
+			}
 
-						if render {
-							println!("{}", highscore.render());
+			if let Some(key) = self.read_key(INPUT_POLL_DURATION) {
+				match (key, self.key_bindings.action(key)) {
+					(_, Some(Action::Up)) if highscore.can_scroll() => {
+						highscore.scroll_up();
+					},
+					(_, Some(Action::Down)) if highscore.can_scroll() => {
+						highscore.scroll_down(renderer::max_scroll(&page));
+					},
+					(Key::Tab | Key::BackTab, _) | (_, Some(Action::Left | Action::Right)) => {
+						highscore.switch_tab();
+					},
+					(_, Some(Action::Restart)) => {
+						highscore.refresh();
+					},
+					(Key::Char(' '), _) => {
+						if let Ok(mut state) = highscore.state.lock() {
+							*state = State::Quit;
 						}
-					}
-				} else {
-					match byte as char {
-						'r' | 'R' => {
-							if let Ok(mut state) = highscore.state.lock() {
-								if *state == State::Idle || *state == State::Error {
-									*state = State::Loading;
-									highscore.fetch_data();
-									highscore.render_loading();
-									println!("{}", Highscore::render_loading_screen());
-								}
-							}
-						},
-						' ' => {
-							if let Ok(mut state) = highscore.state.lock() {
-								*state = State::Quit;
-							}
-							self.level_start += pause.elapsed();
-							self.state = GameState::Playing;
-							break;
-						},
-						'h' | 'H' => {
-							if let Ok(mut state) = highscore.state.lock() {
-								*state = State::Quit;
-							}
-							self.state = GameState::Help;
-							break;
-						},
-						'q' | 'Q' => {
-							self.state = GameState::Quit;
-							break;
-						},
-						_ => {},
-					}
+						self.pause_timers(pause.elapsed());
+						self.state = GameState::Playing;
+						break;
+					},
+					(_, Some(Action::Help)) => {
+						if let Ok(mut state) = highscore.state.lock() {
+							*state = State::Quit;
+						}
+						self.state = GameState::Help;
+						break;
+					},
+					(_, Some(Action::Quit)) => {
+						self.state = GameState::Quit;
+						break;
+					},
+					_ => {},
 				}
 			}
+
+			// the scores load and the loading animation plays in the background
+			let next_page = highscore.render();
+			if next_page != page {
+				page = next_page;
+				self.renderer.render_page(&page);
+			}
 		}
 	}
 
 	fn handle_enter_highscore_state(&mut self) {
-		let mut highscore = Highscore::new_idle();
-		if highscore.handle_enter_name(&self.input_listener, self.player.score, self.level).is_some() {
+		let mut highscore = Highscore::new_idle(&self.config);
+		let ranked = self.is_ranked();
+		if highscore
+			.handle_enter_name(
+				&self.input_listener,
+				self.renderer.as_mut(),
+				self.player.score,
+				self.level,
+				self.local_entry,
+				ranked,
+			)
+			.is_some()
+		{
 			self.start_new_game();
 		}
 		self.state = GameState::HighScore;
 	}
 
+	/// plan the moves of every beast that is due on the next tick, all beasts plan their move on the same board and
+	/// conflicts are resolved after so no beast chases a player that respawned in the same tick, the plan is kept until
+	/// the tick or the board changed so the training overlay shows exactly what the beasts do next, beasts plan on a
+	/// copy of themselves so planning more often doesn't change what they do
+	fn plan_beasts(&mut self) {
+		let planned_for = (self.schedule.ticks(), self.board.buffer);
+		if self.planned_for == Some(planned_for) {
+			return;
+		}
+
+		let level_config = self.level.get_config();
+		let half_speed = self.config.slow_beasts;
+		let snapshot = self.board;
+		let mut scratch = snapshot;
+		let player_position = self.player.position;
+		let hunt = self.hunt(&level_config);
+		let mut moves = Vec::new();
+
+		// every beast counts down its own cooldown so beasts that hatched later move out of step with the others
+		let common_interval = Schedule::interval(Mover::Common, &level_config, half_speed);
+		let super_interval = Schedule::interval(Mover::Super, &level_config, half_speed);
+		let hatched_interval = Schedule::interval(Mover::Hatched, &level_config, half_speed);
+
+		for beast in self.common_beasts.iter().filter(|beast| beast.cooldown.is_due(common_interval)) {
+			let mut beast = beast.clone();
+			moves.extend(Move::plan(Mover::Common, beast.position, &mut scratch, &snapshot, |board| {
+				beast.advance_as(board, &hunt);
+				beast.position
+			}));
+		}
+		// all super beasts share one flow field computed once per tick
+		if self.super_beasts.iter().any(|beast| beast.cooldown.is_due(super_interval)) {
+			self.walking_field.update(&snapshot, player_position);
+		}
+		for beast in self.super_beasts.iter().filter(|beast| beast.cooldown.is_due(super_interval)) {
+			let mut beast = beast.clone();
+			moves.extend(Move::plan(Mover::Super, beast.position, &mut scratch, &snapshot, |board| {
+				beast.advance_as(board, &self.walking_field, &hunt);
+				beast.position
+			}));
+		}
+		// hatched beasts follow their cached paths and only read the field when they need a new one
+		if self.hatched_beasts.iter().any(|beast| {
+			beast.cooldown.is_due(hatched_interval) && beast.needs_path(&snapshot, player_position, hunt.danger_aware)
+		}) {
+			self.pushing_field.update(&snapshot, player_position);
+		}
+		let mut planned_hatched = Vec::new();
+		for beast in self.hatched_beasts.iter().filter(|beast| beast.cooldown.is_due(hatched_interval)) {
+			let from = beast.position;
+			let mut beast = beast.clone();
+			moves.extend(Move::plan(Mover::Hatched, from, &mut scratch, &snapshot, |board| {
+				beast.advance_as(board, &self.pushing_field, &hunt);
+				beast.position
+			}));
+			planned_hatched.push((from, beast));
+		}
+
+		self.planned_moves = resolve(moves);
+		self.planned_hatched = planned_hatched;
+		self.planned_for = Some(planned_for);
+	}
+
+	/// move every beast that is due this tick the way it planned to and count down the cooldowns of all beasts, returns
+	/// who killed the player if anyone did
+	fn advance_beasts(&mut self) -> Option<Entity> {
+		self.plan_beasts();
+		let level_config = self.level.get_config();
+		let half_speed = self.config.slow_beasts;
+		let player_position = self.player.position;
+
+		let common_interval = Schedule::interval(Mover::Common, &level_config, half_speed);
+		let super_interval = Schedule::interval(Mover::Super, &level_config, half_speed);
+		let hatched_interval = Schedule::interval(Mover::Hatched, &level_config, half_speed);
+		for beast in &mut self.common_beasts {
+			beast.cooldown.tick(common_interval);
+		}
+		for beast in &mut self.super_beasts {
+			beast.cooldown.tick(super_interval);
+		}
+		for beast in &mut self.hatched_beasts {
+			beast.cooldown.tick(hatched_interval);
+		}
+
+		let moves = std::mem::take(&mut self.planned_moves);
+		let planned_hatched = std::mem::take(&mut self.planned_hatched);
+		self.planned_for = None;
+		for planned in &moves {
+			planned.apply(&mut self.board);
+			match planned.mover {
+				Mover::Common => {
+					if let Some(beast) = self.common_beasts.iter_mut().find(|beast| beast.position == planned.from) {
+						beast.position = planned.to;
+					}
+				},
+				Mover::Super => {
+					if let Some(beast) = self.super_beasts.iter_mut().find(|beast| beast.position == planned.from) {
+						beast.position = planned.to;
+					}
+				},
+				Mover::Hatched => {
+					// only a move that is carried out uses up a step of the path the beast planned it with
+					let next = planned_hatched.iter().find(|(from, _)| *from == planned.from).map(|(_, beast)| beast);
+					if let (Some(beast), Some(next)) =
+						(self.hatched_beasts.iter_mut().find(|beast| beast.position == planned.from), next)
+					{
+						let cooldown = beast.cooldown;
+						*beast = next.clone();
+						beast.cooldown = cooldown;
+					}
+				},
+			}
+		}
+
+		moves
+			.iter()
+			.find(|planned| planned.kills(player_position))
+			.and_then(|planned| Entity::from_tile(planned.mover.tile()))
+	}
+
+	/// what beasts know about the player when they plan their moves
+	fn hunt(&self, level_config: &LevelConfig) -> Hunt {
+		Hunt {
+			player_position: self.player.position,
+			player_dir: self.player.last_dir,
+			tick: self.schedule.ticks(),
+			danger_aware: level_config.danger_aware_beasts,
+		}
+	}
+
+	/// the hints of the training overlay, the intents are the moves the beasts planned for the next tick
+	fn training_hints(&self) -> Training {
+		let level_config = self.level.get_config();
+		let player_position = self.player.position;
+
+		let beasts = self
+			.common_beasts
+			.iter()
+			.map(|beast| beast.position)
+			.chain(self.super_beasts.iter().map(|beast| beast.position))
+			.chain(self.hatched_beasts.iter().map(|beast| beast.position))
+			.chain(self.eggs.iter().map(|egg| egg.position));
+
+		Training {
+			intents: self.planned_moves.iter().map(|planned| planned.to).collect(),
+			squishable: beasts.filter(|coord| is_squishable(&self.board, coord, &player_position)).collect(),
+			eggs: self.eggs.iter().map(|egg| (egg.position, egg.secs_until_hatched(&level_config))).collect(),
+		}
+	}
+
+	/// games started past the first level, played offline or with the training overlay are not ranked globally
+	fn is_ranked(&self) -> bool {
+		self.config.is_ranked() && !self.training_used
+	}
+
+	/// check if the terminal was resized or we were resumed after being suspended and wait while the terminal is too
+	/// small for the game, returns how long the game was interrupted once the renderer is ready to draw from scratch
+	pub fn handle_redraw(renderer: &mut dyn Renderer) -> Option<Duration> {
+		if !terminal::take_redraw() {
+			return None;
+		}
+
+		let start = Instant::now();
+		while let Ok((columns, rows)) = terminal::terminal_size() {
+			screen::resize(columns, rows);
+			if columns >= MIN_TERMINAL_WIDTH && rows >= MIN_TERMINAL_HEIGHT {
+				break;
+			}
+
+			renderer.render_page(&Self::render_too_small(columns, rows));
+			while !terminal::take_redraw() {
+				thread::sleep(RESIZE_POLL_DURATION);
+			}
+		}
+
+		renderer.clear();
+		Some(start.elapsed() + terminal::take_suspension().unwrap_or_default())
+	}
+
+	/// move the level timer and the eggs forward so no time passes for them while the game is paused
+	fn pause_timers(&mut self, duration: Duration) {
+		self.level_start += duration;
+		for egg in &mut self.eggs {
+			egg.delay(duration);
+		}
+	}
+
+	/// how long to block for until the deadline, capped so we keep checking for a redraw
+	fn timeout_until(deadline: Instant) -> Duration {
+		deadline.saturating_duration_since(Instant::now()).min(INPUT_POLL_DURATION)
+	}
+
+	/// wait for the next key until the timeout passed
+	fn read_key(&self, timeout: Duration) -> Option<Key> {
+		match input::read_event(&self.input_listener, timeout)? {
+			// we leave combinations with ctrl and alt to the terminal
+			KeyEvent::Key { key, modifiers } if modifiers.is_plain() => Some(key),
+			_ => None,
+		}
+	}
+
+	fn record_local_highscore(&mut self) {
+		if self.local_entry.is_none() {
+			let name =
+				(self.config.name.clone()).or_else(|| env::var("USER").ok()).unwrap_or_else(|| String::from("anonymous"));
+			let practice = !self.is_ranked();
+			self.local_entry = self.local_highscores.record(&name, self.player.score, self.level, practice).ok();
+		}
+	}
+
+	fn get_time_taken(&self) -> Duration {
+		self.level_start.elapsed().min(self.level.get_config().time)
+	}
+
 	fn get_secs_remaining(&self) -> u64 {
 		let elapsed = Instant::now().duration_since(self.level_start);
 		let total_time = self.level.get_config().time;
@@ -611,245 +1043,342 @@ impl Game {
 		.as_secs()
 	}
 
-	fn get_game_statistics(&self) -> String {
-		let mut output = String::new();
-		output.push_str(&format!("{ANSI_LEFT_BORDER}     REACHED SCORE:     {ANSI_BOLD}{:<4}{ANSI_RESET}                                                                        {ANSI_RIGHT_BORDER}\n", self.player.score));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}     LEVEL REACHED:     {ANSI_BOLD}{:<2}{ANSI_RESET}                                                                          {ANSI_RIGHT_BORDER}\n", self.level.to_string()));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}     BEASTS KILLED:     {ANSI_BOLD}{:<4}{ANSI_RESET}                                                                        {ANSI_RIGHT_BORDER}\n", self.player.beasts_killed.to_string()));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}     BLOCKS MOVED:      {ANSI_BOLD}{:<6}{ANSI_RESET}                                                                      {ANSI_RIGHT_BORDER}\n", self.player.blocks_moved.to_string()));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}     DISTANCE TRAVELED: {ANSI_BOLD}{:<6}{ANSI_RESET}                                                                      {ANSI_RIGHT_BORDER}\n", self.player.distance_traveled.to_string()));
-		output
+	fn format_duration(duration: Duration) -> String {
+		let secs = duration.as_secs();
+		format!("{:02}:{:02}", secs / 60, secs % 60)
+	}
+
+	fn get_game_statistics(&self) -> Vec<Line> {
+		let glyphs = &theme::current().tiles;
+		let mut lines = vec![
+			Line::new(vec![
+				Span::plain("     REACHED SCORE:     "),
+				Span::bold(format!("{:<6}", self.player.score)),
+				Span::plain("    LEVEL REACHED:     "),
+				Span::bold(format!("{:<2}", self.level.to_string())),
+			]),
+			Line::new(vec![
+				Span::plain("     BEASTS KILLED:     "),
+				Span::bold(format!("{:<6}", self.player.beasts_killed)),
+				Span::plain("    BLOCKS MOVED:      "),
+				Span::bold(format!("{:<6}", self.player.blocks_moved)),
+				Span::plain("    DISTANCE TRAVELED: "),
+				Span::bold(format!("{:<6}", self.player.distance_traveled)),
+			]),
+			Line::blank(),
+			Line::new(vec![
+				Span::plain("     "),
+				Span::muted(format!(
+					"LEVEL    {}    {}    {}    {}    LIVES LOST    TIME     TIME BONUS    SCORE",
+					glyphs.common_beast.glyph, glyphs.super_beast.glyph, glyphs.egg.glyph, glyphs.hatched_beast.glyph,
+				)),
+			]),
+		];
+		for index in 0..LEVEL_COUNT {
+			lines.push(match self.statistics.levels.get(index) {
+				Some(level_statistics) => Line::new(vec![
+					Span::plain(format!(
+						"     {:<5}   {:>3}   {:>3}   {:>3}   {:>3}    {:>10}    {:>5}    {:>10}    ",
+						level_statistics.level.to_string(),
+						level_statistics.common_beasts_killed,
+						level_statistics.super_beasts_killed,
+						level_statistics.eggs_killed,
+						level_statistics.hatched_beasts_killed,
+						level_statistics.lives_lost,
+						Self::format_duration(level_statistics.time_taken),
+						level_statistics.time_bonus,
+					)),
+					Span::bold(format!("{:>5}", level_statistics.score)),
+				]),
+				None => Line::blank(),
+			});
+		}
+		lines
+	}
+
+	fn get_level_summary(&self) -> String {
+		let level_statistics = self.statistics.current();
+		format!(
+			"KILLS {}  LIVES LOST {}  TIME {}  TIME BONUS {}  SCORE {}",
+			level_statistics.beasts_killed(),
+			level_statistics.lives_lost,
+			Self::format_duration(level_statistics.time_taken),
+			level_statistics.time_bonus,
+			level_statistics.score,
+		)
 	}
 
 	fn start_new_game(&mut self) {
-		let board_terrain_info = Board::generate_terrain(Level::One);
+		let board_terrain_info = Board::generate_terrain(self.config.start_level);
 		self.board = Board::new(board_terrain_info.buffer);
-		self.level = Level::One;
+		self.level = self.config.start_level;
 		self.level_start = Instant::now();
 		self.common_beasts = board_terrain_info.common_beasts;
 		self.super_beasts = board_terrain_info.super_beasts;
 		self.eggs = board_terrain_info.eggs;
 		self.hatched_beasts = board_terrain_info.hatched_beasts;
 		self.player = board_terrain_info.player;
+		self.statistics = Statistics::new(self.config.start_level);
+		self.local_entry = None;
+		self.training_used = self.training;
+		self.announcer.reset();
+		self.schedule.reset();
+		self.events.publish(EventKind::LevelStarted { level: self.level });
 
 		self.state = GameState::Playing;
 	}
 
-	fn render_header(output: &mut String) {
-		output.push('\n');
-		output.push_str(" ╔╗  ╔═╗ ╔═╗ ╔═╗ ╔╦╗\n");
-		output.push_str(" ╠╩╗ ║╣  ╠═╣ ╚═╗  ║\n");
-		output.push_str(" ╚═╝ ╚═╝ ╩ ╩ ╚═╝  ╩\n");
+	/// hand the events published since the last frame to the subscribers and the announcer
+	fn dispatch_events(&mut self) {
+		for event in self.events.dispatch() {
+			self.announcer.notify(&event);
+		}
 	}
 
-	fn render_footer(&self) -> String {
-		let mut output = String::new();
+	fn hud(&self) -> Hud {
 		let secs_remaining = self.get_secs_remaining();
-		let minutes = secs_remaining / 60;
-		let seconds = secs_remaining % 60;
 		let elapsed = self.level_start.elapsed();
 		let tick_count = elapsed.as_millis() / TICK_DURATION.as_millis();
-		let timer_color = if tick_count % 2 == 0 && minutes == 0 && seconds < 20 || minutes == 0 && seconds == 0 {
-			"\x1b[31m"
-		} else {
-			ANSI_RESET_FONT
-		};
 
-		let lives = if self.player.lives == 1 {
-			format!("\x1B[31m{}{ANSI_RESET_FONT}", self.player.lives)
-		} else {
-			self.player.lives.to_string()
+		Hud {
+			beasts: self.common_beasts.len() + self.super_beasts.len() + self.hatched_beasts.len(),
+			level: self.level,
+			secs_remaining,
+			// the timer blinks unless flashing is turned off for accessibility
+			timer_warning: (self.config.accessibility || tick_count.is_multiple_of(2)) && secs_remaining < 20
+				|| secs_remaining == 0,
+			lives: self.player.lives,
+			score: self.player.score,
+			training: self.training,
+		}
+	}
+
+	/// the status line of the accessibility mode
+	fn announcement(&self) -> String {
+		let beasts = self
+			.common_beasts
+			.iter()
+			.map(|beast| beast.position)
+			.chain(self.super_beasts.iter().map(|beast| beast.position))
+			.chain(self.hatched_beasts.iter().map(|beast| beast.position))
+			.chain(self.eggs.iter().map(|egg| egg.position));
+		self.announcer.message(self.player.position, beasts)
+	}
+
+	/// move a page one line up or down, pages only scroll when the terminal is too small for them
+	fn scroll_page(page: Page, action: Action) -> Page {
+		let scroll = match action {
+			Action::Up => page.scroll.saturating_sub(1),
+			_ => (page.scroll + 1).min(renderer::max_scroll(&page)),
 		};
+		Page { scroll, ..page }
+	}
+
+	/// the notice shown while the terminal is too small to play in
+	fn render_too_small(columns: usize, rows: usize) -> Page {
+		Page {
+			logo: false,
+			..Page::new(
+				ScreenType::TooSmall,
+				vec![
+					Line::new(vec![Span::bold("RESIZE YOUR TERMINAL")]),
+					Line::blank(),
+					Line::plain(format!("The game needs at least {MIN_TERMINAL_WIDTH} x {MIN_TERMINAL_HEIGHT}")),
+					Line::new(vec![
+						Span::plain("but the terminal is "),
+						Span::warning(format!("{columns} x {rows}")),
+						Span::plain("."),
+					]),
+					Line::blank(),
+					Line::plain("The game is paused until there is enough room."),
+				],
+				Vec::new(),
+			)
+		}
+	}
+
+	fn render_intro(&self) -> Page {
+		let centered = |text: &str| Line::centered(vec![Span::plain(text)]);
+		Page::new(
+			ScreenType::Intro,
+			vec![
+				Line::blank(),
+				centered("Written and Developed by the following"),
+				centered("Dominik Wilkowski"),
+				Line::blank(),
+				Line::blank(),
+				Line::blank(),
+				centered("Faithfully recreated from the work of"),
+				centered("Dan Baker , Alan Brown , Mark Hamilton , Derrick Shadel"),
+				Line::blank(),
+				Line::blank(),
+				Line::blank(),
+				Line::plain("             NOTICE:    This is a Free copy of BEAST. You may copy it and give it away."),
+				Line::plain("                        If you enjoy the game, please send a contribution ($20) to"),
+				Line::plain("                        Dan Baker, PO BOX 1174, Orem UT 84057"),
+				Line::blank(),
+				Line::blank(),
+				Line::blank(),
+			],
+			vec![
+				Line::centered(vec![
+					Span::plain("Press "),
+					Span::bold("[SPACE]"),
+					Span::plain(" key to start"),
+				]),
+				Line::centered(vec![
+					self.key_bindings.label(Action::Quit),
+					Span::plain(" Quit  "),
+					self.key_bindings.label(Action::Help),
+					Span::plain(" Help  "),
+					self.key_bindings.label(Action::Highscore),
+					Span::plain(" Highscores"),
+				]),
+				Line::blank(),
+			],
+		)
+	}
+
+	fn render_log_score_prompt(&self) -> Line {
+		let register = if self.is_ranked() { "GLOBAL" } else { "LOCAL" };
+		Line::centered(vec![
+			Span::plain("PRESS "),
+			Span::bold("[ENTER]"),
+			Span::plain(format!(" TO LOG YOUR SCORE IN THE {register} HIGHSCORE REGISTER")),
+		])
+	}
+
+	/// the page after the game ended with the statistics of every level
+	fn render_end_screen(&self, screen: ScreenType, title: &str) -> Page {
+		let mut lines = vec![Line::centered(vec![Span::bold(title)]), Line::blank()];
+		lines.extend(self.get_game_statistics());
+		lines.push(Line::blank());
+
+		Page::new(
+			screen,
+			lines,
+			vec![
+				self.render_log_score_prompt(),
+				Line::centered(vec![
+					Span::plain("Press "),
+					Span::bold("[SPACE]"),
+					Span::plain(" key to play again"),
+				]),
+				Line::centered(vec![
+					Span::plain("Press "),
+					self.key_bindings.label(Action::Quit),
+					Span::plain(" to exit the game"),
+				]),
+			],
+		)
+	}
 
-		output.push_str("⌂⌂                                      ");
-		output.push_str("  Beasts: ");
-		output.push_str(&format!(
-			"{ANSI_BOLD}{:>2}{ANSI_RESET}",
-			(self.common_beasts.len() + self.super_beasts.len() + self.hatched_beasts.len()).to_string()
-		));
-		output.push_str("  Level: ");
-		output.push_str(&format!("{ANSI_BOLD}{:>2}{ANSI_RESET}", self.level.to_string()));
-		output.push_str("  Time: ");
-		output.push_str(&format!("{ANSI_BOLD}{timer_color}{minutes:02}:{seconds:02}{ANSI_RESET}"));
-		output.push_str("  Lives: ");
-		output.push_str(&format!("{ANSI_BOLD}{lives}{ANSI_RESET}"));
-		output.push_str("  Score: ");
-		output.push_str(&format!("{ANSI_BOLD}{:>4}{ANSI_RESET}", self.player.score));
-		output.push_str(&format!(" {}\n\n", Tile::Player));
-
-		output
-	}
-
-	fn render_top_frame() -> String {
-		format!("\x1b[33m▛{}▜{ANSI_RESET_FONT}\n", "▀▀".repeat(BOARD_WIDTH))
-	}
-
-	fn render_bottom_frame() -> String {
-		format!("\x1b[33m▙{}▟{ANSI_RESET_FONT}\n", "▄▄".repeat(BOARD_WIDTH))
-	}
-
-	fn render_intro() -> String {
-		let mut output = String::new();
-		Self::render_header(&mut output);
-		output.push_str(&Self::render_top_frame());
-		output.push_str(&LOGO.join("\n"));
-		output.push('\n');
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                               Written and Developed by the following                               {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                         Dominik Wilkowski                                          {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                               Faithfully recreated from the work of                                {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                      Dan Baker , Alan Brown , Mark Hamilton , Derrick Shadel                       {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}             NOTICE:    This is a Free copy of BEAST. You may copy it and give it away.             {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                        If you enjoy the game, please send a contribution ($20) to                  {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                        Dan Baker, PO BOX 1174, Orem UT 84057                                       {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                     Press {ANSI_BOLD}[SPACE]{ANSI_RESET} key to start                                     {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                 {ANSI_BOLD}[Q]{ANSI_RESET} Quit  {ANSI_BOLD}[H]{ANSI_RESET} Help  {ANSI_BOLD}[S]{ANSI_RESET} Highscores                                 {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&Self::render_bottom_frame());
-		output.push_str("\n\n");
-
-		output
-	}
-
-	fn render_death_screen(&self) -> String {
-		let mut output = String::new();
-		let top_pos = format!("\x1b[{}F", ANSI_FRAME_SIZE + ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-
-		output.push_str(&top_pos);
-		output.push_str(&LOGO.join("\n"));
-		output.push('\n');
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		if self.player.lives == 0 {
-			output.push_str(&format!("{ANSI_LEFT_BORDER}                                              {ANSI_BOLD}YOU DIED{ANSI_RESET}                                              {ANSI_RIGHT_BORDER}\n"));
+	fn render_death_screen(&self) -> Page {
+		let title = if self.player.lives == 0 {
+			"YOU DIED"
 		} else {
-			output.push_str(&format!("{ANSI_LEFT_BORDER}                                          {ANSI_BOLD}YOUR TIME RAN OUT{ANSI_RESET}                                         {ANSI_RIGHT_BORDER}\n"));
+			"YOUR TIME RAN OUT"
+		};
+		Page {
+			hud: Some(self.hud()),
+			..self.render_end_screen(ScreenType::GameOver, title)
 		}
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&self.get_game_statistics());
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                  PRESS {ANSI_BOLD}[ENTER]{ANSI_RESET} TO LOG YOUR SCORE IN THE GLOBAL HIGHSCORE REGISTER                  {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                  Press {ANSI_BOLD}[SPACE]{ANSI_RESET} key to play again                                   {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                     Press {ANSI_BOLD}[Q]{ANSI_RESET} to exit the game                                     {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&Self::render_bottom_frame());
-		output.push_str(&self.render_footer());
-
-		output
-	}
-
-	fn render_winning_screen(&self) -> String {
-		let mut output = String::new();
-		let top_pos = format!("\x1b[{}F", ANSI_FRAME_SIZE + ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-
-		output.push_str(&top_pos);
-		output.push_str(&LOGO.join("\n"));
-		output.push('\n');
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                               {ANSI_BOLD}YOU WON{ANSI_RESET}                                              {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&self.get_game_statistics());
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                  PRESS {ANSI_BOLD}[ENTER]{ANSI_RESET} TO LOG YOUR SCORE IN THE GLOBAL HIGHSCORE REGISTER                  {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                  Press {ANSI_BOLD}[SPACE]{ANSI_RESET} key to play again                                   {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                     Press {ANSI_BOLD}[Q]{ANSI_RESET} to exit the game                                     {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&Self::render_bottom_frame());
-		output.push_str("\n\n");
-
-		output
-	}
-
-	fn render_board(&self) -> String {
-		let top_pos = format!("\x1b[{}F", ANSI_FRAME_SIZE + ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-		let bottom_pos = format!("\x1b[{ANSI_FRAME_SIZE}E");
-		let mut output = String::new();
-
-		output.push_str(&top_pos);
-		output.push_str(&self.board.render());
-		output.push_str(&Self::render_bottom_frame());
-		output.push_str(&self.render_footer());
-		output.push_str(&bottom_pos);
-		output
+	}
+
+	fn render_winning_screen(&self) -> Page {
+		self.render_end_screen(ScreenType::Won, "YOU WON")
 	}
 
 	fn render_with_state(&mut self) {
-		match self.state {
-			GameState::Dying(beat) => match beat {
-				Beat::One => {
-					self.state = GameState::Dying(Beat::Two);
-					print!("\x1b[48;5;196m");
-				},
-				Beat::Two => {
-					self.state = GameState::Dying(Beat::Three);
-					print!("\x1b[48;5;208m");
-				},
-				Beat::Three | Beat::Four | Beat::Five => {
-					self.state = GameState::Playing;
-					print!("{ANSI_RESET_BG}");
-				},
+		let flash = match self.state {
+			GameState::Dying(Beat::One) => {
+				self.state = GameState::Dying(Beat::Two);
+				Some(Flash::Death)
 			},
-			GameState::Killing(beat) => match beat {
-				Beat::One => {
-					self.state = GameState::Killing(Beat::Two);
-					print!("\x1b[48;2;51;51;51m");
-				},
-				Beat::Two | Beat::Three | Beat::Four | Beat::Five => {
-					self.state = GameState::Playing;
-					print!("{ANSI_RESET_BG}");
-				},
+			GameState::Dying(Beat::Two) => {
+				self.state = GameState::Dying(Beat::Three);
+				Some(Flash::DeathFading)
 			},
-			GameState::Playing => {
-				print!("{ANSI_RESET_BG}");
+			GameState::Killing(Beat::One) => {
+				self.state = GameState::Killing(Beat::Two);
+				Some(Flash::Kill)
 			},
-			_ => {},
+			GameState::Dying(_) | GameState::Killing(_) => {
+				self.state = GameState::Playing;
+				None
+			},
+			_ => None,
 		}
-		print!("{}", self.render_board());
+		.filter(|_| !self.config.accessibility);
+
+		let overlay = (self.state == GameState::Paused).then(|| {
+			let resume_key = self.key_bindings.keys(Action::Pause).first().map(Key::to_string).unwrap_or_default();
+			Overlay {
+				title: String::from("PAUSED"),
+				message: format!("Press [{resume_key}] or [SPACE] to resume"),
+				progress: 0,
+			}
+		});
+
+		self.render_frame(flash, overlay);
 	}
 
-	fn alert(msg: &str, progress: usize) -> String {
-		let alert_height = 4;
-		let top_pos = ((ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE) / 2) + ANSI_FOOTER_HEIGHT + alert_height;
-		let bottom_pos = top_pos - alert_height;
-		let left_pad =
-			format!("\x1b[{:.0}C", (((BOARD_WIDTH * 2 + ANSI_FRAME_SIZE + ANSI_FRAME_SIZE) / 2) - ((msg.len() + 4) / 2)));
+	fn render_level_summary(&mut self, summary: &str, progress: usize) {
+		self.render_frame(
+			None,
+			Some(Overlay {
+				title: String::from("LEVEL COMPLETED"),
+				message: summary.to_string(),
+				progress,
+			}),
+		);
+	}
 
-		let progress_bar = format!("{:▁<width$}", "", width = (msg.len() * progress) / 100);
-		format!(
-			"\x1b[{top_pos}F{left_pad}┌{border:─<width$}┐\n{left_pad}│ {msg} │\n{left_pad}│ \x1B[38;5;235m{progress_bar:<msg_width$}{ANSI_RESET_FONT} │\n{left_pad}└{border:─<width$}┘\n\x1b[{bottom_pos:.0}E",
-			border = "",
-			width = msg.len() + 2,
-			msg_width = msg.len()
-		)
+	fn render_frame(&mut self, flash: Option<Flash>, overlay: Option<Overlay>) {
+		// what happened since the last frame is announced in this one
+		self.dispatch_events();
+		let screen = match self.state {
+			GameState::Paused => ScreenType::Paused,
+			GameState::LevelComplete => ScreenType::LevelComplete,
+			GameState::GameOver => ScreenType::GameOver,
+			_ => ScreenType::Playing,
+		};
+		let announcement = self.config.accessibility.then(|| self.announcement());
+		let announcements = if self.config.accessibility {
+			self.announcer.take_new()
+		} else {
+			Vec::new()
+		};
+		// the beasts plan for every frame whether the overlay is shown or not so showing it doesn't change what they do
+		self.plan_beasts();
+		let training = self.training.then(|| self.training_hints());
+		let frame = Frame {
+			screen,
+			board: &self.board,
+			hud: self.hud(),
+			overlay,
+			flash,
+			announcement,
+			announcements,
+			training,
+		};
+		self.renderer.render(&frame);
 	}
 }
 
 #[cfg(test)]
 mod test {
 	use super::*;
-	use crate::{BOARD_WIDTH, test_common::strip_ansi_border};
+	use crate::{
+		BOARD_WIDTH, Coord, LOGO, PLAYER_START,
+		input_source::{ScriptedSource, Step},
+		renderer::{AnsiRenderer, MemoryRenderer},
+	};
+
+	/// a game that doesn't touch the terminal and gets no input
+	fn scripted_game(config: Config) -> Game {
+		Game::with_input(config, Box::new(ScriptedSource::new(Vec::new())))
+	}
 
 	#[test]
 	fn beat_next_test() {
@@ -862,7 +1391,7 @@ mod test {
 
 	#[test]
 	fn game_new_test() {
-		let game = Game::new();
+		let game = scripted_game(Config::default());
 
 		assert_eq!(game.state, GameState::Intro, "Game should start in Intro state");
 		assert_eq!(game.beat, Beat::One, "Game should start with Beat One");
@@ -902,9 +1431,165 @@ mod test {
 		assert_eq!(game.player.score, 0, "Each player should start with a score of 0");
 	}
 
+	#[test]
+	fn advance_beasts_test() {
+		let right = Coord { column: 11, row: 10 };
+		let left = Coord { column: 9, row: 10 };
+		let outcomes = [[right, left], [left, right]].map(|order| {
+			let mut game = scripted_game(Config::default());
+			game.board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+			game.player.position = Coord { column: 10, row: 10 };
+			game.board[&game.player.position] = Tile::Player;
+			game.super_beasts.clear();
+			game.eggs.clear();
+			game.hatched_beasts.clear();
+			game.common_beasts = order.iter().map(|&position| CommonBeast::new(position)).collect();
+			for beast in &game.common_beasts {
+				game.board[&beast.position] = Tile::CommonBeast;
+			}
+			// both beasts hatch at the same time so they wait out the same cooldown before their first move
+			for _ in 1..Schedule::interval(Mover::Common, &game.level.get_config(), false) {
+				assert_eq!(game.advance_beasts(), None, "No beast should move before its cooldown");
+			}
+
+			let killer = game.advance_beasts();
+			let mut positions = game.common_beasts.iter().map(|beast| beast.position).collect::<Vec<Coord>>();
+			positions.sort();
+			(killer, positions, game.board.buffer)
+		});
+
+		let (killer, positions, buffer) = &outcomes[0];
+		assert_eq!(*killer, Some(Entity::CommonBeast), "The player should be killed");
+		assert_eq!(
+			*positions,
+			vec![Coord { column: 10, row: 10 }, right],
+			"Only the beast further left should get the player"
+		);
+		assert_eq!(buffer[left.row][left.column], Tile::Empty, "The winning beast should leave its tile");
+		assert_eq!(buffer[right.row][right.column], Tile::CommonBeast, "The other beast should stay");
+		assert_eq!(outcomes[0], outcomes[1], "The outcome should not depend on the order of the beasts");
+	}
+
+	#[test]
+	fn advance_beasts_rejected_move_test() {
+		let mut game = scripted_game(Config::default());
+		game.board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		game.player.position = Coord { column: 10, row: 10 };
+		game.board[&game.player.position] = Tile::Player;
+		game.common_beasts.clear();
+		game.super_beasts.clear();
+		game.eggs.clear();
+		// both beasts head for the tile right above the player
+		game.hatched_beasts = vec![
+			HatchedBeast::new(Coord { column: 11, row: 8 }),
+			HatchedBeast::new(Coord { column: 9, row: 8 }),
+		];
+		for beast in &game.hatched_beasts {
+			game.board[&beast.position] = Tile::HatchedBeast;
+		}
+		for _ in 1..Schedule::interval(Mover::Hatched, &game.level.get_config(), false) {
+			assert_eq!(game.advance_beasts(), None, "No beast should move before its cooldown");
+		}
+
+		game.plan_beasts();
+		let loser = game.hatched_beasts[0].clone();
+		// planning again must not use up any steps of the paths
+		for _ in 0..3 {
+			game.planned_for = None;
+			game.plan_beasts();
+		}
+		assert_eq!(game.hatched_beasts[0], loser, "Planning should not change the beasts");
+
+		game.advance_beasts();
+		assert_eq!(
+			game.hatched_beasts[1].position,
+			Coord { column: 10, row: 9 },
+			"The beast further left should get the tile"
+		);
+		let mut loser = loser;
+		loser.cooldown = game.hatched_beasts[0].cooldown;
+		assert_eq!(game.hatched_beasts[0], loser, "The beast whose move was rejected should keep its path");
+	}
+
+	#[test]
+	fn training_hints_test() {
+		let mut game = scripted_game(Config::default());
+		game.board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		game.player.position = Coord { column: 10, row: 10 };
+		game.board[&game.player.position] = Tile::Player;
+		game.board[&Coord { column: 11, row: 10 }] = Tile::Block;
+		game.board[&Coord { column: 13, row: 10 }] = Tile::StaticBlock;
+		game.common_beasts = vec![CommonBeast::new(Coord { column: 12, row: 10 })];
+		game.super_beasts = vec![SuperBeast::new(Coord { column: 10, row: 14 })];
+		game.hatched_beasts.clear();
+		game.eggs = vec![Egg::new(Coord { column: 30, row: 20 }, Instant::now())];
+		game.board[&Coord { column: 12, row: 10 }] = Tile::CommonBeast;
+		game.board[&Coord { column: 10, row: 14 }] = Tile::SuperBeast;
+		game.board[&Coord { column: 30, row: 20 }] = Tile::Egg(Instant::now());
+		let board = game.board;
+
+		game.plan_beasts();
+		let training = game.training_hints();
+		assert_eq!(
+			training.squishable,
+			vec![Coord { column: 12, row: 10 }],
+			"Only the beast between the block and the static block can be squished"
+		);
+		assert_eq!(training.intents, Vec::new(), "Beasts that wait out their cooldown should have no intent");
+		assert_eq!(
+			training.eggs,
+			vec![(Coord { column: 30, row: 20 }, game.level.get_config().egg_hatching_time.as_secs())],
+			"The egg should count down from its hatching time"
+		);
+		assert_eq!(game.board.buffer, board.buffer, "Peeking ahead should not change the board");
+		assert_eq!(game.super_beasts[0].position, Coord { column: 10, row: 14 }, "Peeking ahead should not move beasts");
+
+		let beast_positions = |game: &Game| {
+			let mut positions = game
+				.common_beasts
+				.iter()
+				.map(|beast| beast.position)
+				.chain(game.super_beasts.iter().map(|beast| beast.position))
+				.collect::<Vec<Coord>>();
+			positions.sort();
+			positions
+		};
+		let mut all_intents = Vec::new();
+		for _ in 0..Schedule::interval(Mover::Common, &game.level.get_config(), false) {
+			game.plan_beasts();
+			let mut intents = game.training_hints().intents;
+			intents.sort();
+			let before = beast_positions(&game);
+			game.advance_beasts();
+			let moved_to = beast_positions(&game).into_iter().filter(|coord| !before.contains(coord)).collect::<Vec<Coord>>();
+			assert_eq!(moved_to, intents, "The beasts should move exactly where the overlay said they would");
+			all_intents.extend(intents);
+		}
+		assert!(
+			all_intents.contains(&Coord { column: 10, row: 13 }),
+			"The super beast should head straight for the player"
+		);
+	}
+
+	#[test]
+	fn is_ranked_training_test() {
+		let mut game = scripted_game(Config::default());
+		assert!(game.is_ranked(), "A game without the training overlay should be ranked");
+
+		game.training = true;
+		game.training_used = true;
+		assert!(!game.is_ranked(), "A game with the training overlay should not be ranked");
+
+		game.training = false;
+		assert!(!game.is_ranked(), "Hiding the overlay again should not make the game ranked");
+
+		game.start_new_game();
+		assert!(game.is_ranked(), "A new game without the overlay should be ranked again");
+	}
+
 	#[test]
 	fn get_secs_remaining_test() {
-		let mut game = Game::new();
+		let mut game = scripted_game(Config::default());
 
 		let now = Instant::now();
 		game.level_start = now - Duration::from_secs(10);
@@ -918,8 +1603,8 @@ mod test {
 
 	#[test]
 	fn render_footer_test() {
-		let game = Game::new();
-		let footer = game.render_footer();
+		let game = scripted_game(Config::default());
+		let footer = AnsiRenderer::render_hud(&game.hud());
 
 		assert!(footer.contains("Level:"), "Footer should contain Level");
 		assert!(footer.contains("Beasts:"), "Footer should contain Beasts");
@@ -930,7 +1615,7 @@ mod test {
 
 	#[test]
 	fn render_with_state_test() {
-		let mut game = Game::new();
+		let mut game = scripted_game(Config::default());
 
 		game.state = GameState::Intro;
 		game.render_with_state();
@@ -976,191 +1661,227 @@ mod test {
 	}
 
 	#[test]
-	fn header_height_test() {
-		let mut output = String::new();
-		Game::render_header(&mut output);
-		assert_eq!(
-			output.lines().count(),
-			ANSI_HEADER_HEIGHT,
-			"There should be exactly ANSI_HEADER_HEIGHT lines in the header"
-		);
-	}
+	fn render_with_state_snapshot_test() {
+		let mut game = scripted_game(Config::default());
+		let renderer = MemoryRenderer::new();
+		game.renderer = Box::new(renderer.clone());
 
-	#[test]
-	fn footer_height_test() {
-		assert_eq!(
-			Game::new().render_footer().lines().count(),
-			ANSI_FOOTER_HEIGHT,
-			"There should be exactly ANSI_FOOTER_HEIGHT lines in the footer"
-		);
-	}
+		game.state = GameState::Dying(Beat::One);
+		game.render_with_state();
+		game.state = GameState::Paused;
+		game.render_with_state();
 
-	#[test]
-	fn footer_line_length_test() {
-		let output = Game::new().render_footer();
-
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 1 {
-				assert_eq!(
-					strip_ansi_border(line).len(),
-					BOARD_WIDTH * 2 + ANSI_FRAME_SIZE + ANSI_FRAME_SIZE,
-					"Line {i} should be the correct length"
-				);
-			}
-		}
+		let frames = renderer.frames();
+		assert_eq!(frames.len(), 2, "Every render should produce a frame");
+		assert!(frames[0].contains("* the player died *"), "The dying state should flash");
+		assert!(frames[0].contains("Lives: 5"), "The frame should contain the HUD");
+		let last_frame = renderer.last_frame().unwrap_or_default();
+		assert!(last_frame.starts_with("[Paused]"), "The paused frame should have the paused screen type");
+		assert!(last_frame.contains("PAUSED: Press [P] or [SPACE] to resume"), "The paused frame should have the overlay");
 	}
 
 	#[test]
-	fn top_frame_height_test() {
-		assert_eq!(
-			Game::render_top_frame().lines().count(),
-			ANSI_FRAME_SIZE,
-			"There should be exactly ANSI_FRAME_HEIGHT lines in the top frame"
-		);
+	fn render_with_state_accessibility_test() {
+		let mut game = scripted_game(Config {
+			accessibility: true,
+			..Config::default()
+		});
+		let renderer = MemoryRenderer::new();
+		game.renderer = Box::new(renderer.clone());
+
+		game.state = GameState::Dying(Beat::One);
+		game.events.publish(EventKind::PlayerKilled {
+			by: Entity::CommonBeast,
+			position: PLAYER_START,
+			lives: 4,
+		});
+		game.render_with_state();
+
+		let frame = renderer.last_frame().unwrap_or_default();
+		assert!(!frame.contains("* the player died *"), "Flashes should be turned off");
+		assert!(frame.contains("Status: Life lost, 4 lives left"), "The announcement should be part of the frame");
+		assert_eq!(game.state, GameState::Dying(Beat::Two), "The dying state should still move on");
+
+		game.render_with_state();
+		let frame = renderer.last_frame().unwrap_or_default();
+		assert!(!frame.contains("Life lost"), "The announcement should not be repeated in the next frame");
 	}
 
 	#[test]
-	fn top_frame_line_length_test() {
-		let output = Game::render_top_frame();
-
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 1 {
-				assert_eq!(
-					strip_ansi_border(line).len(),
-					BOARD_WIDTH * 2 + ANSI_FRAME_SIZE + ANSI_FRAME_SIZE,
-					"Line {i} should be the correct length"
-				);
-			}
-		}
+	fn handle_redraw_test() {
+		let mut renderer = MemoryRenderer::new();
+		assert!(Game::handle_redraw(&mut renderer).is_none(), "Nothing should be redrawn without a resize");
+		assert!(renderer.frames().is_empty(), "Nothing should be rendered without a resize");
 	}
 
 	#[test]
-	fn bottom_frame_height_test() {
-		assert_eq!(
-			Game::render_bottom_frame().lines().count(),
-			ANSI_FRAME_SIZE,
-			"There should be exactly ANSI_FRAME_HEIGHT lines in the bottom frame"
-		);
+	fn scroll_page_test() {
+		let page = Page::new(ScreenType::Help, vec![Line::plain("line"); 40], Vec::new());
+
+		let page = Game::scroll_page(page, Action::Down);
+		assert_eq!(page.scroll, 1, "Pages that don't fit should scroll down");
+		let page = Game::scroll_page(Game::scroll_page(page, Action::Up), Action::Up);
+		assert_eq!(page.scroll, 0, "Pages should not scroll above the top");
+
+		let intro = Game::scroll_page(scripted_game(Config::default()).render_intro(), Action::Down);
+		assert_eq!(intro.scroll, 0, "Pages that fit should not scroll");
 	}
 
 	#[test]
-	fn bottom_frame_line_length_test() {
-		let output = Game::render_bottom_frame();
-
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 1 {
-				assert_eq!(
-					strip_ansi_border(line).len(),
-					BOARD_WIDTH * 2 + ANSI_FRAME_SIZE + ANSI_FRAME_SIZE,
-					"Line {i} should be the correct length"
-				);
-			}
-		}
+	fn render_too_small_test() {
+		let page = Game::render_too_small(80, 24);
+		assert_eq!(page.screen, ScreenType::TooSmall, "The notice should have its own screen type");
+		assert!(!page.logo, "The notice should not need room for the logo");
+
+		let text = page.lines.iter().map(Line::text).collect::<Vec<_>>();
+		assert!(text.iter().any(|line| line == "RESIZE YOUR TERMINAL"), "The notice should ask to resize the terminal");
+		assert!(
+			text.iter().any(|line| line.contains(&format!("at least {MIN_TERMINAL_WIDTH} x {MIN_TERMINAL_HEIGHT}"))),
+			"The notice should show the minimum size"
+		);
+		assert!(text.iter().any(|line| line.contains("80 x 24")), "The notice should show the current size");
+		assert!(text.iter().all(|line| line.chars().count() <= 80), "The notice should fit into a small terminal");
 	}
 
 	#[test]
 	fn intro_height_test() {
+		let page = scripted_game(Config::default()).render_intro();
 		assert_eq!(
-			Game::render_intro().lines().count(),
-			ANSI_HEADER_HEIGHT + ANSI_FRAME_SIZE + ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT,
-			"The intro screen needs to be the correct height for the ANSI re-render to work"
+			LOGO.len() + page.lines.len() + page.footer.len(),
+			ANSI_BOARD_HEIGHT,
+			"The intro should fill the board without scrolling"
 		);
 	}
 
 	#[test]
 	fn intro_line_length_test() {
-		let output = Game::render_intro();
-
-		let lines = output.lines().skip(5).collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 3 {
-				assert_eq!(
-					strip_ansi_border(line).len(),
-					BOARD_WIDTH * 2,
-					"Line {i} should be the correct length is={:?}",
-					strip_ansi_border(line)
-				);
-			}
+		let page = scripted_game(Config::default()).render_intro();
+		for (i, line) in page.lines.iter().chain(&page.footer).enumerate() {
+			assert!(
+				line.text().chars().count() <= BOARD_WIDTH * 2,
+				"Line {i} should fit into the board is={:?}",
+				line.text()
+			);
 		}
 	}
 
 	#[test]
 	fn end_screen_height_test() {
+		let page = scripted_game(Config::default()).render_death_screen();
 		assert_eq!(
-			Game::new().render_death_screen().lines().count(),
-			ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT,
-			"The end screen needs to be the correct height for the ANSI re-render to work"
+			LOGO.len() + page.lines.len() + page.footer.len(),
+			ANSI_BOARD_HEIGHT,
+			"The end screen should fill the board without scrolling"
 		);
+		assert!(page.hud.is_some(), "The end screen should show the HUD");
 	}
 
 	#[test]
 	fn end_screen_line_length_test() {
-		let output = Game::new().render_death_screen();
-
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 3 {
-				assert_eq!(
-					strip_ansi_border(line).len(),
-					BOARD_WIDTH * 2,
-					"Line {i} should be the correct length is={:?}",
-					strip_ansi_border(line)
-				);
-			}
+		let page = scripted_game(Config::default()).render_death_screen();
+		for (i, line) in page.lines.iter().chain(&page.footer).enumerate() {
+			assert!(
+				line.text().chars().count() <= BOARD_WIDTH * 2,
+				"Line {i} should fit into the board is={:?}",
+				line.text()
+			);
 		}
 	}
 
 	#[test]
 	fn winning_screen_height_test() {
+		let page = scripted_game(Config::default()).render_winning_screen();
 		assert_eq!(
-			Game::new().render_winning_screen().lines().count(),
-			ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT,
-			"The winning screen needs to be the correct height for the ANSI re-render to work"
+			LOGO.len() + page.lines.len() + page.footer.len(),
+			ANSI_BOARD_HEIGHT,
+			"The winning screen should fill the board without scrolling"
 		);
+		assert!(page.hud.is_none(), "The winning screen should not show the HUD");
 	}
 
 	#[test]
 	fn winning_screen_line_length_test() {
-		let output = Game::new().render_winning_screen();
-
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 3 {
-				assert_eq!(
-					strip_ansi_border(line).len(),
-					BOARD_WIDTH * 2,
-					"Line {i} should be the correct length is={:?}",
-					strip_ansi_border(line)
-				);
-			}
+		let page = scripted_game(Config::default()).render_winning_screen();
+		for (i, line) in page.lines.iter().chain(&page.footer).enumerate() {
+			assert!(
+				line.text().chars().count() <= BOARD_WIDTH * 2,
+				"Line {i} should fit into the board is={:?}",
+				line.text()
+			);
 		}
 	}
 
 	#[test]
 	fn render_death_screen_message_test() {
-		let mut game = Game::new();
+		let mut game = scripted_game(Config::default());
 
 		let end_screen = game.render_death_screen();
-		assert!(end_screen.contains("YOUR TIME RAN OUT"), "End screen should say 'YOUR TIME RAN OUT' when lives > 0");
+		assert_eq!(
+			end_screen.lines[0].text(),
+			"YOUR TIME RAN OUT",
+			"End screen should say 'YOUR TIME RAN OUT' when lives > 0"
+		);
 
 		game.player.lives = 0;
 		let end_screen = game.render_death_screen();
-		assert!(end_screen.contains("YOU DIED"), "End screen should say 'YOU DIED' when lives == 0");
+		assert_eq!(end_screen.lines[0].text(), "YOU DIED", "End screen should say 'YOU DIED' when lives == 0");
+	}
+
+	#[test]
+	fn game_statistics_test() {
+		let mut game = scripted_game(Config::default());
+		game.statistics.record_player_action(&PlayerAction::KillSuperBeast(game.player.position));
+		game.statistics.finish_level(Duration::from_secs(83), 4);
+		game.statistics.start_level(Level::Two);
+
+		let lines = game.get_game_statistics().iter().map(Line::text).collect::<Vec<_>>();
+		assert_eq!(lines.len(), 4 + LEVEL_COUNT, "The statistics should have a row for each level");
+		assert!(lines[4].contains("01:23"), "The first level row should contain the time taken");
+		assert!(
+			lines[4].contains(&(SuperBeast::get_score() + 4).to_string()),
+			"The first level row should contain the score of the level"
+		);
+		assert!(lines[5].trim().starts_with('2'), "The second level row should be rendered");
+		assert!(lines[6].trim().is_empty(), "Levels not yet played should be empty");
+
+		let summary = game.get_level_summary();
+		assert!(summary.contains("KILLS 0"), "The level summary should only include the current level");
+	}
+
+	#[test]
+	fn complete_level_test() {
+		let mut game = scripted_game(Config::default());
+		let completion_score = Level::One.get_config().completion_score;
+		game.complete_level();
+
+		assert_eq!(game.level, Level::Two, "Completing level one should start level two");
+		assert_eq!(game.player.score, completion_score, "The player should get the bonus of the completed level");
+		assert_eq!(
+			game.statistics.levels[0].score, completion_score,
+			"The statistics of the completed level should get its bonus"
+		);
+		assert_eq!(game.statistics.current().score, 0, "The new level should start without a score");
+
+		game.level = Level::Ten;
+		game.complete_level();
+		assert_eq!(game.state, GameState::Won, "Completing the last level should win the game");
+		assert_eq!(
+			game.player.score,
+			completion_score + Level::Ten.get_config().completion_score,
+			"Winning the game should award the bonus of the last level too"
+		);
 	}
 
 	#[test]
 	fn render_footer_time_format_test() {
-		let mut game = Game::new();
+		let mut game = scripted_game(Config::default());
 
 		let test_times = [(0, "00:00"), (10, "00:09"), (60, "00:59"), (75, "01:14")];
 
 		for (secs, expected) in test_times {
 			game.level_start = Instant::now() - (game.level.get_config().time - Duration::from_secs(secs));
 
-			let footer = game.render_footer();
+			let footer = AnsiRenderer::render_hud(&game.hud());
 
 			assert!(footer.contains(expected), "Footer should display '{expected}' when {secs} seconds remain");
 		}
@@ -1168,18 +1889,37 @@ mod test {
 
 	#[test]
 	fn play_quit_test() {
-		let mut game = Game::new();
-		let (sender, receiver) = mpsc::channel::<u8>();
-		game.input_listener = receiver;
-
-		let handle = thread::spawn(move || {
-			game.play();
-			game
-		});
-
-		sender.send(b'q').unwrap();
-		thread::sleep(Duration::from_millis(50));
-		let game = handle.join().unwrap();
+		let mut game = Game::with_input(Config::default(), Box::new(ScriptedSource::new(vec![Step::new(0, b"q")])));
+		game.play();
 		assert_eq!(game.state, GameState::Quit, "The game has quit after you hit the 'q' key");
 	}
+
+	#[test]
+	fn play_scripted_test() {
+		let config = Config {
+			offline: true,
+			..Config::default()
+		};
+		// intro, help, highscore, help again, playing, pause, resume and quit
+		let script = ScriptedSource::new(vec![
+			Step::new(0, b"h"),
+			Step::new(20, b"s"),
+			Step::new(40, b"h"),
+			Step::new(60, b" "),
+			Step::new(80, b"p"),
+			Step::new(100, b" "),
+			Step::new(120, b"q"),
+		]);
+		let mut game = Game::with_input(config, Box::new(script));
+		let renderer = MemoryRenderer::new();
+		game.renderer = Box::new(renderer.clone());
+		game.play();
+
+		let screens =
+			renderer.frames().iter().filter_map(|frame| frame.lines().next().map(String::from)).collect::<Vec<_>>();
+		assert_eq!(game.state, GameState::Quit, "The script should have quit the game");
+		for screen in ["[Intro]", "[Help]", "[Highscore]", "[Playing]", "[Paused]"] {
+			assert!(screens.iter().any(|line| line.starts_with(screen)), "The script should have shown {screen}");
+		}
+	}
 }
@@ -3,9 +3,10 @@
 use std::{fmt, time::Instant};
 
 use crate::{
-	ANSI_BOLD, ANSI_LEFT_BORDER, ANSI_RESET, ANSI_RIGHT_BORDER, LOGO, Tile,
+	Tile,
 	beasts::{Beast, CommonBeast, Egg, HatchedBeast, SuperBeast},
-	game::{ANSI_BOARD_HEIGHT, ANSI_FOOTER_HEIGHT, ANSI_FRAME_SIZE},
+	keys::{Action, KeyBindings},
+	renderer::{self, Line, ScreenType, Span},
 };
 
 /// keeping track of what page to display
@@ -29,20 +30,19 @@ impl fmt::Display for Page {
 	}
 }
 
-/// the height of the help window
-const ANSI_HELP_HEIGHT: usize = 17;
-/// the height of the bottom to index
-const ANSI_HELP_INDEX_HEIGHT: usize = 2;
-
 /// our help
 pub struct Help {
 	page: Page,
+	key_bindings: KeyBindings,
 }
 
 impl Help {
-	/// create a new instance of help
-	pub fn new() -> Self {
-		Self { page: Page::One }
+	/// create a new instance of help that describes the given key bindings
+	pub fn new(key_bindings: &KeyBindings) -> Self {
+		Self {
+			page: Page::One,
+			key_bindings: key_bindings.clone(),
+		}
 	}
 
 	/// go to the next page
@@ -63,116 +63,185 @@ impl Help {
 		}
 	}
 
-	/// render the help
-	pub fn render(&self) -> String {
-		match self.page {
+	/// the page of the help we're on
+	pub fn render(&self) -> renderer::Page {
+		let mut lines = vec![Line::centered(vec![Span::bold("HELP")]), Line::blank(), Line::blank()];
+		lines.extend(match self.page {
 			Page::One => self.general_page(),
-			Page::Two => self.beast_page(),
-			Page::Three => self.scoring_page(),
-		}
-	}
-
-	fn general_page(&self) -> String {
-		let mut output = String::new();
-		let top_pos = format!("\x1b[{}F", ANSI_FRAME_SIZE + ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-		let bottom_pos = format!("\x1b[{}E", ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
+			Page::Two => Self::beast_page(),
+			Page::Three => Self::scoring_page(),
+		});
+		lines.push(self.render_pagination());
 
-		output.push_str(&top_pos);
-		output.push_str(&LOGO.join("\n"));
-		output.push('\n');
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                               {ANSI_BOLD}HELP{ANSI_RESET}                                                 {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  {ANSI_BOLD}GENERAL{ANSI_RESET}                                                                                           {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  You must survive while {ANSI_BOLD}beasts{ANSI_RESET} attack you. The only way to fight back is to squish the beasts      {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  between blocks. But there are different types of beasts that attack you the longer you survive.   {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  You are {} and you move around with the arrow keys on your keyboard.                              {ANSI_RIGHT_BORDER}\n", Tile::Player));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  You can push {} around the board.                                                                 {ANSI_RIGHT_BORDER}\n", Tile::Block));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  However, {} can't be moved.                                                                       {ANSI_RIGHT_BORDER}\n", Tile::StaticBlock));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  Your goal is to use the blocks to squish all beasts before the time runs out.                     {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  Each level will introduce new Beasts and an ever changing environment.                            {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  And you better hurry up because you only got a little time to survive in {ANSI_BOLD}BEAST{ANSI_RESET}.                   {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&self.render_pagination());
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}             {ANSI_BOLD}[SPACE]{ANSI_RESET} Play  {ANSI_BOLD}[Q]{ANSI_RESET} Quit  {ANSI_BOLD}[S]{ANSI_RESET} Highscores  {ANSI_BOLD}[←]{ANSI_RESET} Previous Page  {ANSI_BOLD}[→]{ANSI_RESET} Next Page               {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&bottom_pos);
-
-		output
+		renderer::Page::new(
+			ScreenType::Help,
+			lines,
+			vec![
+				Line::blank(),
+				Line::centered(vec![
+					Span::bold("[SPACE]"),
+					Span::plain(" Play  "),
+					self.key_bindings.label(Action::Quit),
+					Span::plain(" Quit  "),
+					self.key_bindings.label(Action::Highscore),
+					Span::plain(" Highscores  "),
+					self.key_bindings.label(Action::Left),
+					Span::plain(" Previous Page  "),
+					self.key_bindings.label(Action::Right),
+					Span::plain(" Next Page"),
+				]),
+			],
+		)
 	}
 
-	fn beast_page(&self) -> String {
-		let mut output = String::new();
-		let top_pos = format!("\x1b[{}F", ANSI_FRAME_SIZE + ANSI_HELP_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-		let bottom_pos = format!("\x1b[{}E", ANSI_FRAME_SIZE + ANSI_HELP_INDEX_HEIGHT + ANSI_FOOTER_HEIGHT);
-
-		output.push_str(&top_pos);
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  {ANSI_BOLD}ENEMIES{ANSI_RESET}                                                                                           {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  The {ANSI_BOLD}Common Beast{ANSI_RESET} {}                                                                               {ANSI_RIGHT_BORDER}\n", Tile::CommonBeast));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  It's the beast that attacks you first and in large numbers. Don't worry though, it isn't super    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  smart and often gets stuck. You can kill it by squishing it against any block or the board frame. {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  The {ANSI_BOLD}Super Beast{ANSI_RESET} {}                                                                                {ANSI_RIGHT_BORDER}\n", Tile::SuperBeast));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  This beast is vicious and smart and will find you if you leave an opening.                        {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  It can only be killed by squishing it against a {}.                                               {ANSI_RIGHT_BORDER}\n", Tile::StaticBlock));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  The {ANSI_BOLD}Egg{ANSI_RESET} {} and the {ANSI_BOLD}Hatched Beast{ANSI_RESET} {}                                                               {ANSI_RIGHT_BORDER}\n", Tile::Egg(Instant::now()), Tile::HatchedBeast));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  Towards the end you will encounter eggs which hatch into Hatched Beasts. These beasts can push {} {ANSI_RIGHT_BORDER}\n", Tile::Block));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  and will try to squish YOU with them. They can be killed like the common beasts though.           {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&self.render_pagination());
-		output.push_str(&bottom_pos);
+	fn general_page(&self) -> Vec<Line> {
+		let mut you_are = vec![
+			Span::plain("  You are "),
+			Span::Tile(Tile::Player),
+			Span::plain(" and you move around with "),
+		];
+		you_are.extend(self.key_bindings.movement_label());
+		you_are.push(Span::plain("."));
 
-		output
+		vec![
+			Line::new(vec![Span::plain("  "), Span::bold("GENERAL")]),
+			Line::blank(),
+			Line::new(vec![
+				Span::plain("  You must survive while "),
+				Span::bold("beasts"),
+				Span::plain(" attack you. The only way to fight back is to squish the beasts"),
+			]),
+			Line::plain("  between blocks. But there are different types of beasts that attack you the longer you survive."),
+			Line::blank(),
+			Line::new(you_are),
+			Line::new(vec![
+				Span::plain("  You can push "),
+				Span::Tile(Tile::Block),
+				Span::plain(" around the board."),
+			]),
+			Line::new(vec![
+				Span::plain("  However, "),
+				Span::Tile(Tile::StaticBlock),
+				Span::plain(" can't be moved."),
+			]),
+			Line::blank(),
+			Line::plain("  Your goal is to use the blocks to squish all beasts before the time runs out."),
+			Line::plain("  Each level will introduce new Beasts and an ever changing environment."),
+			Line::new(vec![
+				Span::plain("  And you better hurry up because you only got a little time to survive in "),
+				Span::bold("BEAST"),
+				Span::plain("."),
+			]),
+			Line::blank(),
+			Line::new(vec![
+				Span::plain("  "),
+				Span::bold("CONTROLS"),
+				Span::plain("  "),
+				self.key_bindings.label(Action::Pause),
+				Span::plain(" Pause  "),
+				self.key_bindings.label(Action::Restart),
+				Span::plain(" Restart  "),
+				self.key_bindings.label(Action::Training),
+				Span::plain(" Training  "),
+				self.key_bindings.label(Action::Help),
+				Span::plain(" Help  "),
+				self.key_bindings.label(Action::Highscore),
+				Span::plain(" Highscores  "),
+				self.key_bindings.label(Action::Quit),
+				Span::plain(" Quit"),
+			]),
+		]
 	}
 
-	fn scoring_page(&self) -> String {
-		let mut output = String::new();
-		let top_pos = format!("\x1b[{}F", ANSI_FRAME_SIZE + ANSI_HELP_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-		let bottom_pos = format!("\x1b[{}E", ANSI_FRAME_SIZE + ANSI_HELP_INDEX_HEIGHT + ANSI_FOOTER_HEIGHT);
+	fn beast_page() -> Vec<Line> {
+		vec![
+			Line::new(vec![Span::plain("  "), Span::bold("ENEMIES")]),
+			Line::blank(),
+			Line::new(vec![
+				Span::plain("  The "),
+				Span::bold("Common Beast"),
+				Span::plain(" "),
+				Span::Tile(Tile::CommonBeast),
+			]),
+			Line::plain("  It's the beast that attacks you first and in large numbers. Don't worry though, it isn't super"),
+			Line::plain(
+				"  smart and often gets stuck. You can kill it by squishing it against any block or the board frame.",
+			),
+			Line::blank(),
+			Line::new(vec![
+				Span::plain("  The "),
+				Span::bold("Super Beast"),
+				Span::plain(" "),
+				Span::Tile(Tile::SuperBeast),
+			]),
+			Line::plain("  This beast is vicious and smart and will find you if you leave an opening."),
+			Line::new(vec![
+				Span::plain("  It can only be killed by squishing it against a "),
+				Span::Tile(Tile::StaticBlock),
+				Span::plain("."),
+			]),
+			Line::blank(),
+			Line::new(vec![
+				Span::plain("  The "),
+				Span::bold("Egg"),
+				Span::plain(" "),
+				Span::Tile(Tile::Egg(Instant::now())),
+				Span::plain(" and the "),
+				Span::bold("Hatched Beast"),
+				Span::plain(" "),
+				Span::Tile(Tile::HatchedBeast),
+			]),
+			Line::new(vec![
+				Span::plain(
+					"  Towards the end you will encounter eggs which hatch into Hatched Beasts. These beasts can push ",
+				),
+				Span::Tile(Tile::Block),
+			]),
+			Line::plain("  and will try to squish YOU with them. They can be killed like the common beasts though."),
+			Line::blank(),
+		]
+	}
 
-		output.push_str(&top_pos);
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  {ANSI_BOLD}SCORING{ANSI_RESET}                                                                                           {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  You add scores by squishing beasts, completing levels and having time left over by the end of     {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  level. Additionally each second you have left over after you finished a level                     {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  will award you 0.1 score.                                                                         {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  Beast  | Score for squishing                                                                      {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  ----------------------------                                                                      {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  {}     | {}                                                                                        {ANSI_RIGHT_BORDER}\n", Tile::CommonBeast, CommonBeast::get_score()));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  {}     | {}                                                                                        {ANSI_RIGHT_BORDER}\n", Tile::SuperBeast, SuperBeast::get_score()));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  {}     | {}                                                                                        {ANSI_RIGHT_BORDER}\n", Tile::Egg(Instant::now()), Egg::get_score()));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}  {}     | {}                                                                                        {ANSI_RIGHT_BORDER}\n", Tile::HatchedBeast, HatchedBeast::get_score()));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&self.render_pagination());
-		output.push_str(&bottom_pos);
+	fn scoring_page() -> Vec<Line> {
+		let score = |tile: Tile, score: u16| {
+			Line::new(vec![
+				Span::plain("  "),
+				Span::Tile(tile),
+				Span::plain(format!("     | {score}")),
+			])
+		};
 
-		output
+		vec![
+			Line::new(vec![Span::plain("  "), Span::bold("SCORING")]),
+			Line::blank(),
+			Line::plain("  You add scores by squishing beasts, completing levels and having time left over by the end of"),
+			Line::plain("  level. Additionally each second you have left over after you finished a level"),
+			Line::plain("  will award you 0.1 score."),
+			Line::blank(),
+			Line::plain("  Beast  | Score for squishing"),
+			Line::plain("  ----------------------------"),
+			score(Tile::CommonBeast, CommonBeast::get_score()),
+			score(Tile::SuperBeast, SuperBeast::get_score()),
+			score(Tile::Egg(Instant::now()), Egg::get_score()),
+			score(Tile::HatchedBeast, HatchedBeast::get_score()),
+			Line::blank(),
+			Line::blank(),
+		]
 	}
 
-	fn render_pagination(&self) -> String {
-		format!(
-			"{ANSI_LEFT_BORDER}                                                {}                                               {ANSI_RIGHT_BORDER}\n",
-			self.page
-		)
+	fn render_pagination(&self) -> Line {
+		Line::centered(vec![Span::plain(self.page.to_string())])
 	}
 }
 
 #[cfg(test)]
 mod test {
 	use super::*;
-	use crate::{BOARD_WIDTH, test_common::strip_ansi_border};
+	use crate::{BOARD_WIDTH, LOGO, game::ANSI_BOARD_HEIGHT, keys::KeyPreset};
 
 	#[test]
 	fn next_page_test() {
-		let mut help = Help::new();
+		let mut help = Help::new(&KeyBindings::from_preset(KeyPreset::Default));
 		assert_eq!(help.page, Page::One, "The first page should be page one");
 		help.next_page();
 		assert_eq!(help.page, Page::Two, "The page after calling next_page 1 time should be page two");
@@ -184,7 +253,7 @@ mod test {
 
 	#[test]
 	fn previous_page_test() {
-		let mut help = Help::new();
+		let mut help = Help::new(&KeyBindings::from_preset(KeyPreset::Default));
 		assert_eq!(help.page, Page::One, "The first page should be page one");
 		help.previous_page();
 		assert_eq!(help.page, Page::Three, "The page after calling previous_page 1 time should be page three");
@@ -196,69 +265,56 @@ mod test {
 
 	#[test]
 	fn render_pagination_test() {
-		let mut help = Help::new();
-		assert_eq!(
-			strip_ansi_border(help.render_pagination().strip_suffix("\n").unwrap()).len(),
-			BOARD_WIDTH * 2,
-			"The pagination for page one should render the correct length"
-		);
+		let mut help = Help::new(&KeyBindings::from_preset(KeyPreset::Default));
+		assert_eq!(help.render_pagination().text(), "● ○ ○", "The pagination should mark page one");
 		help.next_page();
-		assert_eq!(
-			strip_ansi_border(help.render_pagination().strip_suffix("\n").unwrap()).len(),
-			BOARD_WIDTH * 2,
-			"The pagination for page two should render the correct length"
-		);
+		assert_eq!(help.render_pagination().text(), "○ ● ○", "The pagination should mark page two");
 		help.next_page();
-		assert_eq!(
-			strip_ansi_border(help.render_pagination().strip_suffix("\n").unwrap()).len(),
-			BOARD_WIDTH * 2,
-			"The pagination for page three should render the correct length"
-		);
-		help.next_page();
-		assert_eq!(
-			strip_ansi_border(help.render_pagination().strip_suffix("\n").unwrap()).len(),
-			BOARD_WIDTH * 2,
-			"The pagination for page one should render the correct length"
-		);
-	}
-
-	#[test]
-	fn general_page_line_length_test() {
-		let help = Help::new();
-		let output = help.render();
-
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 1 {
-				assert_eq!(strip_ansi_border(line).len(), BOARD_WIDTH * 2, "Line {i} should be the correct length");
-			}
-		}
+		assert_eq!(help.render_pagination().text(), "○ ○ ●", "The pagination should mark page three");
 	}
 
 	#[test]
-	fn beast_page_line_length_test() {
-		let mut help = Help::new();
-		help.next_page();
-		let output = help.render();
-
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 1 {
-				assert_eq!(strip_ansi_border(line).len(), BOARD_WIDTH * 2, "Line {i} should be the correct length");
+	fn page_size_test() {
+		let mut help = Help::new(&KeyBindings::from_preset(KeyPreset::Default));
+		for index in 1..=3 {
+			let page = help.render();
+			assert_eq!(
+				LOGO.len() + page.lines.len() + page.footer.len(),
+				ANSI_BOARD_HEIGHT,
+				"Page {index} should fill the board without scrolling"
+			);
+			for line in page.lines.iter().chain(&page.footer) {
+				assert!(
+					line.text().chars().count() <= BOARD_WIDTH * 2,
+					"The line \"{}\" on page {index} should fit into the board",
+					line.text()
+				);
 			}
+			help.next_page();
 		}
 	}
 
 	#[test]
-	fn scoring_page_line_length_test() {
-		let mut help = Help::new();
-		help.previous_page();
-		let output = help.render();
+	fn general_page_key_bindings_test() {
+		for preset in [KeyPreset::Default, KeyPreset::Wasd, KeyPreset::Hjkl] {
+			let key_bindings = KeyBindings::from_preset(preset);
+			let page = Help::new(&key_bindings).render();
+			let spans = page.lines.iter().chain(&page.footer).flat_map(|line| &line.spans).collect::<Vec<&Span>>();
 
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 1 {
-				assert_eq!(strip_ansi_border(line).len(), BOARD_WIDTH * 2, "Line {i} should be the correct length");
+			assert!(
+				spans.contains(&&key_bindings.label(Action::Help)),
+				"The help for the {preset:?} preset should show the active help key"
+			);
+			assert!(
+				key_bindings.movement_label().iter().all(|label| spans.contains(&label)),
+				"The help for the {preset:?} preset should show the active movement keys"
+			);
+			for line in page.lines.iter().chain(&page.footer) {
+				assert!(
+					line.text().chars().count() <= BOARD_WIDTH * 2,
+					"The line \"{}\" should fit into the board for the {preset:?} preset",
+					line.text()
+				);
 			}
 		}
 	}
@@ -2,24 +2,38 @@
 
 use reqwest::{blocking, header::CONTENT_TYPE};
 use std::{
-	env,
-	sync::{Arc, Mutex, mpsc::Receiver},
+	sync::{
+		Arc, Mutex,
+		mpsc::{self, Receiver, RecvTimeoutError},
+	},
 	thread,
-	time::Duration,
+	time::{Duration, Instant},
 };
+use time::OffsetDateTime;
 
 use crate::{
-	ANSI_BOLD, ANSI_LEFT_BORDER, ANSI_RESET, ANSI_RESET_BG, ANSI_RESET_FONT, ANSI_RIGHT_BORDER, LOGO, Tile,
+	Tile,
 	common::{Highscores, MAX_NAME_LENGTH, MAX_SCORES, Score, levels::Level},
-	game::{ANSI_BOARD_HEIGHT, ANSI_FOOTER_HEIGHT, ANSI_FRAME_SIZE},
+	config::Config,
+	game::{Game, INPUT_POLL_DURATION},
+	input::{self, KeyEvent},
+	keys::{Action, Key, KeyBindings},
+	local_highscore::LocalHighscores,
+	pending_highscore::PendingHighscores,
+	renderer::{Line, Page, Renderer, ScreenType, Span},
 };
 
-/// the height of the window
-const WINDOW_HEIGHT: usize = 28;
-/// where the loading is displayed
-const LOADING_POSITION: usize = 13;
-/// the two types of backgrounds displayed in the score table
-const ALT_BG: [&str; 2] = [ANSI_RESET_BG, "\x1B[48;5;233m"];
+/// how long each frame of the loading animation is shown
+const LOADING_FRAME_DURATION: Duration = Duration::from_millis(100);
+/// the frames of the loading animation where `P` is the player, `B` a block, `C` a beast and a space an empty tile
+const LOADING_FRAMES: [&str; 12] = [
+	"P  BCB", " P BCB", "  PBCB", "   PBB", "    PB", "     P", "BCB  P", "BCB P ", "BCBP  ", "BBP   ", "BP    ",
+	"P     ",
+];
+/// how many empty lines are between the logo and the loading animation
+const LOADING_POSITION: usize = 6;
+/// the mark behind the name of scores reached in practice games
+const PRACTICE_TAG: &str = " (practice)";
 
 /// the state our highscore can be in
 #[derive(Debug, Clone, PartialEq)]
@@ -34,126 +48,246 @@ pub enum State {
 	Quit,
 }
 
+/// the ways submitting a score can fail
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubmitError {
+	/// the server could not be reached or had a problem so we should try again later
+	Unreachable(String),
+	/// the server refused the score so there is no point in trying again
+	Rejected(String),
+}
+
+/// post a single score to the highscore server
+pub fn post_score(url: &str, score: &Score) -> Result<(), SubmitError> {
+	let payload = Highscores::ron_to_str(score).map_err(|error| SubmitError::Rejected(error.to_string()))?;
+	let response = blocking::Client::new()
+		.post(url)
+		.header(CONTENT_TYPE, "application/x-ron")
+		.body(payload)
+		.send()
+		.map_err(|error| SubmitError::Unreachable(error.to_string()))?;
+
+	if response.status().is_success() {
+		Ok(())
+	} else {
+		let is_server_error = response.status().is_server_error();
+		let error = response.text().unwrap_or_else(|_| String::from("Could not read error response"));
+		if is_server_error {
+			Err(SubmitError::Unreachable(error))
+		} else {
+			Err(SubmitError::Rejected(error))
+		}
+	}
+}
+
+/// the tabs of the highscore screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tab {
+	/// the scores recorded on this machine
+	Local,
+	/// the scores synced with the server
+	Global,
+}
+
 /// the highscore fetches its own data from the server and keeps track of it's scroll position
 pub struct Highscore {
 	scroll: usize,
-	screen_array: Arc<Mutex<Vec<String>>>,
+	rows: Arc<Mutex<Vec<Line>>>,
+	local_rows: Vec<Line>,
+	local: LocalHighscores,
+	pending: PendingHighscores,
+	/// how many scores are waiting to be submitted to the server
+	pending_count: Arc<Mutex<usize>>,
+	/// what went wrong when loading or submitting failed
+	error: Arc<Mutex<String>>,
+	/// when we started loading which drives the loading animation
+	loading_since: Instant,
+	config: Config,
+	key_bindings: KeyBindings,
+	offline: bool,
+	tab: Tab,
 	pub state: Arc<Mutex<State>>,
 }
 
 impl Highscore {
-	fn new() -> Self {
-		let mut screen_array = Vec::with_capacity(112);
-		screen_array.extend(LOGO.iter().map(|&s| s.to_string()));
-		screen_array.push(format!(
-			"{ANSI_LEFT_BORDER}                                            {ANSI_BOLD}HIGHSCORES{ANSI_RESET}                                              {ANSI_RIGHT_BORDER}"
-		));
-		screen_array.push(format!(
-			"{ANSI_LEFT_BORDER}     \x1B[38;5;241mPOS  SCORE  NAME                                                LEVEL  DATE{ANSI_RESET_FONT}                    {ANSI_RIGHT_BORDER}",
-		));
-		screen_array.push(format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}"));
-
-		for i in 1..=MAX_SCORES {
-			let bg = ALT_BG[(i + 1) % 2];
-			screen_array.push(format!(
-			"{ANSI_LEFT_BORDER}   {bg}  {i:<3}  {ANSI_BOLD}    -{ANSI_RESET}{bg}  ...                                                                             {ANSI_RESET_BG}   {ANSI_RIGHT_BORDER}"));
-		}
+	fn new(config: &Config) -> Self {
+		let offline = config.offline;
+		let mut local_rows = Self::empty_rows();
+		let local = LocalHighscores::new();
+		Self::inject_score_into_rows(&mut local_rows, &local.load());
+		let pending = PendingHighscores::new();
 
 		Self {
 			scroll: 0,
-			screen_array: Arc::new(Mutex::new(screen_array)),
+			rows: Arc::new(Mutex::new(Self::empty_rows())),
+			local_rows,
+			local,
+			pending_count: Arc::new(Mutex::new(pending.count())),
+			pending,
+			error: Arc::new(Mutex::new(String::new())),
+			loading_since: Instant::now(),
+			config: config.clone(),
+			key_bindings: config.key_bindings(),
+			offline,
+			tab: if offline { Tab::Local } else { Tab::Global },
 			state: Arc::new(Mutex::new(State::Loading)),
 		}
 	}
 
-	/// create a new instance of highscore and default to a loading state
-	pub fn new_loading() -> Self {
-		let highscore = Self::new();
-		highscore.fetch_data();
+	/// the rows of the score table before any scores are known
+	fn empty_rows() -> Vec<Line> {
+		(0..MAX_SCORES)
+			.map(|index| {
+				Line::new(vec![
+					Span::plain(format!("  {:<3}  ", index + 1)),
+					Span::bold("    -"),
+					Span::plain("  ..."),
+				])
+				.shaded(index % 2 == 1)
+			})
+			.collect()
+	}
+
+	/// create a new instance of highscore and default to a loading state unless we're offline
+	pub fn new_loading(config: &Config) -> Self {
+		let mut highscore = Self::new(config);
+		if config.offline {
+			*highscore.state.lock().unwrap() = State::Idle;
+		} else {
+			highscore.fetch_data();
+		}
 		highscore
 	}
 
 	/// create a new instance of highscore and default to an idle state
-	pub fn new_idle() -> Self {
-		let highscore = Self::new();
+	pub fn new_idle(config: &Config) -> Self {
+		let highscore = Self::new(config);
 		*highscore.state.lock().unwrap() = State::Idle;
 		highscore
 	}
 
-	/// listen to stdin to capture the name you want to enter into the highscore
-	pub fn handle_enter_name(&mut self, input_listener: &Receiver<u8>, score: u16, level: Level) -> Option<()> {
-		let mut name = String::new();
+	/// the tab currently displayed
+	pub fn tab(&self) -> Tab {
+		self.tab
+	}
 
-		println!("{}", Self::render_score_input_screen(name.clone()));
+	/// switch between the local and global tab, there is nothing to switch to when we're offline
+	pub fn switch_tab(&mut self) {
+		if self.offline {
+			return;
+		}
 
-		loop {
-			if let Ok(byte) = input_listener.try_recv() {
-				match byte as char {
-					'\n' => {
-						if !name.is_empty() {
-							break;
-						}
-					},
-					'\u{7f}' | '\x08' => {
-						name.pop();
-						println!("{}", Self::render_score_input_screen(name.clone()));
-					},
-					' ' => {
-						name.push(' ');
-						println!("{}", Self::render_score_input_screen(name.clone()));
-					},
-					c @ ('a'..='z'
-					| 'A'..='Z'
-					| '0'..='9'
-					| '!'
-					| '@'
-					| '#'
-					| '$'
-					| '%'
-					| '^'
-					| '&'
-					| '*'
-					| '('
-					| ')'
-					| '_'
-					| '+'
-					| '='
-					| '-'
-					| ':'
-					| ';'
-					| '"'
-					| '\''
-					| '?'
-					| '<'
-					| '>'
-					| '['
-					| ']'
-					| '{'
-					| '}'
-					| '|'
-					| '\\'
-					| '/'
-					| ','
-					| '.') => {
-						if name.len() < MAX_NAME_LENGTH {
-							name.push(c);
-							println!("{}", Self::render_score_input_screen(name.clone()));
-						}
-					},
-					_ => {},
+		self.tab = match self.tab {
+			Tab::Local => Tab::Global,
+			Tab::Global => Tab::Local,
+		};
+		self.scroll = 0;
+
+		if self.tab == Tab::Global && *self.state.lock().unwrap() == State::Error {
+			// we retry fetching when coming back to a failed global highscore
+			*self.state.lock().unwrap() = State::Loading;
+			self.fetch_data();
+		}
+	}
+
+	/// reload the scores of the tab currently displayed
+	pub fn refresh(&mut self) {
+		match self.tab {
+			Tab::Local => {
+				self.local_rows = Self::empty_rows();
+				Self::inject_score_into_rows(&mut self.local_rows, &self.local.load());
+				*self.pending_count.lock().unwrap() = self.pending.count();
+			},
+			Tab::Global => {
+				let mut state = self.state.lock().unwrap();
+				if *state == State::Idle || *state == State::Error {
+					*state = State::Loading;
+					drop(state);
+					self.fetch_data();
 				}
+			},
+		}
+	}
+
+	/// the local scores can always be scrolled while the global scores need to be loaded first
+	pub fn can_scroll(&self) -> bool {
+		self.tab() == Tab::Local || *self.state.lock().unwrap() == State::Idle
+	}
+
+	/// listen to stdin to capture the name you want to enter into the highscore, scores that aren't ranked are only
+	/// renamed locally
+	pub fn handle_enter_name(
+		&mut self,
+		input_listener: &Receiver<u8>,
+		renderer: &mut dyn Renderer,
+		score: u16,
+		level: Level,
+		local_entry: Option<OffsetDateTime>,
+		ranked: bool,
+	) -> Option<()> {
+		let mut name = Self::initial_name(self.config.name.as_deref());
+
+		renderer.render_page(&Self::render_score_input_screen(&name));
+
+		loop {
+			if Game::handle_redraw(renderer).is_some() {
+				renderer.render_page(&Self::render_score_input_screen(&name));
+			}
+
+			match input::read_event(input_listener, INPUT_POLL_DURATION) {
+				Some(KeyEvent::Key { key: Key::Enter, .. }) if !name.is_empty() => break,
+				Some(KeyEvent::Key {
+					key: Key::Backspace, ..
+				}) => {
+					name.pop();
+					renderer.render_page(&Self::render_score_input_screen(&name));
+				},
+				Some(KeyEvent::Key {
+					key: Key::Char(c),
+					modifiers,
+				}) if modifiers.is_plain() && Self::is_name_char(c) && name.chars().count() < MAX_NAME_LENGTH => {
+					name.push(c);
+					renderer.render_page(&Self::render_score_input_screen(&name));
+				},
+				Some(KeyEvent::Paste(text)) => {
+					Self::extend_name(&mut name, text.chars());
+					renderer.render_page(&Self::render_score_input_screen(&name));
+				},
+				_ => {},
 			}
 		}
 
-		*self.state.lock().unwrap() = State::Loading;
-		self.render_loading();
-		println!("{}", Self::render_loading_screen());
-		self.submit_name(&name, score, level)
+		if let Some(timestamp) = local_entry {
+			let _ = self.local.rename(timestamp, &name);
+		}
+
+		// practice and training games are only recorded locally
+		if !ranked {
+			return Some(());
+		}
+
+		self.submit_name(renderer, &name, score, level)
+	}
+
+	/// the name the input starts with, taken from the config and cut down to what could have been typed in
+	fn initial_name(name: Option<&str>) -> String {
+		name.unwrap_or_default().chars().filter(|c| Self::is_name_char(*c)).take(MAX_NAME_LENGTH).collect()
+	}
+
+	/// add pasted characters to a name dropping the ones not allowed and the ones that don't fit anymore
+	fn extend_name(name: &mut String, chars: impl Iterator<Item = char>) {
+		let room = MAX_NAME_LENGTH.saturating_sub(name.chars().count());
+		name.extend(chars.filter(|c| Self::is_name_char(*c)).take(room));
+	}
+
+	/// the characters allowed in a highscore name
+	fn is_name_char(c: char) -> bool {
+		c.is_ascii_alphanumeric() || c == ' ' || "!@#$%^&*()_+=-:;\"'?<>[]{}|\\/,.".contains(c)
 	}
 
-	/// scroll down
-	pub fn scroll_down(&mut self) {
-		self.scroll = if self.scroll >= 85 { 85 } else { self.scroll + 1 };
+	/// scroll down until the given number of lines are scrolled out
+	pub fn scroll_down(&mut self, max: usize) {
+		self.scroll = (self.scroll + 1).min(max);
 	}
 
 	/// scroll up
@@ -161,323 +295,278 @@ impl Highscore {
 		self.scroll = if self.scroll == 0 { 0 } else { self.scroll - 1 };
 	}
 
-	/// render the highscore while taking into account the scroll position
-	pub fn render(&self) -> String {
-		let state = self.state.lock().unwrap();
-		let screen_array = self.screen_array.lock().unwrap();
-		match *state {
-			State::Loading => {
-				self.render_loading();
-				Self::render_loading_screen()
-			},
-			State::Idle => Self::render_score(screen_array.clone(), self.scroll),
-			State::Error => String::new(),
-			State::Quit => String::new(),
+	/// render the tab currently displayed while taking into account the scroll position
+	pub fn render(&self) -> Page {
+		if self.tab == Tab::Local {
+			return self.render_score(&self.local_rows);
+		}
+
+		let state = self.state.lock().unwrap().clone();
+		match state {
+			State::Idle => self.render_score(&self.rows.lock().unwrap()),
+			State::Error => self.render_loading_screen(Some(&self.error.lock().unwrap())),
+			State::Loading | State::Quit => self.render_loading_screen(None),
 		}
 	}
 
-	/// fetch the data from the server
-	pub fn fetch_data(&self) {
+	/// fetch the data from the server in the background
+	pub fn fetch_data(&mut self) {
 		let state_clone = Arc::clone(&self.state);
-		let screen_array_clone = Arc::clone(&self.screen_array);
-		let scroll_clone = self.scroll;
+		let rows_clone = Arc::clone(&self.rows);
+		let error_clone = Arc::clone(&self.error);
+		let pending_count_clone = Arc::clone(&self.pending_count);
+		let pending = self.pending.clone();
+		let url = self.config.highscore_endpoint();
+		self.loading_since = Instant::now();
 
 		thread::spawn(move || {
-			let mut url = env::var("HIGHSCORE_URL").unwrap_or(String::from("https://dominik-wilkowski.com/beast"));
-			url.push_str("/highscore");
-
-			match blocking::get(url) {
-				Ok(responds) => match responds.text() {
-					Ok(body) => {
-						if let Ok(mut state) = state_clone.lock() {
-							if let Ok(mut screen_array) = screen_array_clone.lock() {
-								match Highscores::ron_from_str(&body) {
-									Ok(data) => {
-										Self::inject_score_into_screen_array(&mut screen_array, &data);
-										if *state == State::Loading {
-											*state = State::Idle;
-											println!("{}", Self::render_score(screen_array.clone(), scroll_clone));
-										}
-									},
-									Err(error) => {
-										if *state == State::Loading {
-											*state = State::Error;
-											println!(
-												"{}{}",
-												Self::render_loading_screen(),
-												Self::render_error(format!("Failed to parse highscores file: {error}"))
-											);
-										}
-									},
-								}
-							};
-						}
-					},
-					Err(error) => {
-						if let Ok(mut state) = state_clone.lock() {
-							if *state == State::Loading {
-								*state = State::Error;
-								println!(
-									"{}{}",
-									Self::render_loading_screen(),
-									Self::render_error(format!("Error reading highscore data: {error}"))
-								);
-							}
-						}
-					},
+			// submitting what's queued first means the scores we're about to fetch include them
+			*pending_count_clone.lock().unwrap() = pending.sync(&url);
+
+			let data = blocking::get(url)
+				.map_err(|error| format!("Fetching highscore failed: {error}"))
+				.and_then(|response| response.text().map_err(|error| format!("Error reading highscore data: {error}")))
+				.and_then(|body| {
+					Highscores::ron_from_str(&body).map_err(|error| format!("Failed to parse highscores file: {error}"))
+				});
+
+			let mut state = state_clone.lock().unwrap();
+			if *state != State::Loading {
+				return;
+			}
+			match data {
+				Ok(data) => {
+					Self::inject_score_into_rows(&mut rows_clone.lock().unwrap(), &data);
+					*state = State::Idle;
 				},
 				Err(error) => {
-					if let Ok(mut state) = state_clone.lock() {
-						if *state == State::Loading {
-							*state = State::Error;
-							println!(
-								"{}{}",
-								Self::render_loading_screen(),
-								Self::render_error(format!("Fetching highscore failed: {error}"))
-							);
-						}
-					}
+					*error_clone.lock().unwrap() = error;
+					*state = State::Error;
 				},
 			}
 		});
 	}
 
-	fn submit_name(&self, name: &str, score: u16, level: Level) -> Option<()> {
-		let state_clone = Arc::clone(&self.state);
-		let name_clone = name.to_string();
-
-		let mut url = env::var("HIGHSCORE_URL").unwrap_or(String::from("https://dominik-wilkowski.com/beast"));
-		url.push_str("/highscore");
-
-		match Highscores::ron_to_str(&Score {
-			name: name_clone,
+	/// submit the score in the background while the loading animation plays
+	fn submit_name(&mut self, renderer: &mut dyn Renderer, name: &str, score: u16, level: Level) -> Option<()> {
+		let score = Score {
+			name: name.to_string(),
 			score,
 			level,
-		}) {
-			Ok(payload) => {
-				match blocking::Client::new().post(&url).header(CONTENT_TYPE, "application/x-ron").body(payload).send() {
-					Ok(response) => {
-						if let Ok(mut state) = state_clone.lock() {
-							if *state == State::Loading {
-								if response.status().is_success() {
-									*state = State::Idle;
-									Some(())
-								} else {
-									*state = State::Error;
-									let error = response.text().unwrap_or_else(|_| String::from("Could not read error response"));
-									println!(
-										"{}{}",
-										Self::render_loading_screen(),
-										Self::render_error(format!("Failed to post highscore: {error}"))
-									);
-									None
-								}
-							} else {
-								None
-							}
-						} else {
-							None
-						}
-					},
-					Err(error) => {
-						if let Ok(mut state) = state_clone.lock() {
-							if *state == State::Loading {
-								*state = State::Error;
-								println!(
-									"{}{}",
-									Self::render_loading_screen(),
-									Self::render_error(format!("Failed to parse highscores file: {error}"))
-								);
-							}
-						}
-						None
-					},
-				}
+		};
+
+		let (sender, receiver) = mpsc::channel();
+		let url = self.config.highscore_endpoint();
+		let submitted = score.clone();
+		thread::spawn(move || {
+			let _ = sender.send(post_score(&url, &submitted));
+		});
+
+		*self.state.lock().unwrap() = State::Loading;
+		self.loading_since = Instant::now();
+		let result = loop {
+			renderer.render_page(&self.render_loading_screen(None));
+			match receiver.recv_timeout(LOADING_FRAME_DURATION) {
+				Ok(result) => break result,
+				Err(RecvTimeoutError::Timeout) => {},
+				Err(RecvTimeoutError::Disconnected) => {
+					break Err(SubmitError::Unreachable(String::from("The submission stopped unexpectedly")));
+				},
+			}
+		};
+
+		let error = match result {
+			Ok(()) => {
+				*self.state.lock().unwrap() = State::Idle;
+				return Some(());
 			},
-			Err(error) => {
-				if let Ok(mut state) = state_clone.lock() {
-					if *state == State::Loading {
-						*state = State::Error;
-						println!(
-							"{}{}",
-							Self::render_loading_screen(),
-							Self::render_error(format!("Failed to parse highscores file: {error}"))
-						);
-					}
-				}
-				None
+			Err(SubmitError::Rejected(error)) => format!("Failed to post highscore: {error}"),
+			Err(SubmitError::Unreachable(error)) => match self.pending.push(score) {
+				Ok(()) => format!("Highscore server unreachable, your score will be submitted later: {error}"),
+				Err(_) => format!("Failed to post highscore: {error}"),
 			},
-		}
+		};
+
+		*self.state.lock().unwrap() = State::Error;
+		renderer.render_page(&self.render_loading_screen(Some(&error)));
+		*self.error.lock().unwrap() = error;
+		None
 	}
 
-	fn inject_score_into_screen_array(screen_array: &mut [String], data: &Highscores) {
-		for (index, score) in data.scores.iter().enumerate() {
-			let bg = ALT_BG[index % 2];
-			screen_array[index + 13] = format!(
-				"{ANSI_LEFT_BORDER}   {bg}  {:<3}  {ANSI_BOLD}{:>5}{ANSI_RESET}{bg}  {:<50}  {:<5}  \x1B[38;5;239m{:<19}{ANSI_RESET_FONT}  {ANSI_RESET_BG}   {ANSI_RIGHT_BORDER}",
-				index + 1,
-				score.score,
-				score.name,
-				score.level.to_string(),
-				score.format_timestamp(),
-			);
+	fn inject_score_into_rows(rows: &mut [Line], data: &Highscores) {
+		// a file with more scores than rows only fills the rows there are
+		for (index, (row, score)) in rows.iter_mut().zip(&data.scores).enumerate() {
+			// practice scores are marked behind the name which is cut short to make room if needed
+			let tag = if score.practice { PRACTICE_TAG } else { "" };
+			let name = score.name.chars().take(MAX_NAME_LENGTH - tag.len()).collect::<String>();
+			let padding = MAX_NAME_LENGTH - name.chars().count() - tag.len();
+			*row = Line::new(vec![
+				Span::plain(format!("  {:<3}  ", index + 1)),
+				Span::bold(format!("{:>5}", score.score)),
+				Span::plain(format!("  {name}")),
+				Span::muted(tag),
+				Span::plain(format!("{:padding$}  {:<5}  ", "", score.level.to_string())),
+				Span::faint(format!("{:<19}", score.format_timestamp())),
+			])
+			.shaded(index % 2 == 1);
 		}
 	}
 
-	/// render the loading screen
-	pub fn render_loading_screen() -> String {
-		let mut output = String::new();
-		let top_pos = format!("\x1b[{}F", ANSI_FRAME_SIZE + ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-		let bottom_pos = format!("\x1b[{}E", ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-
-		output.push_str(&top_pos);
-		output.push_str(&LOGO.join("\n"));
-		output.push('\n');
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                  {ANSI_BOLD}[SPACE]{ANSI_RESET} Play  {ANSI_BOLD}[Q]{ANSI_RESET} Quit  {ANSI_BOLD}[H]{ANSI_RESET} Help                                  {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&bottom_pos);
-
-		output
-	}
-
-	fn render_score_input_screen(name: String) -> String {
-		let mut output = String::new();
-		let top_pos = format!("\x1b[{}F", ANSI_FRAME_SIZE + ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-		let bottom_pos = format!("\x1b[{}E", ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-
-		output.push_str(&top_pos);
-		output.push_str(&LOGO.join("\n"));
-		output.push('\n');
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                        Enter your name below                                       {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                        ┌──────────────────────────────────────────────────┐                        {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!(
-			"{ANSI_LEFT_BORDER}                        │{name:<50}│                        {ANSI_RIGHT_BORDER}\n"
-		));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                        └──────────────────────────────────────────────────┘                        {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                        {ANSI_BOLD}[ENTER]{ANSI_RESET} Submit score                                        {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&bottom_pos);
-
-		output
-	}
-
-	/// render the loading animaiton in a separate thread
-	pub fn render_loading(&self) {
-		let state_clone = Arc::clone(&self.state);
+	/// render the loading screen with the animation or the error that stopped the loading
+	fn render_loading_screen(&self, error: Option<&str>) -> Page {
+		let mut lines = vec![Line::blank(); LOADING_POSITION];
+		match error {
+			Some(error) => {
+				lines.push(Line::blank());
+				lines.push(Line::centered(vec![Span::plain(error.chars().take(98).collect::<String>())]));
+			},
+			None => {
+				let frame = (self.loading_since.elapsed().as_millis() / LOADING_FRAME_DURATION.as_millis()) as usize;
+				lines.push(Line::centered(vec![Span::plain("LOADING")]));
+				lines.push(Line::centered(Self::render_loading_frame(LOADING_FRAMES[frame % LOADING_FRAMES.len()])));
+			},
+		}
 
-		thread::spawn(move || {
-			let player = Tile::Player;
-			let block = Tile::Block;
-			let beast = Tile::CommonBeast;
-			let loading_frames = [
-				format!("{player}    {block}{beast}{block}"),
-				format!("  {player}  {block}{beast}{block}"),
-				format!("    {player}{block}{beast}{block}"),
-				format!("      {player}{block}{block}"),
-				format!("        {player}{block}"),
-				format!("          {player}"),
-				format!("{block}{beast}{block}    {player}"),
-				format!("{block}{beast}{block}  {player}  "),
-				format!("{block}{beast}{block}{player}    "),
-				format!("{block}{block}{player}      "),
-				format!("{block}{player}        "),
-				format!("{player}          "),
-			];
-			let mut frame_index = 0;
-
-			while *state_clone.lock().unwrap() == State::Loading {
-				let top_pos = format!("\x1b[{}F", LOADING_POSITION + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT + 2);
-				let bottom_pos = format!("\x1b[{}E", LOADING_POSITION + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-				println!(
-					"{top_pos}{ANSI_LEFT_BORDER}                                               LOADING                                              {ANSI_RIGHT_BORDER}"
-				);
-				println!(
-					"{ANSI_LEFT_BORDER}                                            {:>12}                                            {ANSI_RIGHT_BORDER}{bottom_pos}",
-					loading_frames[frame_index]
-				);
-				frame_index += 1;
-				if frame_index >= loading_frames.len() {
-					frame_index = 0;
-				}
-				std::thread::sleep(Duration::from_millis(100));
-			}
-		});
+		Page::new(
+			ScreenType::Highscore,
+			lines,
+			vec![Line::centered(vec![
+				Span::bold("[SPACE]"),
+				Span::plain(" Play  "),
+				self.key_bindings.label(Action::Quit),
+				Span::plain(" Quit  "),
+				self.key_bindings.label(Action::Help),
+				Span::plain(" Help"),
+			])],
+		)
 	}
 
-	fn render_error(mut error: String) -> String {
-		let top_pos = format!("\x1b[{}F", LOADING_POSITION + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT + 1);
-		let bottom_pos = format!("\x1b[{}E", LOADING_POSITION + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT + 1);
-		error.truncate(98);
-		format!("{top_pos}{ANSI_LEFT_BORDER}{error:^100}{ANSI_RESET}{ANSI_RIGHT_BORDER}{bottom_pos}")
+	/// turn a frame of the loading animation into tiles
+	fn render_loading_frame(frame: &str) -> Vec<Span> {
+		frame
+			.chars()
+			.map(|c| {
+				Span::Tile(match c {
+					'P' => Tile::Player,
+					'B' => Tile::Block,
+					'C' => Tile::CommonBeast,
+					_ => Tile::Empty,
+				})
+			})
+			.collect()
 	}
 
-	fn render_score(screen_array: Vec<String>, scroll: usize) -> String {
-		let mut output = String::new();
-		let top_pos = format!("\x1b[{}F", ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT + 1);
-		let bottom_pos = format!("\x1b[{}E", ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT);
-		let start = scroll;
-		let end = (scroll + WINDOW_HEIGHT).min(screen_array.len());
+	fn render_score_input_screen(name: &str) -> Page {
+		let mut lines = vec![Line::blank(); 7];
+		lines.push(Line::centered(vec![Span::plain("Enter your name below")]));
+		lines.push(Line::centered(vec![Span::plain(format!("┌{}┐", "─".repeat(MAX_NAME_LENGTH)))]));
+		lines.push(Line::centered(vec![Span::plain(format!("│{name:<MAX_NAME_LENGTH$}│"))]));
+		lines.push(Line::centered(vec![Span::plain(format!("└{}┘", "─".repeat(MAX_NAME_LENGTH)))]));
+
+		Page::new(
+			ScreenType::Highscore,
+			lines,
+			vec![Line::centered(vec![
+				Span::bold("[ENTER]"),
+				Span::plain(" Submit score"),
+			])],
+		)
+	}
 
-		output.push_str(&top_pos);
-		output.push_str(&screen_array[start..end].join("\n"));
-		output.push('\n');
-		output.push_str(&format!("{ANSI_LEFT_BORDER}                                                                                                    {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&format!("{ANSI_LEFT_BORDER}            {ANSI_BOLD}[SPACE]{ANSI_RESET} Play  {ANSI_BOLD}[Q]{ANSI_RESET} Quit  {ANSI_BOLD}[H]{ANSI_RESET} Help  {ANSI_BOLD}[↓]{ANSI_RESET} Scroll Down  {ANSI_BOLD}[↑]{ANSI_RESET} Scroll Up  {ANSI_BOLD}[R]{ANSI_RESET} Refresh           {ANSI_RIGHT_BORDER}\n"));
-		output.push_str(&bottom_pos);
+	fn render_tabs(tab: Tab, offline: bool, pending: usize) -> Line {
+		let local = match tab {
+			Tab::Local => Span::bold("[ LOCAL ]"),
+			Tab::Global => Span::plain("  LOCAL  "),
+		};
+		let global = match (tab, offline) {
+			(_, true) => Span::muted("  GLOBAL (OFFLINE)  "),
+			(Tab::Global, false) => Span::bold("[ GLOBAL ]"),
+			(Tab::Local, false) => Span::plain("  GLOBAL  "),
+		};
+		let tabs_width = local.text().len() + 2 + global.text().len();
+		let left_pad = (100 - tabs_width) / 2;
+		let mut spans = vec![
+			Span::plain(format!("{:left_pad$}", "")),
+			local,
+			Span::plain("  "),
+			global,
+		];
+
+		if pending > 0 {
+			// the sync indicator sits at the right edge so the tabs stay centered
+			let indicator = format!("{pending} PENDING SYNC");
+			let right_pad = 100 - left_pad - tabs_width - indicator.len() - 3;
+			spans.push(Span::plain(format!("{:right_pad$}", "")));
+			spans.push(Span::highlight(indicator));
+		}
+		Line::new(spans)
+	}
+
+	fn render_footer(&self) -> Line {
+		Line::centered(vec![
+			Span::bold("[SPACE]"),
+			Span::plain(" Play  "),
+			self.key_bindings.label(Action::Quit),
+			Span::plain(" Quit  "),
+			self.key_bindings.label(Action::Help),
+			Span::plain(" Help  "),
+			self.key_bindings.label(Action::Up),
+			Span::plain(" "),
+			self.key_bindings.label(Action::Down),
+			Span::plain(" Scroll  "),
+			self.key_bindings.label(Action::Restart),
+			Span::plain(" Refresh  "),
+			Span::bold("[TAB]"),
+			Span::plain(" Local/Global"),
+		])
+	}
 
-		output
+	fn render_score(&self, rows: &[Line]) -> Page {
+		let mut lines = vec![
+			Line::centered(vec![Span::bold("HIGHSCORES")]),
+			Self::render_tabs(self.tab, self.offline, *self.pending_count.lock().unwrap()),
+			Line::new(vec![
+				Span::plain("     "),
+				Span::muted(format!("POS  SCORE  NAME{:48}LEVEL  DATE", "")),
+			]),
+		];
+		lines.extend_from_slice(rows);
+
+		Page {
+			scroll: self.scroll,
+			..Page::new(ScreenType::Highscore, lines, vec![Line::blank(), self.render_footer()])
+		}
 	}
 }
 
 #[cfg(test)]
 mod test {
 	use super::*;
-	use crate::{BOARD_WIDTH, test_common::strip_ansi_border};
+	use crate::{
+		BOARD_WIDTH, LOGO,
+		game::ANSI_BOARD_HEIGHT,
+		keys::KeyPreset,
+		renderer::{AnsiRenderer, strip_ansi},
+	};
+
+	/// all lines of a page as text
+	fn page_text(page: &Page) -> Vec<String> {
+		page.lines.iter().chain(&page.footer).map(Line::text).collect()
+	}
 
 	#[test]
 	fn initial_state_test() {
-		let highscore_idle = Highscore::new_idle();
+		let highscore_idle = Highscore::new_idle(&Config::default());
 		assert_eq!(*highscore_idle.state.lock().unwrap(), State::Idle, "The initial state from new_idle should be Idle");
 	}
 
 	#[test]
 	fn scroll_test() {
-		let mut highscore = Highscore::new_idle();
+		let mut highscore = Highscore::new_idle(&Config::default());
 		assert_eq!(highscore.scroll, 0, "Initial scroll should be 0");
 
-		highscore.scroll_down();
+		let max = highscore.render().max_scroll(BOARD_WIDTH * 2, ANSI_BOARD_HEIGHT);
+		highscore.scroll_down(max);
 		assert_eq!(highscore.scroll, 1, "After scrolling down once, scroll should be 1");
 
 		highscore.scroll_up();
@@ -487,66 +576,90 @@ mod test {
 		assert_eq!(highscore.scroll, 0, "Scrolling up at minimum should remain at 0");
 
 		for _ in 0..100 {
-			highscore.scroll_down();
+			highscore.scroll_down(max);
 		}
-		assert_eq!(highscore.scroll, 85, "Scroll should cap at 85");
+		assert_eq!(highscore.scroll, 85, "Scroll should cap at 85 in the full layout");
+		assert_eq!(
+			LOGO.len() + highscore.render().lines.len() - 85,
+			ANSI_BOARD_HEIGHT - highscore.render().footer.len(),
+			"Scrolling to the cap should show the last score at the bottom of the board"
+		);
 	}
 
 	#[test]
-	fn screen_array_initialization_test() {
-		let highscore = Highscore::new_idle();
-		let screen_array = highscore.screen_array.lock().unwrap().clone();
+	fn rows_initialization_test() {
+		let page = Highscore::new_idle(&Config::default()).render();
+
+		assert_eq!(page.lines[0].text(), "HIGHSCORES", "Title should contain HIGHSCORES");
+		assert_eq!(page.lines.len(), 3 + MAX_SCORES, "There should be a line for every score");
+		assert!(page.lines[3].text().contains("  1  "), "First score placeholder should have index 1");
+		assert!(page.lines[3].text().contains('-'), "First score placeholder should have a dash");
+		assert!(!page.lines[3].shaded && page.lines[4].shaded, "Every other row should be shaded");
+	}
 
-		for (i, logo_line) in LOGO.iter().enumerate() {
-			assert_eq!(screen_array[i], logo_line.to_string(), "Logo line {i} should be copied correctly");
-		}
+	#[test]
+	fn render_loading_screen_test() {
+		let highscore = Highscore::new_idle(&Config::default());
+		let page = highscore.render_loading_screen(None);
 
-		assert!(screen_array[LOGO.len()].contains("HIGHSCORES"), "Title should contain HIGHSCORES");
-		assert_eq!(screen_array.len(), LOGO.len() + 3 + MAX_SCORES, "Screen array should have the correct number of lines");
-		assert!(screen_array[LOGO.len() + 3].contains("  1  "), "First score placeholder should have index 1");
-		assert!(screen_array[LOGO.len() + 3].contains("-"), "First score placeholder should have a dash");
+		assert!(
+			LOGO.len() + page.lines.len() + page.footer.len() <= ANSI_BOARD_HEIGHT,
+			"The loading screen should fit into the board"
+		);
+		assert_eq!(page.lines[LOADING_POSITION].text(), "LOADING", "The loading screen should say it's loading");
+		assert_eq!(
+			page.lines[LOADING_POSITION + 1].text().chars().count(),
+			12,
+			"The loading animation should be six tiles wide"
+		);
 	}
 
 	#[test]
-	fn render_loading_screen_line_length_test() {
-		let output = Highscore::render_loading_screen();
-
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 1 {
-				assert_eq!(strip_ansi_border(line).len(), BOARD_WIDTH * 2, "Line {i} should be the correct length");
-			}
+	fn render_score_input_screen_test() {
+		let page = Highscore::render_score_input_screen("TestPlayer");
+		assert!(
+			LOGO.len() + page.lines.len() + page.footer.len() <= ANSI_BOARD_HEIGHT,
+			"The input screen should fit into the board"
+		);
+		for line in page_text(&page) {
+			assert!(line.chars().count() <= 100, "The line \"{line}\" should fit into the board");
 		}
 	}
 
 	#[test]
-	fn render_score_input_screen_line_length_test() {
-		let name = String::from("TestPlayer");
-		let output = Highscore::render_score_input_screen(name);
-
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 1 {
-				assert_eq!(strip_ansi_border(line).len(), BOARD_WIDTH * 2, "Line {i} should be the correct length");
-			}
-		}
+	fn paste_into_long_non_ascii_name_test() {
+		let config_name = format!("Dömïnïk {}", "ø".repeat(MAX_NAME_LENGTH * 2));
+		let mut name = Highscore::initial_name(Some(&config_name));
+		assert_eq!(name, "Dmnk ", "Characters that can't be typed should be dropped from the config name");
+
+		name = "é".repeat(MAX_NAME_LENGTH * 2);
+		Highscore::extend_name(&mut name, "pasted".chars());
+		assert_eq!(name.chars().count(), MAX_NAME_LENGTH * 2, "Nothing should be pasted into a name that is too long");
+
+		let mut name = Highscore::initial_name(Some(&"Ab".repeat(MAX_NAME_LENGTH)));
+		assert_eq!(name.chars().count(), MAX_NAME_LENGTH, "The config name should be cut to the max length");
+		name.pop();
+		Highscore::extend_name(&mut name, "ü12".chars());
+		assert_eq!(name.chars().count(), MAX_NAME_LENGTH, "Pasting should fill the name up to the max length");
+		assert!(name.ends_with('1'), "Pasting should skip characters that aren't allowed");
 	}
 
 	#[test]
 	fn render_score_input_screen_name_display_test() {
-		let empty_name = "".to_string();
-		let output_empty = Highscore::render_score_input_screen(empty_name);
-		assert!(output_empty.contains("│                                                  │"), "Input box should be empty");
+		let output_empty = page_text(&Highscore::render_score_input_screen(""));
+		assert!(
+			output_empty.contains(&String::from("│                                                  │")),
+			"Input box should be empty"
+		);
 
-		let name = "TestPlayer".to_string();
-		let output = Highscore::render_score_input_screen(name);
+		let output = page_text(&Highscore::render_score_input_screen("TestPlayer"));
 		assert!(
-			output.contains("│TestPlayer                                        │"),
+			output.contains(&String::from("│TestPlayer                                        │")),
 			"Input box should contain the name"
 		);
 
 		let max_name = "X".repeat(MAX_NAME_LENGTH);
-		let output_max = Highscore::render_score_input_screen(max_name);
+		let output_max = page_text(&Highscore::render_score_input_screen(&max_name));
 		assert!(
 			output_max.contains(&format!("│{:<50}│", "X".repeat(MAX_NAME_LENGTH))),
 			"Input box should contain the full max-length name"
@@ -555,26 +668,64 @@ mod test {
 
 	#[test]
 	fn render_score_line_length_test() {
-		let highscore = Highscore::new_idle();
-		let screen_array = highscore.screen_array.lock().unwrap().clone();
+		let page = Highscore::new_idle(&Config::default()).render();
+		for line in page.lines.iter().chain(&page.footer) {
+			let width = if line.shaded { 94 } else { 100 };
+			assert!(line.text().chars().count() <= width, "The line \"{}\" should fit into the board", line.text());
+		}
+	}
 
-		let output = Highscore::render_score(screen_array, 0);
+	#[test]
+	fn render_footer_line_length_test() {
+		for preset in [KeyPreset::Default, KeyPreset::Wasd, KeyPreset::Hjkl] {
+			let config = Config {
+				key_bindings: preset,
+				..Config::default()
+			};
+			assert!(
+				Highscore::new_idle(&config).render_footer().text().chars().count() <= 100,
+				"The footer for the {preset:?} preset should fit into the board"
+			);
+		}
+	}
 
-		let lines = output.lines().collect::<Vec<&str>>();
-		for (i, line) in lines.iter().enumerate() {
-			if i < lines.len() - 1 {
-				assert_eq!(strip_ansi_border(line).len(), BOARD_WIDTH * 2, "Line {i} should be the correct length");
+	#[test]
+	fn render_tabs_line_length_test() {
+		for (tab, offline) in [(Tab::Local, false), (Tab::Global, false), (Tab::Local, true)] {
+			for pending in [0, 1, 42] {
+				let tabs = Highscore::render_tabs(tab, offline, pending).text();
+				assert!(
+					tabs.chars().count() <= 100,
+					"The tabs for {tab:?} should fit into the board when offline is {offline} and {pending} are pending"
+				);
+				if pending > 0 {
+					assert_eq!(
+						tabs.chars().count(),
+						97,
+						"The sync indicator for {tab:?} should sit at the right edge when offline is {offline}"
+					);
+				}
 			}
 		}
 	}
 
 	#[test]
-	fn render_score_scroll_test() {
-		let highscore = Highscore::new_idle();
-		let mut screen_array = highscore.screen_array.lock().unwrap().clone();
+	fn switch_tab_offline_test() {
+		let config = Config {
+			offline: true,
+			..Config::default()
+		};
+		let mut highscore = Highscore::new_idle(&config);
+		assert_eq!(highscore.tab(), Tab::Local, "Offline highscores should default to the local tab");
+		highscore.switch_tab();
+		assert_eq!(highscore.tab(), Tab::Local, "Offline highscores should not switch to the global tab");
+	}
 
-		Highscore::inject_score_into_screen_array(
-			&mut screen_array,
+	#[test]
+	fn render_score_scroll_test() {
+		let mut highscore = Highscore::new_idle(&Config::default());
+		Highscore::inject_score_into_rows(
+			&mut highscore.rows.lock().unwrap(),
 			&Highscores {
 				scores: vec![
 					crate::common::Highscore::new("Dom", 666, Level::One),
@@ -582,51 +733,61 @@ mod test {
 				],
 			},
 		);
-		*highscore.screen_array.lock().unwrap() = screen_array.clone();
 
-		let output_0 = Highscore::render_score(screen_array.clone(), 0);
+		let output_0 = strip_ansi(&AnsiRenderer::render_page_frame(&highscore.render()));
 		assert!(output_0.contains("Dom"), "First score should be visible with scroll = 0");
 
-		let output_14 = Highscore::render_score(screen_array.clone(), 14);
+		for _ in 0..14 {
+			highscore.scroll_down(usize::MAX);
+		}
+		let output_14 = strip_ansi(&AnsiRenderer::render_page_frame(&highscore.render()));
 		assert!(!output_14.contains("Dom"), "First score should not be visible with scroll = 14");
 		assert!(output_14.contains("Belle"), "Second score should be visible with scroll = 14");
 	}
 
 	#[test]
 	fn state_rendering_test() {
-		let highscore = Highscore::new_idle();
-
-		assert!(!highscore.render().is_empty(), "Idle state should render content");
+		let highscore = Highscore::new_idle(&Config::default());
+		assert!(
+			page_text(&highscore.render()).iter().any(|line| line.contains("...")),
+			"Idle state should render the scores"
+		);
 
+		*highscore.error.lock().unwrap() = String::from("Server on fire");
 		*highscore.state.lock().unwrap() = State::Error;
-		assert!(highscore.render().is_empty(), "Error state should render empty string");
+		assert!(
+			page_text(&highscore.render()).contains(&String::from("Server on fire")),
+			"Error state should render the error"
+		);
 
 		*highscore.state.lock().unwrap() = State::Quit;
-		assert!(highscore.render().is_empty(), "Quit state should render empty string");
+		assert!(
+			!page_text(&highscore.render()).contains(&String::from("Server on fire")),
+			"Quit state should not render the error"
+		);
 	}
 
 	#[test]
 	fn render_method_dispatches_correctly_test() {
-		let highscore = Highscore::new_idle();
+		let highscore = Highscore::new_idle(&Config::default());
 
-		let idle_render = highscore.render();
-		assert!(!idle_render.is_empty(), "Idle state should render content");
-		assert!(idle_render.contains("Scroll Down"), "Idle state should render score screen with scroll controls");
+		let idle_render = page_text(&highscore.render());
+		assert!(
+			idle_render.iter().any(|line| line.contains("Scroll")),
+			"Idle state should render score screen with scroll controls"
+		);
 
 		*highscore.state.lock().unwrap() = State::Loading;
-		let loading_render = highscore.render();
-		assert!(!loading_render.contains("LOADING"), "Loading state should render empty screen");
+		let loading_render = page_text(&highscore.render());
+		assert!(loading_render.contains(&String::from("LOADING")), "Loading state should render the loading animation");
 	}
 
 	#[test]
-	fn inject_score_into_screen_array_test() {
-		let mut screen_array = Vec::new();
-		for _ in 0..LOGO.len() + 2 + MAX_SCORES {
-			screen_array.push(String::new());
-		}
+	fn inject_score_into_rows_test() {
+		let mut rows = Highscore::empty_rows();
 
-		Highscore::inject_score_into_screen_array(
-			&mut screen_array,
+		Highscore::inject_score_into_rows(
+			&mut rows,
 			&Highscores {
 				scores: vec![
 					crate::common::Highscore::new("Player 1", 100, Level::Six),
@@ -635,8 +796,8 @@ mod test {
 			},
 		);
 
-		let first_score_line = &screen_array[LOGO.len() + 3];
-		let second_score_line = &screen_array[LOGO.len() + 4];
+		let first_score_line = rows[0].text();
+		let second_score_line = rows[1].text();
 
 		assert!(first_score_line.contains("Player 1"), "First score line should contain Player 1");
 		assert!(first_score_line.contains("100"), "First score line should contain score 100");
@@ -644,38 +805,56 @@ mod test {
 		assert!(second_score_line.contains("Player 2"), "Second score line should contain Player 2");
 		assert!(second_score_line.contains("200"), "Second score line should contain score 200");
 		assert!(second_score_line.contains("8"), "Second score line should contain level eight");
+		assert!(rows[1].shaded, "The second score should be shaded");
+		assert!(!first_score_line.contains(PRACTICE_TAG), "Ranked scores should not be marked");
 	}
 
 	#[test]
-	fn render_error_test() {
-		let error = String::from("Short error message");
-		let error_output = Highscore::render_error(error.clone());
-		let line = error_output.lines().next().unwrap();
-		assert_eq!(strip_ansi_border(line).len(), BOARD_WIDTH * 2, "Short error line should have the correct length");
-		assert!(error_output.contains(&error), "Output should contain the original short error message");
-
-		let error = "X".repeat(98);
-		let error_output = Highscore::render_error(error.clone());
-		let line = error_output.lines().next().unwrap();
+	fn inject_practice_score_into_rows_test() {
+		let mut rows = Highscore::empty_rows();
+		let long_name = "X".repeat(MAX_NAME_LENGTH);
+
+		Highscore::inject_score_into_rows(
+			&mut rows,
+			&Highscores {
+				scores: vec![
+					crate::common::Highscore::new("Dom", 100, Level::Six).with_practice(true),
+					crate::common::Highscore::new(&long_name, 50, Level::Two).with_practice(true),
+					crate::common::Highscore::new(&long_name, 20, Level::Two),
+				],
+			},
+		);
+
+		assert!(rows[0].text().contains(&format!("Dom{PRACTICE_TAG}")), "Practice scores should be marked");
+		assert!(rows[1].text().contains(PRACTICE_TAG), "Long names should make room for the mark");
 		assert_eq!(
-			strip_ansi_border(line).len(),
-			BOARD_WIDTH * 2,
-			"Exact-length error line should have the correct length"
+			rows[1].text().chars().count(),
+			rows[2].text().chars().count(),
+			"The mark should not make the row wider"
 		);
-		assert!(error_output.contains(&error), "Output should contain the 98-character error message without truncation");
+	}
 
-		let mut error = "X".repeat(98);
-		error.push_str("OOOOOOO");
-		let error_output = Highscore::render_error(error.clone());
-		let line = error_output.lines().next().unwrap();
-		assert_eq!(strip_ansi_border(line).len(), BOARD_WIDTH * 2, "Truncated error line should have the correct length");
-		assert!(error_output.contains(&error[0..98].to_string()), "Output should contain the truncated error message");
-		assert!(!error_output.contains("OOOOOOO"), "Output should not contain the truncated portion of the error");
+	#[test]
+	fn inject_more_scores_than_rows_test() {
+		let mut rows = Highscore::empty_rows();
+		let scores = (0..MAX_SCORES + 5).map(|_| crate::common::Highscore::new("Dom", 100, Level::One)).collect();
 
-		let output = Highscore::render_error(String::from(""));
-		assert!(
-			output.contains(ANSI_LEFT_BORDER) && output.contains(ANSI_RIGHT_BORDER),
-			"Empty error should still have proper formatting"
-		);
+		Highscore::inject_score_into_rows(&mut rows, &Highscores { scores });
+
+		assert_eq!(rows.len(), MAX_SCORES, "Scores beyond the table should not add rows");
+		assert!(rows[MAX_SCORES - 1].text().contains("Dom"), "The last row should still be filled");
+	}
+
+	#[test]
+	fn render_error_test() {
+		let highscore = Highscore::new_idle(&Config::default());
+		let error_line = |error: &str| highscore.render_loading_screen(Some(error)).lines[LOADING_POSITION + 1].text();
+
+		assert_eq!(error_line("Short error message"), "Short error message", "Short errors should be shown");
+		assert_eq!(error_line(&"X".repeat(98)), "X".repeat(98), "Errors that fit should not be truncated");
+
+		let mut error = "é".repeat(98);
+		error.push_str("OOOOOOO");
+		assert_eq!(error_line(&error), "é".repeat(98), "Long errors should be truncated to the width of the board");
 	}
 }
@@ -0,0 +1,380 @@
+//! this module decodes the raw bytes we read from stdin into key events
+
+use std::{
+	sync::mpsc::{Receiver, RecvTimeoutError},
+	thread,
+	time::Duration,
+};
+
+use crate::keys::Key;
+
+/// how long we wait for the rest of an escape sequence before we treat ESC as a key press on its own
+const ESCAPE_TIMEOUT: Duration = Duration::from_millis(30);
+/// how long we wait for the next byte while a paste is in progress
+const PASTE_TIMEOUT: Duration = Duration::from_millis(500);
+/// the sequence a terminal sends once a bracketed paste ends
+const PASTE_END: &[u8] = b"\x1b[201~";
+
+/// the modifier keys held down while a key was pressed, shift is part of the key itself like with uppercase letters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Modifiers {
+	/// the alt or option key
+	pub alt: bool,
+	/// the control key
+	pub ctrl: bool,
+}
+
+impl Modifiers {
+	/// no modifier held down
+	pub const NONE: Self = Self {
+		alt: false,
+		ctrl: false,
+	};
+
+	/// decode the modifier parameter of xterm style sequences like the `5` in `ESC [ 1 ; 5 A`
+	fn from_param(param: u16) -> Self {
+		let bits = param.saturating_sub(1);
+		Self {
+			alt: bits & 2 != 0,
+			ctrl: bits & 4 != 0,
+		}
+	}
+
+	/// if neither alt nor ctrl is held down
+	pub fn is_plain(&self) -> bool {
+		!self.alt && !self.ctrl
+	}
+}
+
+/// an event decoded from the terminal input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyEvent {
+	/// a key was pressed
+	Key {
+		/// the key pressed
+		key: Key,
+		/// the modifiers held down
+		modifiers: Modifiers,
+	},
+	/// text was pasted while bracketed paste was enabled
+	Paste(String),
+}
+
+impl KeyEvent {
+	fn key(key: Key) -> Self {
+		Self::Key {
+			key,
+			modifiers: Modifiers::NONE,
+		}
+	}
+
+	fn with_modifiers(key: Key, modifiers: Modifiers) -> Self {
+		Self::Key { key, modifiers }
+	}
+}
+
+/// wait for the next key event until the timeout passed, returns right away once input arrives
+pub fn read_event(input: &Receiver<u8>, timeout: Duration) -> Option<KeyEvent> {
+	match input.recv_timeout(timeout) {
+		Ok(byte) => decode(byte, input),
+		Err(RecvTimeoutError::Timeout) => None,
+		Err(RecvTimeoutError::Disconnected) => {
+			// a finished script or replay leaves the game waiting like an untouched keyboard
+			thread::sleep(timeout);
+			None
+		},
+	}
+}
+
+fn next_byte(input: &Receiver<u8>, timeout: Duration) -> Option<u8> {
+	input.recv_timeout(timeout).ok()
+}
+
+fn decode(byte: u8, input: &Receiver<u8>) -> Option<KeyEvent> {
+	match byte {
+		0x1B => match next_byte(input, ESCAPE_TIMEOUT) {
+			None => Some(KeyEvent::key(Key::Escape)),
+			Some(b'[') => decode_csi(input),
+			Some(b'O') => decode_ss3(input),
+			// ESC followed by a key is how terminals send alt
+			Some(byte) => match decode(byte, input)? {
+				KeyEvent::Key { key, mut modifiers } => {
+					modifiers.alt = true;
+					Some(KeyEvent::with_modifiers(key, modifiers))
+				},
+				paste => Some(paste),
+			},
+		},
+		b'\r' | b'\n' => Some(KeyEvent::key(Key::Enter)),
+		b'\t' => Some(KeyEvent::key(Key::Tab)),
+		0x7F | 0x08 => Some(KeyEvent::key(Key::Backspace)),
+		0x00 => Some(KeyEvent::with_modifiers(
+			Key::Char(' '),
+			Modifiers {
+				ctrl: true,
+				..Modifiers::NONE
+			},
+		)),
+		0x01..=0x1A => Some(KeyEvent::with_modifiers(
+			Key::Char((b'a' + byte - 1) as char),
+			Modifiers {
+				ctrl: true,
+				..Modifiers::NONE
+			},
+		)),
+		0x20..=0x7E => Some(KeyEvent::key(Key::Char(byte as char))),
+		0x1C..=0x1F => None,
+		_ => decode_utf8(byte, input).map(|c| KeyEvent::key(Key::Char(c))),
+	}
+}
+
+fn decode_utf8(first: u8, input: &Receiver<u8>) -> Option<char> {
+	let len = match first {
+		0xC0..=0xDF => 2,
+		0xE0..=0xEF => 3,
+		0xF0..=0xF7 => 4,
+		_ => return None,
+	};
+
+	let mut bytes = vec![first];
+	for _ in 1..len {
+		bytes.push(next_byte(input, ESCAPE_TIMEOUT)?);
+	}
+	std::str::from_utf8(&bytes).ok()?.chars().next()
+}
+
+fn decode_csi(input: &Receiver<u8>) -> Option<KeyEvent> {
+	let mut params = Vec::new();
+	let final_byte = loop {
+		match next_byte(input, ESCAPE_TIMEOUT)? {
+			byte @ 0x30..=0x3F => params.push(byte),
+			// intermediate bytes don't change the meaning of any key we care about
+			0x20..=0x2F => {},
+			byte @ 0x40..=0x7E => break byte,
+			_ => return None,
+		}
+	};
+
+	let params = String::from_utf8(params).ok()?;
+	let mut numbers = params.split(';').map(|param| param.parse::<u16>().unwrap_or(1));
+	let first = numbers.next().unwrap_or(1);
+	let modifiers = Modifiers::from_param(numbers.next().unwrap_or(1));
+
+	let key = match final_byte {
+		b'A' => Key::Up,
+		b'B' => Key::Down,
+		b'C' => Key::Right,
+		b'D' => Key::Left,
+		b'H' => Key::Home,
+		b'F' => Key::End,
+		b'P' => Key::F(1),
+		b'Q' => Key::F(2),
+		b'R' => Key::F(3),
+		b'S' => Key::F(4),
+		b'Z' => Key::BackTab,
+		b'~' => match first {
+			200 => return read_paste(input).map(KeyEvent::Paste),
+			1 | 7 => Key::Home,
+			2 => Key::Insert,
+			3 => Key::Delete,
+			4 | 8 => Key::End,
+			5 => Key::PageUp,
+			6 => Key::PageDown,
+			11..=15 => Key::F((first - 10) as u8),
+			17..=21 => Key::F((first - 11) as u8),
+			23 | 24 => Key::F((first - 12) as u8),
+			_ => return None,
+		},
+		_ => return None,
+	};
+
+	Some(KeyEvent::with_modifiers(key, modifiers))
+}
+
+fn decode_ss3(input: &Receiver<u8>) -> Option<KeyEvent> {
+	let key = match next_byte(input, ESCAPE_TIMEOUT)? {
+		b'A' => Key::Up,
+		b'B' => Key::Down,
+		b'C' => Key::Right,
+		b'D' => Key::Left,
+		b'H' => Key::Home,
+		b'F' => Key::End,
+		b'P' => Key::F(1),
+		b'Q' => Key::F(2),
+		b'R' => Key::F(3),
+		b'S' => Key::F(4),
+		_ => return None,
+	};
+
+	Some(KeyEvent::key(key))
+}
+
+fn read_paste(input: &Receiver<u8>) -> Option<String> {
+	let mut bytes = Vec::new();
+	while !bytes.ends_with(PASTE_END) {
+		bytes.push(next_byte(input, PASTE_TIMEOUT)?);
+	}
+	bytes.truncate(bytes.len() - PASTE_END.len());
+	Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::sync::mpsc;
+
+	fn decode_all(bytes: &[u8]) -> Vec<KeyEvent> {
+		let (sender, receiver) = mpsc::channel::<u8>();
+		for byte in bytes {
+			sender.send(*byte).unwrap();
+		}
+
+		let mut events = Vec::new();
+		while let Ok(byte) = receiver.try_recv() {
+			events.extend(decode(byte, &receiver));
+		}
+		events
+	}
+
+	fn key(key: Key) -> KeyEvent {
+		KeyEvent::key(key)
+	}
+
+	#[test]
+	fn read_event_test() {
+		let (sender, receiver) = mpsc::channel::<u8>();
+		let timeout = Duration::from_millis(20);
+
+		let start = std::time::Instant::now();
+		assert_eq!(read_event(&receiver, timeout), None, "Nothing should be read without input");
+		assert!(start.elapsed() >= timeout, "We should block until the timeout passed");
+
+		sender.send(b'q').unwrap();
+		assert_eq!(
+			read_event(&receiver, Duration::from_secs(60)),
+			Some(key(Key::Char('q'))),
+			"Waiting input should be read right away"
+		);
+	}
+
+	#[test]
+	fn plain_keys_test() {
+		assert_eq!(
+			decode_all(b"a \n\t\x7f"),
+			vec![
+				key(Key::Char('a')),
+				key(Key::Char(' ')),
+				key(Key::Enter),
+				key(Key::Tab),
+				key(Key::Backspace)
+			],
+			"Plain bytes should decode into keys"
+		);
+	}
+
+	#[test]
+	fn lone_escape_test() {
+		assert_eq!(decode_all(b"\x1b"), vec![key(Key::Escape)], "A lone ESC should time out into the escape key");
+	}
+
+	#[test]
+	fn csi_arrows_test() {
+		assert_eq!(
+			decode_all(b"\x1b[A\x1b[B\x1b[C\x1b[D"),
+			vec![key(Key::Up), key(Key::Down), key(Key::Right), key(Key::Left)],
+			"CSI arrow sequences should decode into arrow keys"
+		);
+	}
+
+	#[test]
+	fn ss3_arrows_test() {
+		assert_eq!(
+			decode_all(b"\x1bOA\x1bOB\x1bOC\x1bOD\x1bOP"),
+			vec![
+				key(Key::Up),
+				key(Key::Down),
+				key(Key::Right),
+				key(Key::Left),
+				key(Key::F(1))
+			],
+			"SS3 sequences from application cursor mode should decode"
+		);
+	}
+
+	#[test]
+	fn modifiers_test() {
+		assert_eq!(
+			decode_all(b"\x1b[1;5A\x1b[1;2D\x1b[Z"),
+			vec![
+				KeyEvent::with_modifiers(
+					Key::Up,
+					Modifiers {
+						ctrl: true,
+						..Modifiers::NONE
+					}
+				),
+				key(Key::Left),
+				key(Key::BackTab),
+			],
+			"Modifier parameters should be decoded"
+		);
+		assert_eq!(
+			decode_all(b"\x1bx\x13"),
+			vec![
+				KeyEvent::with_modifiers(
+					Key::Char('x'),
+					Modifiers {
+						alt: true,
+						..Modifiers::NONE
+					}
+				),
+				KeyEvent::with_modifiers(
+					Key::Char('s'),
+					Modifiers {
+						ctrl: true,
+						..Modifiers::NONE
+					}
+				),
+			],
+			"Alt and control characters should be decoded"
+		);
+	}
+
+	#[test]
+	fn function_keys_test() {
+		assert_eq!(
+			decode_all(b"\x1b[15~\x1b[24~\x1b[3~\x1b[5~\x1b[H"),
+			vec![
+				key(Key::F(5)),
+				key(Key::F(12)),
+				key(Key::Delete),
+				key(Key::PageUp),
+				key(Key::Home)
+			],
+			"Function and navigation keys should be decoded"
+		);
+	}
+
+	#[test]
+	fn bracketed_paste_test() {
+		assert_eq!(
+			decode_all(b"\x1b[200~Dom \x1b[A\x1b[201~q"),
+			vec![KeyEvent::Paste(String::from("Dom \x1b[A")), key(Key::Char('q'))],
+			"A bracketed paste should be a single event including escape sequences"
+		);
+	}
+
+	#[test]
+	fn utf8_test() {
+		assert_eq!(decode_all("ö".as_bytes()), vec![key(Key::Char('ö'))], "Multi-byte characters should be decoded");
+	}
+
+	#[test]
+	fn unknown_sequence_test() {
+		assert_eq!(
+			decode_all(b"\x1b[99~a"),
+			vec![key(Key::Char('a'))],
+			"Unknown sequences should be dropped without swallowing the next key"
+		);
+	}
+}
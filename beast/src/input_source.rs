@@ -0,0 +1,217 @@
+//! this module contains the sources the game reads its input from like the keyboard, a script, a replay file or a
+//! socket
+
+use serde::{Deserialize, Serialize};
+use std::{
+	fs::{self, File},
+	io::{self, LineWriter, Read, Write},
+	net::TcpListener,
+	path::Path,
+	sync::mpsc::{self, Receiver, Sender},
+	thread,
+	time::{Duration, Instant},
+};
+
+use crate::config::Config;
+
+/// something the game reads the raw bytes of its input from
+pub trait InputSource: Send {
+	/// send every byte into the game until the source runs dry or the game stopped listening
+	fn run(self: Box<Self>, sender: Sender<u8>);
+}
+
+/// start reading from a source in the background, the game decodes the bytes it receives into key events
+pub fn listen(source: Box<dyn InputSource>) -> Receiver<u8> {
+	let (sender, receiver) = mpsc::channel::<u8>();
+	thread::spawn(move || source.run(sender));
+	receiver
+}
+
+/// the source picked with the cli flags, the keyboard unless we replay a file or listen on a socket
+pub fn from_config(config: &Config) -> io::Result<Box<dyn InputSource>> {
+	if let Some(address) = &config.listen {
+		Ok(Box::new(SocketSource::bind(address)?))
+	} else if let Some(path) = &config.replay {
+		Ok(Box::new(ReplaySource::load(path)?))
+	} else {
+		Ok(Box::new(StdinSource::new(config.record.as_deref())?))
+	}
+}
+
+/// bytes sent at a point in time, a replay file has one of these per line written as RON
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Step {
+	/// the milliseconds since the input started
+	pub at: u64,
+	/// the raw bytes as a terminal would send them
+	pub bytes: Vec<u8>,
+}
+
+impl Step {
+	/// create a step
+	pub fn new(at: u64, bytes: &[u8]) -> Self {
+		Self {
+			at,
+			bytes: bytes.to_vec(),
+		}
+	}
+}
+
+/// the keyboard, optionally recording everything typed into a replay file
+pub struct StdinSource {
+	record: Option<LineWriter<File>>,
+}
+
+impl StdinSource {
+	/// read from stdin and record into the replay file if a path is given
+	pub fn new(record: Option<&Path>) -> io::Result<Self> {
+		Ok(Self {
+			record: record.map(File::create).transpose()?.map(LineWriter::new),
+		})
+	}
+}
+
+impl InputSource for StdinSource {
+	fn run(mut self: Box<Self>, sender: Sender<u8>) {
+		let start = Instant::now();
+		let mut lock = io::stdin().lock();
+		let mut buffer = [0u8; 1];
+		while lock.read_exact(&mut buffer).is_ok() {
+			if let Some(record) = &mut self.record
+				&& let Ok(line) = ron::to_string(&Step::new(start.elapsed().as_millis() as u64, &buffer))
+			{
+				// a recording that can't be written to shouldn't stop the game
+				let _ = writeln!(record, "{line}");
+			}
+			if sender.send(buffer[0]).is_err() {
+				break;
+			}
+		}
+	}
+}
+
+/// a sequence of bytes sent at set times for tests and demos
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ScriptedSource {
+	steps: Vec<Step>,
+}
+
+impl ScriptedSource {
+	/// create a script from steps ordered by time
+	pub fn new(steps: Vec<Step>) -> Self {
+		Self { steps }
+	}
+}
+
+impl InputSource for ScriptedSource {
+	fn run(self: Box<Self>, sender: Sender<u8>) {
+		let start = Instant::now();
+		for step in self.steps {
+			thread::sleep(Duration::from_millis(step.at).saturating_sub(start.elapsed()));
+			for byte in step.bytes {
+				if sender.send(byte).is_err() {
+					return;
+				}
+			}
+		}
+	}
+}
+
+/// the input recorded into a replay file played back with its original timing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplaySource {
+	script: ScriptedSource,
+}
+
+impl ReplaySource {
+	/// read a replay file
+	pub fn load(path: &Path) -> io::Result<Self> {
+		Self::ron_from_str(&fs::read_to_string(path)?)
+	}
+
+	/// parse the content of a replay file
+	pub fn ron_from_str(content: &str) -> io::Result<Self> {
+		let steps = content
+			.lines()
+			.filter(|line| !line.trim().is_empty())
+			.map(|line| ron::from_str::<Step>(line).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)))
+			.collect::<io::Result<Vec<Step>>>()?;
+
+		Ok(Self {
+			script: ScriptedSource::new(steps),
+		})
+	}
+}
+
+impl InputSource for ReplaySource {
+	fn run(self: Box<Self>, sender: Sender<u8>) {
+		Box::new(self.script).run(sender);
+	}
+}
+
+/// remote control through a TCP socket, one connection after the other
+pub struct SocketSource {
+	listener: TcpListener,
+}
+
+impl SocketSource {
+	/// listen on an address like `127.0.0.1:6667`
+	pub fn bind(address: &str) -> io::Result<Self> {
+		Ok(Self {
+			listener: TcpListener::bind(address)?,
+		})
+	}
+}
+
+impl InputSource for SocketSource {
+	fn run(self: Box<Self>, sender: Sender<u8>) {
+		let mut buffer = [0u8; 64];
+		for mut stream in self.listener.incoming().flatten() {
+			while let Ok(read @ 1..) = stream.read(&mut buffer) {
+				for byte in &buffer[..read] {
+					if sender.send(*byte).is_err() {
+						return;
+					}
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use std::net::TcpStream;
+
+	#[test]
+	fn scripted_source_test() {
+		let receiver = listen(Box::new(ScriptedSource::new(vec![Step::new(0, b"ab"), Step::new(30, b"c")])));
+
+		assert_eq!(receiver.recv_timeout(Duration::from_secs(1)), Ok(b'a'), "The first step should be sent right away");
+		assert_eq!(receiver.recv_timeout(Duration::from_secs(1)), Ok(b'b'), "All bytes of a step should be sent");
+		assert_eq!(receiver.try_recv().ok(), None, "Later steps should wait for their time");
+		assert_eq!(receiver.recv_timeout(Duration::from_secs(1)), Ok(b'c'), "Later steps should be sent eventually");
+	}
+
+	#[test]
+	fn replay_source_test() {
+		let replay = ReplaySource::ron_from_str("(at: 0, bytes: [32])\n\n(at: 15, bytes: [27, 91, 65])\n")
+			.expect("Failed to parse replay");
+		assert_eq!(
+			replay.script,
+			ScriptedSource::new(vec![Step::new(0, b" "), Step::new(15, b"\x1b[A")]),
+			"Every line should be a step"
+		);
+		assert!(ReplaySource::ron_from_str("(at: soon)").is_err(), "Invalid replays should error");
+	}
+
+	#[test]
+	fn socket_source_test() {
+		let source = SocketSource::bind("127.0.0.1:0").expect("Failed to bind socket");
+		let address = source.listener.local_addr().expect("Failed to read address");
+		let receiver = listen(Box::new(source));
+
+		TcpStream::connect(address).and_then(|mut stream| stream.write_all(b"q")).expect("Failed to send input");
+		assert_eq!(receiver.recv_timeout(Duration::from_secs(1)), Ok(b'q'), "Bytes from the socket should be received");
+	}
+}
@@ -0,0 +1,300 @@
+//! this module maps the keys a player presses to the actions they trigger
+
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+
+use crate::renderer::Span;
+
+/// a key we can bind to an action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Key {
+	/// a printable character, letters are matched case-insensitively
+	Char(char),
+	/// the up arrow key
+	Up,
+	/// the down arrow key
+	Down,
+	/// the left arrow key
+	Left,
+	/// the right arrow key
+	Right,
+	/// the enter key
+	Enter,
+	/// the tab key
+	Tab,
+	/// the tab key while holding shift
+	BackTab,
+	/// the escape key
+	Escape,
+	/// the backspace key
+	Backspace,
+	/// the delete key
+	Delete,
+	/// the insert key
+	Insert,
+	/// the home key
+	Home,
+	/// the end key
+	End,
+	/// the page up key
+	PageUp,
+	/// the page down key
+	PageDown,
+	/// the function keys F1 to F12
+	F(u8),
+}
+
+impl Key {
+	fn normalize(self) -> Self {
+		match self {
+			Key::Char(c) => Key::Char(c.to_ascii_lowercase()),
+			key => key,
+		}
+	}
+
+	fn is_arrow(&self) -> bool {
+		matches!(self, Key::Up | Key::Down | Key::Left | Key::Right)
+	}
+}
+
+impl fmt::Display for Key {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Key::Char(' ') => write!(f, "SPACE"),
+			Key::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+			Key::Up => write!(f, "↑"),
+			Key::Down => write!(f, "↓"),
+			Key::Left => write!(f, "←"),
+			Key::Right => write!(f, "→"),
+			Key::Enter => write!(f, "ENTER"),
+			Key::Tab => write!(f, "TAB"),
+			Key::BackTab => write!(f, "SHIFT+TAB"),
+			Key::Escape => write!(f, "ESC"),
+			Key::Backspace => write!(f, "BACKSPACE"),
+			Key::Delete => write!(f, "DEL"),
+			Key::Insert => write!(f, "INS"),
+			Key::Home => write!(f, "HOME"),
+			Key::End => write!(f, "END"),
+			Key::PageUp => write!(f, "PGUP"),
+			Key::PageDown => write!(f, "PGDN"),
+			Key::F(number) => write!(f, "F{number}"),
+		}
+	}
+}
+
+/// everything a player can do with a key press
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action {
+	/// move up, scroll up
+	Up,
+	/// move down, scroll down
+	Down,
+	/// move left, go to the previous page or tab
+	Left,
+	/// move right, go to the next page or tab
+	Right,
+	/// open the help
+	Help,
+	/// open the highscores
+	Highscore,
+	/// quit the game
+	Quit,
+	/// pause and resume the game
+	Pause,
+	/// start a new game
+	Restart,
+	/// show and hide the training overlay
+	Training,
+}
+
+/// the built-in sets of key bindings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum KeyPreset {
+	/// the arrow keys with letters for everything else
+	#[default]
+	Default,
+	/// WASD for movement
+	Wasd,
+	/// vim style hjkl for movement
+	Hjkl,
+}
+
+impl KeyPreset {
+	/// parse a preset from its name as used on the cli
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name.to_ascii_lowercase().as_str() {
+			"default" | "arrows" => Some(Self::Default),
+			"wasd" => Some(Self::Wasd),
+			"hjkl" | "vim" => Some(Self::Hjkl),
+			_ => None,
+		}
+	}
+}
+
+/// the map from actions to the keys that trigger them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings {
+	bindings: HashMap<Action, Vec<Key>>,
+}
+
+impl KeyBindings {
+	/// create the bindings of a preset, the arrow keys always move the player
+	pub fn from_preset(preset: KeyPreset) -> Self {
+		let (up, left, down, right, help, highscore) = match preset {
+			KeyPreset::Default => (None, None, None, None, 'h', 's'),
+			KeyPreset::Wasd => (Some('w'), Some('a'), Some('s'), Some('d'), 'h', 'i'),
+			KeyPreset::Hjkl => (Some('k'), Some('h'), Some('j'), Some('l'), '?', 's'),
+		};
+		let movement = |key: Option<char>, arrow: Key| key.map(Key::Char).into_iter().chain([arrow]).collect::<Vec<Key>>();
+
+		Self {
+			bindings: HashMap::from([
+				(Action::Up, movement(up, Key::Up)),
+				(Action::Down, movement(down, Key::Down)),
+				(Action::Left, movement(left, Key::Left)),
+				(Action::Right, movement(right, Key::Right)),
+				(Action::Help, vec![Key::Char(help)]),
+				(Action::Highscore, vec![Key::Char(highscore)]),
+				(Action::Quit, vec![Key::Char('q')]),
+				(Action::Pause, vec![Key::Char('p')]),
+				(Action::Restart, vec![Key::Char('r')]),
+				(Action::Training, vec![Key::Char('t')]),
+			]),
+		}
+	}
+
+	/// replace the keys of the given actions, a key can only ever trigger one action
+	pub fn with_overrides(mut self, overrides: &HashMap<Action, Vec<Key>>) -> Self {
+		for (action, keys) in overrides {
+			let keys = keys.iter().map(|key| key.normalize()).collect::<Vec<Key>>();
+			for bound in self.bindings.values_mut() {
+				bound.retain(|key| !keys.contains(key));
+			}
+			self.bindings.insert(*action, keys);
+		}
+		self
+	}
+
+	/// find the action a key triggers
+	pub fn action(&self, key: Key) -> Option<Action> {
+		let key = key.normalize();
+		self.bindings.iter().find(|(_, keys)| keys.contains(&key)).map(|(action, _)| *action)
+	}
+
+	/// all keys bound to an action
+	pub fn keys(&self, action: Action) -> &[Key] {
+		self.bindings.get(&action).map(Vec::as_slice).unwrap_or_default()
+	}
+
+	/// the keys of an action in bold like `[H]` or `[K/↑]`
+	pub fn label(&self, action: Action) -> Span {
+		let keys = self.keys(action).iter().map(Key::to_string).collect::<Vec<String>>().join("/");
+		Span::bold(format!("[{}]", if keys.is_empty() { String::from("-") } else { keys }))
+	}
+
+	/// describe how the player moves like `[W] [A] [S] [D] or the arrow keys`
+	pub fn movement_label(&self) -> Vec<Span> {
+		let letters = [Action::Up, Action::Left, Action::Down, Action::Right]
+			.iter()
+			.filter_map(|action| self.keys(*action).iter().find(|key| !key.is_arrow()))
+			.map(|key| Span::bold(format!("[{key}]")))
+			.collect::<Vec<Span>>();
+		let has_arrows = [Action::Up, Action::Left, Action::Down, Action::Right]
+			.iter()
+			.all(|action| self.keys(*action).iter().any(Key::is_arrow));
+
+		if letters.len() != 4 {
+			return vec![Span::plain("the arrow keys")];
+		}
+		let mut spans = Vec::new();
+		for (index, letter) in letters.into_iter().enumerate() {
+			if index > 0 {
+				spans.push(Span::plain(" "));
+			}
+			spans.push(letter);
+		}
+		if has_arrows {
+			spans.push(Span::plain(" or the arrow keys"));
+		}
+		spans
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::renderer::Line;
+
+	#[test]
+	fn default_preset_test() {
+		let bindings = KeyBindings::from_preset(KeyPreset::Default);
+		assert_eq!(bindings.action(Key::Up), Some(Action::Up), "The up arrow should move up");
+		assert_eq!(bindings.action(Key::Char('h')), Some(Action::Help), "H should open the help");
+		assert_eq!(bindings.action(Key::Char('S')), Some(Action::Highscore), "Keys should match case-insensitively");
+		assert_eq!(bindings.action(Key::Char('p')), Some(Action::Pause), "P should pause");
+		assert_eq!(bindings.action(Key::Char('r')), Some(Action::Restart), "R should restart");
+		assert_eq!(bindings.action(Key::Char('t')), Some(Action::Training), "T should toggle the training overlay");
+		assert_eq!(bindings.action(Key::Char('w')), None, "W should not be bound");
+	}
+
+	#[test]
+	fn wasd_preset_test() {
+		let bindings = KeyBindings::from_preset(KeyPreset::Wasd);
+		assert_eq!(bindings.action(Key::Char('w')), Some(Action::Up), "W should move up");
+		assert_eq!(bindings.action(Key::Char('a')), Some(Action::Left), "A should move left");
+		assert_eq!(bindings.action(Key::Char('s')), Some(Action::Down), "S should move down");
+		assert_eq!(bindings.action(Key::Char('d')), Some(Action::Right), "D should move right");
+		assert_eq!(bindings.action(Key::Left), Some(Action::Left), "The arrow keys should still move");
+		assert_eq!(bindings.action(Key::Char('i')), Some(Action::Highscore), "I should open the highscores");
+	}
+
+	#[test]
+	fn hjkl_preset_test() {
+		let bindings = KeyBindings::from_preset(KeyPreset::Hjkl);
+		assert_eq!(bindings.action(Key::Char('h')), Some(Action::Left), "H should move left");
+		assert_eq!(bindings.action(Key::Char('j')), Some(Action::Down), "J should move down");
+		assert_eq!(bindings.action(Key::Char('k')), Some(Action::Up), "K should move up");
+		assert_eq!(bindings.action(Key::Char('l')), Some(Action::Right), "L should move right");
+		assert_eq!(bindings.action(Key::Char('?')), Some(Action::Help), "? should open the help");
+	}
+
+	#[test]
+	fn presets_have_no_conflicts_test() {
+		for preset in [KeyPreset::Default, KeyPreset::Wasd, KeyPreset::Hjkl] {
+			let bindings = KeyBindings::from_preset(preset);
+			let mut keys = bindings.bindings.values().flatten().collect::<Vec<&Key>>();
+			let count = keys.len();
+			keys.sort_by_key(|key| key.to_string());
+			keys.dedup();
+			assert_eq!(keys.len(), count, "The {preset:?} preset should bind every key only once");
+		}
+	}
+
+	#[test]
+	fn with_overrides_test() {
+		let bindings = KeyBindings::from_preset(KeyPreset::Default)
+			.with_overrides(&HashMap::from([(Action::Pause, vec![Key::Char('H')])]));
+
+		assert_eq!(bindings.action(Key::Char('h')), Some(Action::Pause), "The override should take the key");
+		assert_eq!(bindings.action(Key::Char('p')), None, "The old pause key should be unbound");
+		assert!(bindings.keys(Action::Help).is_empty(), "The key should be removed from its previous action");
+		assert_eq!(bindings.label(Action::Help), Span::bold("[-]"), "Unbound actions should render a dash");
+	}
+
+	#[test]
+	fn label_test() {
+		let bindings = KeyBindings::from_preset(KeyPreset::Hjkl);
+		assert_eq!(bindings.label(Action::Help), Span::bold("[?]"), "The help label should show the key");
+		assert_eq!(bindings.label(Action::Up), Span::bold("[K/↑]"), "All keys of an action should be shown");
+		assert_eq!(
+			Line::new(bindings.movement_label()).text(),
+			"[K] [H] [J] [L] or the arrow keys",
+			"The movement should list the letters and arrow keys"
+		);
+		assert_eq!(
+			Line::new(KeyBindings::from_preset(KeyPreset::Default).movement_label()).text(),
+			"the arrow keys",
+			"The default preset should only mention the arrow keys"
+		);
+	}
+}
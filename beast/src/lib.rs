@@ -7,4 +7,353 @@
 //! > BEAST is a homage to the 1984 ASCII game "[BEAST](https://en.wikipedia.org/wiki/Beast_(video_game))"
 //! > from Dan Baker, Alan Brown, Mark Hamilton and Derrick Shadel.
 
+use dotenv::dotenv;
+use serde::Serialize;
+use std::{env, fmt, time::Instant};
+use unicode_width::UnicodeWidthChar;
+
+mod accessibility;
+mod beasts;
+#[doc(hidden)]
+pub mod bench;
+mod board;
+mod capabilities;
 pub mod common;
+mod config;
+mod escape;
+mod events;
+mod game;
+mod help;
+mod highscore;
+mod input;
+mod input_source;
+mod keys;
+mod local_highscore;
+mod pathing;
+mod pending_highscore;
+mod player;
+mod renderer;
+mod screen;
+mod statistics;
+mod terminal;
+mod theme;
+mod xdg;
+
+/// the board width
+pub const BOARD_WIDTH: usize = 50;
+/// the board height
+pub const BOARD_HEIGHT: usize = 30;
+/// where the player starts from
+pub const PLAYER_START: Coord = Coord {
+	column: 0,
+	row: BOARD_HEIGHT - 1,
+};
+/// ANSI escape sequence for bold
+pub const ANSI_BOLD: &str = "\x1B[1m";
+/// ANSI escape sequence to reset all styles and colors
+pub const ANSI_RESET: &str = "\x1B[0m";
+/// ANSI escape sequence to reset font color
+pub const ANSI_RESET_FONT: &str = "\x1B[39m";
+/// ANSI escape sequence to reset background color
+pub const ANSI_RESET_BG: &str = "\x1B[49m";
+/// left border in the frame color of the theme
+pub const LEFT_BORDER: theme::Border = theme::Border::Left;
+/// right border in the frame color of the theme
+pub const RIGHT_BORDER: theme::Border = theme::Border::Right;
+/// the width of a string as displayed in the terminal ignoring ANSI escape sequences, wide characters take two columns
+pub fn visible_width(s: &str) -> usize {
+	escape::chars(s).filter_map(UnicodeWidthChar::width).sum()
+}
+
+/// the logo without the frame
+pub const LOGO: [&str; 10] = [
+	"                                                                                                    ",
+	"                                                                                                    ",
+	"                               HHHH    HHHHH    HHH     HHHH   HHHHH                                ",
+	"                               H   H   H       H   H   H         H                                  ",
+	"                               H   H   H       H   H   H         H                                  ",
+	"                               HHHH    HHHH    HHHHH    HHH      H                                  ",
+	"                               H   H   H       H   H       H     H                                  ",
+	"                               H   H   H       H   H       H     H                                  ",
+	"                               HHHH    HHHHH   H   H   HHHH      H                                  ",
+	"                                                                                                    ",
+];
+
+/// runs the game, this is all the `beast` binary does
+pub fn run() {
+	let cli_flags = env::args().skip(1).collect::<Vec<String>>();
+	if cli_flags.contains(&String::from("--version"))
+		|| cli_flags.contains(&String::from("-v"))
+		|| cli_flags.contains(&String::from("-V"))
+	{
+		println!("v{}", env!("CARGO_PKG_VERSION"));
+		std::process::exit(0);
+	}
+
+	if std::env::var_os("CI").is_none() {
+		match terminal::terminal_size() {
+			Ok((columns, rows)) => {
+				let min_width = game::MIN_TERMINAL_WIDTH;
+				let min_height = game::MIN_TERMINAL_HEIGHT;
+				if columns < min_width || rows < min_height {
+					let width_color = if columns < min_width {
+						"\x1B[31m"
+					} else {
+						ANSI_RESET_FONT
+					};
+					let height_color = if rows < min_height { "\x1B[31m" } else { ANSI_RESET_FONT };
+					eprintln!(
+						"\x1B[31mERROR:{ANSI_RESET_FONT} Terminal size is too small.\nThe size is {width_color}{columns}{ANSI_RESET_FONT} x {height_color}{rows}{ANSI_RESET_FONT} but needs to be at least {min_width} x {min_height}."
+					);
+					std::process::exit(0);
+				}
+			},
+			Err(error) => {
+				eprintln!(
+					"\x1B[31mERROR:{ANSI_RESET_FONT} This game requires a POSIX compatible terminal. Failed to detect terminal size: {error}"
+				);
+				std::process::exit(0);
+			},
+		}
+	}
+
+	dotenv().ok();
+	let config = match config::Config::load().and_then(|mut config| {
+		config.apply_cli_flags(&cli_flags)?;
+		Ok(config)
+	}) {
+		Ok(config) => config,
+		Err(error) => {
+			eprintln!("\x1B[31mERROR:{ANSI_RESET_FONT} {error}");
+			if matches!(error, config::ConfigError::Flag(_) | config::ConfigError::UnknownFlag(_)) {
+				eprintln!("\n{}", config::USAGE);
+			}
+			std::process::exit(1);
+		},
+	};
+
+	if let Err(error) = theme::init(&config.theme) {
+		eprintln!("\x1B[31mERROR:{ANSI_RESET_FONT} {error}");
+		std::process::exit(1);
+	}
+
+	if !config.offline {
+		pending_highscore::PendingHighscores::new().sync_in_background(config.highscore_endpoint());
+	}
+	let mut game = crate::game::Game::new(config);
+	game.play();
+}
+
+/// a data structure to place items on a board
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct Coord {
+	column: usize,
+	row: usize,
+}
+
+impl Coord {
+	/// the column of the coordinate
+	pub fn column(&self) -> usize {
+		self.column
+	}
+
+	/// the row of the coordinate
+	pub fn row(&self) -> usize {
+		self.row
+	}
+}
+
+/// the items that can be found on the baord
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+	/// empty space
+	Empty,
+	/// a block `░░`
+	Block,
+	/// a immovable block `▓▓`
+	StaticBlock,
+	/// the player `◀▶`
+	Player,
+	/// a common beast `├┤`
+	CommonBeast,
+	/// a super beast `╟╢`
+	SuperBeast,
+	/// an egg `○○`
+	Egg(Instant),
+	/// an egg hatching `○○` (in a different color)
+	EggHatching(Instant),
+	/// a hatched beast `╬╬`
+	HatchedBeast,
+}
+
+impl Tile {
+	/// get the raw symbol of the tile to be displayed in the terminal
+	pub fn raw_symbol(&self) -> &'static str {
+		match self {
+			Tile::Empty => "  ",
+			Tile::Block => "░░",
+			Tile::StaticBlock => "▓▓",
+			Tile::Player => "◀▶",
+			Tile::CommonBeast => "├┤",
+			Tile::SuperBeast => "╟╢",
+			Tile::Egg(_) => "○○",
+			Tile::EggHatching(_) => "○○",
+			Tile::HatchedBeast => "╬╬",
+		}
+	}
+}
+
+impl fmt::Display for Tile {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", theme::current().tile(self))
+	}
+}
+
+/// the allowed directions an entity can move
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Dir {
+	/// moving up
+	Up,
+	/// moving right
+	Right,
+	/// moving down
+	Down,
+	/// moving left
+	Left,
+}
+
+#[cfg(test)]
+mod test_common {
+	use super::*;
+	use std::{fs, path::PathBuf, time::Instant};
+
+	/// a file in the temp directory that is removed when dropped, the name is prefixed with the process id so parallel
+	/// test runs don't share files
+	pub struct TempFile {
+		pub path: PathBuf,
+	}
+
+	impl TempFile {
+		pub fn new(name: &str) -> Self {
+			let path = env::temp_dir().join(format!("beast_{}_{name}", std::process::id()));
+			let _ = fs::remove_file(&path);
+			Self { path }
+		}
+	}
+
+	impl Drop for TempFile {
+		fn drop(&mut self) {
+			let _ = fs::remove_file(&self.path);
+		}
+	}
+
+	pub fn strip_ansi_border(s: &str) -> String {
+		let tile_chars = [
+			Tile::Empty,
+			Tile::Block,
+			Tile::StaticBlock,
+			Tile::Player,
+			Tile::CommonBeast,
+			Tile::SuperBeast,
+			Tile::Egg(Instant::now()),
+			Tile::EggHatching(Instant::now()),
+			Tile::HatchedBeast,
+		]
+		.iter()
+		.flat_map(|tile| tile.raw_symbol().chars())
+		.collect::<Vec<char>>();
+
+		escape::chars(s)
+			.filter(|c| !matches!(c, '▌' | '▐'))
+			.map(|c| match c {
+				// normalize the ASCII characters we use in the game
+				x if tile_chars.contains(&x) => ' ',
+				'●' | '←' | '→' | '↓' | '↑' | '⌂' | '▛' | '▀' | '▜' | '▙' | '▄' | '▟' | '┌' | '─' | '┐' | '└' | '┘' | '│' => {
+					' '
+				},
+				// the rest is normal string stuff
+				_ => c,
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::test_common::strip_ansi_border;
+
+	#[test]
+	fn visible_width_test() {
+		assert_eq!(visible_width("beast"), 5, "Plain text should count every character");
+		assert_eq!(visible_width(&format!("{ANSI_BOLD}[↑]{ANSI_RESET}")), 3, "ANSI sequences should not count");
+		assert_eq!(visible_width(&Tile::Player.to_string()), 2, "Tiles should count as two characters");
+		assert_eq!(visible_width("🐍"), 2, "Wide characters should count as two columns");
+		assert_eq!(visible_width("e\u{301}"), 1, "Combining characters should not take a column");
+	}
+
+	#[test]
+	fn tiles_are_consistent_length_test() {
+		let tiles = [
+			Tile::Empty,
+			Tile::Block,
+			Tile::StaticBlock,
+			Tile::Player,
+			Tile::CommonBeast,
+			Tile::SuperBeast,
+			Tile::Egg(Instant::now()),
+			Tile::EggHatching(Instant::now()),
+			Tile::HatchedBeast,
+		];
+
+		for tile in &tiles {
+			assert_eq!(tile.raw_symbol().chars().count(), 2, "tiles should be consistent length");
+		}
+	}
+
+	#[test]
+	fn strip_ansi_border_16_colors_test() {
+		assert_eq!(
+			strip_ansi_border("\x1b[31m├┤\x1b[39m"),
+			"  ",
+			"strip_ansi_border should strip 16 colors ANSI escape sequences"
+		);
+	}
+
+	#[test]
+	fn strip_ansi_border_256_colors_test() {
+		assert_eq!(
+			strip_ansi_border("\x1b[38;5;82m▓▓\x1b[39m"),
+			"  ",
+			"strip_ansi_border should strip 256 colors ANSI escape sequences"
+		);
+	}
+
+	#[test]
+	fn strip_ansi_border_rgb_test() {
+		assert_eq!(
+			strip_ansi_border("\x1b[38;2;255;200;100m○○\x1b[39m"),
+			"  ",
+			"strip_ansi_border should strip rgb colors ANSI escape sequences"
+		);
+	}
+
+	#[test]
+	fn strip_ansi_border_tile_test() {
+		let tiles = [
+			Tile::Empty,
+			Tile::Block,
+			Tile::StaticBlock,
+			Tile::Player,
+			Tile::CommonBeast,
+			Tile::SuperBeast,
+			Tile::Egg(Instant::now()),
+			Tile::EggHatching(Instant::now()),
+			Tile::HatchedBeast,
+		];
+
+		for tile in &tiles {
+			assert_eq!(&strip_ansi_border(&tile.to_string()), "  ", "strip_ansi_border should normalize the {tile:?} tile");
+		}
+	}
+}
@@ -0,0 +1,132 @@
+//! this module stores the highscores of this machine in a RON file so they are available without a network
+
+use std::{
+	fs, io,
+	path::{Path, PathBuf},
+};
+use time::OffsetDateTime;
+
+use crate::{
+	common::{Highscore, Highscores, levels::Level},
+	xdg,
+};
+
+/// the name of the file inside our data directory
+const FILE_NAME: &str = "highscores.ron";
+
+/// the local highscore table which is read from and written to disk on each access
+#[derive(Debug, Clone)]
+pub struct LocalHighscores {
+	path: Option<PathBuf>,
+}
+
+impl LocalHighscores {
+	/// create a new instance pointing at the file inside the XDG data directory
+	pub fn new() -> Self {
+		Self {
+			path: xdg::data_dir().map(|dir| dir.join(FILE_NAME)),
+		}
+	}
+
+	/// create a new instance pointing at a specific file
+	#[cfg(test)]
+	pub fn with_path(path: impl Into<PathBuf>) -> Self {
+		Self {
+			path: Some(path.into()),
+		}
+	}
+
+	/// load all local highscores, a missing or broken file results in an empty table
+	pub fn load(&self) -> Highscores {
+		self
+			.path
+			.as_ref()
+			.and_then(|path| fs::read_to_string(path).ok())
+			.and_then(|content| Highscores::ron_from_str(&content).ok())
+			.unwrap_or(Highscores { scores: Vec::new() })
+	}
+
+	/// record a finished game, practice games are marked as such, and return the timestamp that identifies the entry
+	pub fn record(&self, name: &str, score: u16, level: Level, practice: bool) -> io::Result<OffsetDateTime> {
+		let path = self.get_path()?;
+		let mut highscores = self.load();
+		let entry = Highscore::new(name, score, level).with_practice(practice);
+		let timestamp = entry.timestamp;
+		highscores.add(entry);
+
+		Self::write(path, &highscores)?;
+		Ok(timestamp)
+	}
+
+	/// rename the entry recorded at the given timestamp
+	pub fn rename(&self, timestamp: OffsetDateTime, name: &str) -> io::Result<()> {
+		let path = self.get_path()?;
+		let mut highscores = self.load();
+		if let Some(entry) = highscores.scores.iter_mut().find(|entry| entry.timestamp == timestamp) {
+			entry.name = name.to_string();
+			Self::write(path, &highscores)?;
+		}
+
+		Ok(())
+	}
+
+	fn get_path(&self) -> io::Result<&Path> {
+		self.path.as_deref().ok_or_else(|| io::Error::other("Could not find a data directory to store highscores in"))
+	}
+
+	fn write(path: &Path, highscores: &Highscores) -> io::Result<()> {
+		if let Some(dir) = path.parent() {
+			fs::create_dir_all(dir)?;
+		}
+
+		let ron_str = ron::to_string(highscores).map_err(io::Error::other)?;
+		let temp_path = path.with_extension("tmp");
+		fs::write(&temp_path, ron_str)?;
+		fs::rename(&temp_path, path)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_common::TempFile;
+
+	#[test]
+	fn load_missing_file_test() {
+		let temp_file = TempFile::new("local_highscore_missing.ron");
+		let local = LocalHighscores::with_path(&temp_file.path);
+		assert!(local.load().scores.is_empty(), "A missing file should result in an empty table");
+	}
+
+	#[test]
+	fn record_test() {
+		let temp_file = TempFile::new("local_highscore_record.ron");
+		let local = LocalHighscores::with_path(&temp_file.path);
+
+		local.record("Dom", 42, Level::Two, false).expect("Recording the first score should work");
+		local.record("Belle", 666, Level::Five, true).expect("Recording the second score should work");
+
+		let highscores = local.load();
+		assert_eq!(highscores.scores.len(), 2, "Both scores should have been recorded");
+		assert_eq!(highscores.scores[0].name, "Belle", "The highest score should be first");
+		assert_eq!(highscores.scores[0].level, Level::Five, "The level should be recorded");
+		assert_eq!(highscores.scores[1].name, "Dom", "The lower score should be second");
+		assert!(highscores.scores[0].practice, "The practice game should be marked");
+		assert!(!highscores.scores[1].practice, "The ranked game should not be marked");
+	}
+
+	#[test]
+	fn rename_test() {
+		let temp_file = TempFile::new("local_highscore_rename.ron");
+		let local = LocalHighscores::with_path(&temp_file.path);
+
+		local.record("Dom", 42, Level::Two, false).expect("Recording the first score should work");
+		let timestamp =
+			local.record("anonymous", 100, Level::Three, false).expect("Recording the second score should work");
+		local.rename(timestamp, "Belle").expect("Renaming should work");
+
+		let highscores = local.load();
+		assert_eq!(highscores.scores[0].name, "Belle", "The entry should have been renamed");
+		assert_eq!(highscores.scores[1].name, "Dom", "Other entries should be untouched");
+	}
+}
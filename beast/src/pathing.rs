@@ -55,6 +55,33 @@ pub fn get_next_coord(coord: &Coord, dir: &Dir) -> Option<Coord> {
 	}
 }
 
+/// this method returns if a beast standing on a tile could be squished by the player with their next move, that is if
+/// the player stands right next to a chain of blocks that ends on the tile with something behind it to squish against
+pub fn is_crush_tile(board: &Board, coord: &Coord, beast: Tile, player_position: &Coord) -> bool {
+	[Dir::Up, Dir::Right, Dir::Down, Dir::Left].iter().any(|dir| {
+		get_next_coord(player_position, dir).is_some_and(|start| {
+			board[&start] == Tile::Block
+				&& get_end_of_block_chain(board, &start, dir).is_some_and(|(end_coord, _)| {
+					let behind = get_next_coord(&end_coord, dir);
+					end_coord == *coord
+						&& match beast {
+							// super beasts can only be squished against static blocks
+							Tile::SuperBeast => behind.is_some_and(|behind| board[&behind] == Tile::StaticBlock),
+							_ => behind.is_none_or(|behind| board[&behind] == Tile::Block || board[&behind] == Tile::StaticBlock),
+						}
+				})
+		})
+	})
+}
+
+/// this method returns if the beast or egg on a tile can be squished by the player with a single push following the
+/// same rules as [Player::advance](crate::player::Player::advance)
+pub fn is_squishable(board: &Board, coord: &Coord, player_position: &Coord) -> bool {
+	let tile = board[coord];
+	matches!(tile, Tile::CommonBeast | Tile::SuperBeast | Tile::HatchedBeast | Tile::Egg(_) | Tile::EggHatching(_))
+		&& is_crush_tile(board, coord, tile, player_position)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -257,4 +284,75 @@ mod tests {
 		let coord = Coord { row: 5, column: 0 };
 		assert_eq!(get_next_coord(&coord, &Dir::Left), None, "The next tile is out of bounds");
 	}
+
+	#[test]
+	fn is_crush_tile_test() {
+		// 0 ◀▶░░░░  ▓▓
+		// 1   ░░
+		// 2
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let player_position = Coord { column: 0, row: 0 };
+		board[&player_position] = Tile::Player;
+		board[&Coord { column: 1, row: 0 }] = Tile::Block;
+		board[&Coord { column: 2, row: 0 }] = Tile::Block;
+		board[&Coord { column: 4, row: 0 }] = Tile::StaticBlock;
+		board[&Coord { column: 0, row: 1 }] = Tile::Block;
+
+		let in_line = Coord { column: 3, row: 0 };
+		assert!(
+			is_crush_tile(&board, &in_line, Tile::CommonBeast, &player_position),
+			"A tile at the end of a block chain in front of a static block is lethal"
+		);
+		assert!(
+			is_crush_tile(&board, &in_line, Tile::SuperBeast, &player_position),
+			"Super beasts can be squished against static blocks"
+		);
+		assert!(
+			!is_crush_tile(&board, &Coord { column: 0, row: 2 }, Tile::CommonBeast, &player_position),
+			"A tile with nothing behind it is safe"
+		);
+		assert!(
+			!is_crush_tile(&board, &Coord { column: 3, row: 1 }, Tile::CommonBeast, &player_position),
+			"Tiles out of line with the player are safe"
+		);
+
+		board[&Coord { column: 0, row: 3 }] = Tile::Block;
+		assert!(
+			is_crush_tile(&board, &Coord { column: 0, row: 2 }, Tile::CommonBeast, &player_position),
+			"Common beasts can be squished against blocks"
+		);
+		assert!(
+			!is_crush_tile(&board, &Coord { column: 0, row: 2 }, Tile::SuperBeast, &player_position),
+			"Super beasts can't be squished against blocks"
+		);
+	}
+
+	#[test]
+	fn is_squishable_test() {
+		// ◀▶
+		// ░░
+		// ├┤
+		// ▓▓
+
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let player_position = Coord { column: 0, row: 0 };
+		let beast = Coord { column: 0, row: 2 };
+		board[&player_position] = Tile::Player;
+		board[&Coord { column: 0, row: 1 }] = Tile::Block;
+		board[&beast] = Tile::CommonBeast;
+
+		assert!(!is_squishable(&board, &beast, &player_position), "Nothing behind the beast to squish it against");
+
+		board[&Coord { column: 0, row: 3 }] = Tile::StaticBlock;
+		assert!(is_squishable(&board, &beast, &player_position), "Common beasts can be squished against static blocks");
+
+		board[&beast] = Tile::SuperBeast;
+		assert!(is_squishable(&board, &beast, &player_position), "Super beasts can be squished against static blocks");
+
+		board[&Coord { column: 0, row: 3 }] = Tile::Block;
+		assert!(!is_squishable(&board, &beast, &player_position), "Super beasts can't be squished against blocks");
+
+		board[&beast] = Tile::Empty;
+		assert!(!is_squishable(&board, &beast, &player_position), "Empty tiles have nothing to squish");
+	}
 }
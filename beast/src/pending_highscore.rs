@@ -0,0 +1,178 @@
+//! this module queues highscore submissions that could not reach the server so they can be retried later
+
+use std::{
+	fs, io,
+	path::{Path, PathBuf},
+	sync::{
+		Mutex,
+		atomic::{AtomicBool, Ordering},
+	},
+	thread,
+};
+
+use crate::{
+	common::Score,
+	highscore::{SubmitError, post_score},
+	xdg,
+};
+
+/// the name of the file inside our data directory
+const FILE_NAME: &str = "pending_highscores.ron";
+
+/// guards the queue file against being written from two threads at once
+static FILE_LOCK: Mutex<()> = Mutex::new(());
+/// makes sure only one sync is talking to the server at a time so we never submit a score twice
+static SYNCING: AtomicBool = AtomicBool::new(false);
+
+/// the queue of scores waiting to be submitted to the global highscore
+#[derive(Debug, Clone)]
+pub struct PendingHighscores {
+	path: Option<PathBuf>,
+}
+
+impl PendingHighscores {
+	/// create a new instance pointing at the file inside the XDG data directory
+	pub fn new() -> Self {
+		Self {
+			path: xdg::data_dir().map(|dir| dir.join(FILE_NAME)),
+		}
+	}
+
+	/// create a new instance pointing at a specific file
+	#[cfg(test)]
+	pub fn with_path(path: impl Into<PathBuf>) -> Self {
+		Self {
+			path: Some(path.into()),
+		}
+	}
+
+	/// load all pending scores, a missing or broken file results in an empty queue
+	pub fn load(&self) -> Vec<Score> {
+		self
+			.path
+			.as_ref()
+			.and_then(|path| fs::read_to_string(path).ok())
+			.and_then(|content| ron::from_str::<Vec<Score>>(&content).ok())
+			.unwrap_or_default()
+	}
+
+	/// how many scores are waiting to be submitted
+	pub fn count(&self) -> usize {
+		self.load().len()
+	}
+
+	/// add a score to the end of the queue
+	pub fn push(&self, score: Score) -> io::Result<()> {
+		let path = self.get_path()?;
+		let _lock = FILE_LOCK.lock().unwrap_or_else(|error| error.into_inner());
+		let mut scores = self.load();
+		scores.push(score);
+		Self::write(path, &scores)
+	}
+
+	/// submit every pending score and drop the ones the server accepted or rejected for good,
+	/// returns how many scores are still pending
+	pub fn sync(&self, url: &str) -> usize {
+		if SYNCING.swap(true, Ordering::SeqCst) {
+			return self.count();
+		}
+
+		// we don't hold the file lock while talking to the server so new scores can be queued meanwhile
+		let done = self
+			.load()
+			.into_iter()
+			.filter(|score| !matches!(post_score(url, score), Err(SubmitError::Unreachable(_))))
+			.collect::<Vec<Score>>();
+
+		let remaining = if done.is_empty() {
+			self.count()
+		} else {
+			let _lock = FILE_LOCK.lock().unwrap_or_else(|error| error.into_inner());
+			let mut scores = self.load();
+			for score in &done {
+				if let Some(index) = scores.iter().position(|pending| pending == score) {
+					scores.remove(index);
+				}
+			}
+			if let Ok(path) = self.get_path() {
+				let _ = Self::write(path, &scores);
+			}
+			scores.len()
+		};
+
+		SYNCING.store(false, Ordering::SeqCst);
+		remaining
+	}
+
+	/// retry all pending scores without blocking the game
+	pub fn sync_in_background(&self, url: String) {
+		let pending = self.clone();
+		thread::spawn(move || {
+			if pending.count() > 0 {
+				pending.sync(&url);
+			}
+		});
+	}
+
+	fn get_path(&self) -> io::Result<&Path> {
+		self.path.as_deref().ok_or_else(|| io::Error::other("Could not find a data directory to store highscores in"))
+	}
+
+	fn write(path: &Path, scores: &[Score]) -> io::Result<()> {
+		if scores.is_empty() {
+			return match fs::remove_file(path) {
+				Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+				_ => Ok(()),
+			};
+		}
+
+		if let Some(dir) = path.parent() {
+			fs::create_dir_all(dir)?;
+		}
+
+		let ron_str = ron::to_string(scores).map_err(io::Error::other)?;
+		let temp_path = path.with_extension("tmp");
+		fs::write(&temp_path, ron_str)?;
+		fs::rename(&temp_path, path)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{common::levels::Level, test_common::TempFile};
+
+	fn score(name: &str, score: u16) -> Score {
+		Score {
+			name: name.to_string(),
+			score,
+			level: Level::Three,
+		}
+	}
+
+	#[test]
+	fn push_test() {
+		let temp_file = TempFile::new("pending_push.ron");
+		let pending = PendingHighscores::with_path(&temp_file.path);
+		assert_eq!(pending.count(), 0, "A missing file should result in an empty queue");
+
+		pending.push(score("Dom", 42)).expect("Queueing the first score should work");
+		pending.push(score("Belle", 666)).expect("Queueing the second score should work");
+
+		assert_eq!(pending.load(), vec![score("Dom", 42), score("Belle", 666)], "The scores should be queued in order");
+	}
+
+	#[test]
+	fn sync_unreachable_test() {
+		let temp_file = TempFile::new("pending_sync_unreachable.ron");
+		let pending = PendingHighscores::with_path(&temp_file.path);
+		pending.push(score("Dom", 42)).expect("Queueing a score should work");
+
+		assert_eq!(
+			pending.sync("http://127.0.0.1:0/highscore"),
+			1,
+			"Scores should stay queued when the server can't be reached"
+		);
+		assert_eq!(pending.load(), vec![score("Dom", 42)], "The queued score should be untouched");
+	}
+}
@@ -33,6 +33,8 @@ pub struct Player {
 	pub beasts_killed: u16,
 	pub blocks_moved: u64,
 	pub distance_traveled: u64,
+	/// the direction the player last moved in, cleared when the player is placed somewhere new
+	pub last_dir: Option<Dir>,
 }
 
 impl Player {
@@ -45,6 +47,7 @@ impl Player {
 			beasts_killed: 0,
 			blocks_moved: 0,
 			distance_traveled: 0,
+			last_dir: None,
 		}
 	}
 
@@ -58,6 +61,7 @@ impl Player {
 					board[&self.position] = Tile::Empty;
 					board[&new_coord] = Tile::Player;
 					self.position = new_coord;
+					self.last_dir = Some(*dir);
 					PlayerAction::None
 				},
 				Tile::Block => {
@@ -81,6 +85,7 @@ impl Player {
 									board[&self.position] = Tile::Empty;
 									board[&new_coord] = Tile::Player;
 									self.position = new_coord;
+									self.last_dir = Some(*dir);
 									board[&end_coord] = Tile::Block;
 
 									match end_tile {
@@ -115,6 +120,7 @@ impl Player {
 									board[&self.position] = Tile::Empty;
 									board[&new_coord] = Tile::Player;
 									self.position = new_coord;
+									self.last_dir = Some(*dir);
 									board[&end_coord] = Tile::Block;
 									self.score += SuperBeast::get_score();
 
@@ -135,6 +141,7 @@ impl Player {
 								board[&self.position] = Tile::Empty;
 								board[&new_coord] = Tile::Player;
 								self.position = new_coord;
+								self.last_dir = Some(*dir);
 								board[&end_coord] = Tile::Block;
 
 								PlayerAction::None
@@ -179,6 +186,7 @@ impl Player {
 			board[&old_coord] = Tile::Empty;
 		}
 		self.position = new_coord;
+		self.last_dir = None;
 	}
 }
 
@@ -1348,4 +1356,27 @@ mod test {
 		);
 		assert_eq!(player.lives, 5, "The player has not lost a live");
 	}
+
+	#[test]
+	fn last_dir() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; BOARD_HEIGHT]);
+		let mut player = Player::new(Coord { column: 5, row: 10 });
+		board[&player.position] = Tile::Player;
+		board[&Coord { column: 5, row: 9 }] = Tile::StaticBlock;
+
+		player.advance(&mut board, &Dir::Up);
+		assert_eq!(player.last_dir, None, "A blocked move should not set a direction");
+
+		player.advance(&mut board, &Dir::Left);
+		assert_eq!(player.last_dir, Some(Dir::Left), "Moving should remember the direction");
+		player.advance(&mut board, &Dir::Right);
+		assert_eq!(player.last_dir, Some(Dir::Right), "Moving again should remember the new direction");
+
+		player.advance(&mut board, &Dir::Up);
+		assert_eq!(player.position, Coord { column: 5, row: 10 }, "The player should not move into a static block");
+		assert_eq!(player.last_dir, Some(Dir::Right), "A blocked move should keep the last direction");
+
+		player.respawn(&mut board);
+		assert_eq!(player.last_dir, None, "A respawned player should not have a direction");
+	}
 }
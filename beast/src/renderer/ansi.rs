@@ -0,0 +1,652 @@
+//! this module contains the renderer for ANSI terminals
+
+use crate::{
+	ANSI_BOLD, ANSI_RESET, ANSI_RESET_BG, ANSI_RESET_FONT, BOARD_WIDTH, Coord, LEFT_BORDER, RIGHT_BORDER, Tile,
+	game::{ANSI_BOARD_HEIGHT, ANSI_FOOTER_HEIGHT, ANSI_FRAME_SIZE},
+	renderer::{Camera, Flash, Frame, Hint, Hud, Line, Overlay, Page, Renderer, ScreenType, Span, Training, Viewport},
+	screen, theme, visible_width,
+};
+
+/// the renderer that draws into the terminal with escape sequences
+#[derive(Debug, Default)]
+pub struct AnsiRenderer {
+	/// the part of the board shown on terminals too small for all of it
+	camera: Camera,
+	/// the viewport the last frame was drawn into, `None` for the full layout
+	viewport: Option<Viewport>,
+	/// if the screen has to be cleared before drawing the next frame or page
+	stale: bool,
+}
+
+/// the arrows on the frame around the viewport that point at beasts outside of it
+#[derive(Debug, Default)]
+struct EdgeIndicators {
+	top: Vec<bool>,
+	bottom: Vec<bool>,
+	left: Vec<bool>,
+	right: Vec<bool>,
+}
+
+impl EdgeIndicators {
+	fn new(frame: &Frame, viewport: Viewport, camera: Camera) -> Self {
+		let mut indicators = Self {
+			top: vec![false; viewport.columns],
+			bottom: vec![false; viewport.columns],
+			left: vec![false; viewport.rows],
+			right: vec![false; viewport.rows],
+		};
+
+		for (row, tiles) in frame.board.buffer.iter().enumerate() {
+			for (column, tile) in tiles.iter().enumerate() {
+				let coord = Coord { column, row };
+				if !matches!(tile, Tile::CommonBeast | Tile::SuperBeast | Tile::HatchedBeast) || camera.shows(coord, viewport) {
+					continue;
+				}
+
+				// beasts off a corner are shown above or below the viewport
+				let column = column.clamp(camera.column, camera.column + viewport.columns - 1) - camera.column;
+				let row = row.clamp(camera.row, camera.row + viewport.rows - 1) - camera.row;
+				if coord.row < camera.row {
+					indicators.top[column] = true;
+				} else if coord.row >= camera.row + viewport.rows {
+					indicators.bottom[column] = true;
+				} else if coord.column < camera.column {
+					indicators.left[row] = true;
+				} else {
+					indicators.right[row] = true;
+				}
+			}
+		}
+
+		indicators
+	}
+
+	/// render a horizontal frame with arrows where beasts are beyond it
+	fn render_frame(indicators: &[bool], corners: (&str, &str), edge: &str, arrow: &str) -> String {
+		let theme = theme::current();
+		let mut output = theme.frame.paint(corners.0);
+		for indicator in indicators {
+			if *indicator {
+				output.push_str(&theme.ui.warning.paint(&arrow.repeat(2)));
+			} else {
+				output.push_str(&theme.frame.paint(&edge.repeat(2)));
+			}
+		}
+		output.push_str(&theme.frame.paint(corners.1));
+		output.push('\n');
+		output
+	}
+}
+
+impl AnsiRenderer {
+	/// render the small logo above the board
+	pub fn render_header() -> String {
+		let mut output = String::from("\n");
+		output.push_str(" ╔╗  ╔═╗ ╔═╗ ╔═╗ ╔╦╗\n");
+		output.push_str(" ╠╩╗ ║╣  ╠═╣ ╚═╗  ║\n");
+		output.push_str(" ╚═╝ ╚═╝ ╩ ╩ ╚═╝  ╩\n");
+		output
+	}
+
+	/// the top frame above the board
+	pub fn render_top_frame() -> String {
+		format!("{}\n", theme::current().frame.paint(&format!("▛{}▜", "▀▀".repeat(BOARD_WIDTH))))
+	}
+
+	/// the bottom frame below the board
+	pub fn render_bottom_frame() -> String {
+		format!("{}\n", theme::current().frame.paint(&format!("▙{}▟", "▄▄".repeat(BOARD_WIDTH))))
+	}
+
+	/// render a piece of text of a page in its style, `bg` is brought back after styles that reset it
+	fn render_span(span: &Span, bg: &str) -> String {
+		let ui = &theme::current().ui;
+		match span {
+			Span::Plain(text) => text.clone(),
+			Span::Bold(text) => format!("{ANSI_BOLD}{text}{ANSI_RESET}{bg}"),
+			Span::Muted(text) => ui.muted.paint(text),
+			Span::Faint(text) => format!("{}{text}{ANSI_RESET_FONT}", ui.faint.fg()),
+			Span::Highlight(text) => ui.highlight.paint(text),
+			Span::Warning(text) => ui.warning.paint(text),
+			Span::Tile(tile) => format!("{tile}{bg}"),
+		}
+	}
+
+	/// render a line of a page padded to the given width, shaded lines are drawn on the row background with a margin
+	pub fn render_page_line(line: &Line, width: usize) -> String {
+		let margin = line.margin(width);
+		let bg = if line.shaded {
+			theme::current().ui.row_bg.bg()
+		} else {
+			String::new()
+		};
+		let content = line.spans.iter().map(|span| Self::render_span(span, &bg)).collect::<String>();
+		let padding = (width - margin * 2).saturating_sub(visible_width(&line.text()));
+		let (left_pad, right_pad) = if line.centered {
+			(padding / 2, padding - padding / 2)
+		} else {
+			(0, padding)
+		};
+		format!("{:margin$}{bg}{:left_pad$}{content}{:right_pad$}{ANSI_RESET_BG}{:margin$}", "", "", "", "")
+	}
+
+	/// render a page into a board of the given size in tiles with its frame, arrows on the frame show when there is more
+	/// content above or below
+	fn render_page_board(page: &Page, columns: usize, rows: usize) -> String {
+		let width = columns * 2;
+		let footer = page.footer(width, rows);
+		let content = page.content(width);
+		let height = rows - footer.len();
+		let scroll = page.scroll.min(content.len().saturating_sub(height));
+		let arrow = |more: bool| (0..columns).map(|column| more && column == columns / 2).collect::<Vec<bool>>();
+		let blank = Line::blank();
+
+		let mut output = EdgeIndicators::render_frame(&arrow(scroll > 0), ("▛", "▜"), "▀", "▲");
+		for line in content.iter().skip(scroll).chain(std::iter::repeat(&blank)).take(height).chain(&footer) {
+			output.push_str(&format!("{LEFT_BORDER}{}{RIGHT_BORDER}\n", Self::render_page_line(line, width)));
+		}
+		output.push_str(&EdgeIndicators::render_frame(&arrow(scroll + height < content.len()), ("▙", "▟"), "▄", "▼"));
+		output
+	}
+
+	/// render a page into the full layout with the logo above the content
+	pub fn render_page_frame(page: &Page) -> String {
+		let mut output = String::from("\x1b[H");
+		output.push_str(&Self::render_header());
+		output.push_str(&Self::render_page_board(page, BOARD_WIDTH, ANSI_BOARD_HEIGHT));
+		match &page.hud {
+			Some(hud) => output.push_str(&Self::render_hud(hud)),
+			None => output.push_str(&"\x1b[K\n".repeat(ANSI_FOOTER_HEIGHT)),
+		}
+
+		output
+	}
+
+	/// render a page into the viewport of a small terminal without the logo, lines that are too long are wrapped and
+	/// the content scrolls between the top of the frame and the footer
+	pub fn render_page_viewport(page: &Page, viewport: Viewport) -> String {
+		let mut output = String::from("\x1b[H");
+		output.push_str(&Self::render_page_board(page, viewport.columns, viewport.rows));
+		match &page.hud {
+			Some(hud) => output.push_str(&Self::render_compact_hud(hud)),
+			None => output.push_str("\x1b[K\n"),
+		}
+		output.push_str("\x1b[K\n");
+
+		output
+	}
+
+	/// render a notice like the one asking to resize the terminal from the top left corner without any frame
+	pub fn render_notice(page: &Page) -> String {
+		let mut output = String::from("\x1b[2J\x1b[H");
+		for line in &page.lines {
+			output.push_str(&line.spans.iter().map(|span| Self::render_span(span, "")).collect::<String>());
+			output.push('\n');
+		}
+		output
+	}
+
+	/// render the heads up display below the board
+	pub fn render_hud(hud: &Hud) -> String {
+		let mut output = String::new();
+		let minutes = hud.secs_remaining / 60;
+		let seconds = hud.secs_remaining % 60;
+		let timer_color = if hud.timer_warning {
+			theme::current().ui.warning.fg()
+		} else {
+			String::from(ANSI_RESET_FONT)
+		};
+
+		let lives = if hud.lives == 1 {
+			theme::current().ui.warning.paint(&hud.lives.to_string())
+		} else {
+			hud.lives.to_string()
+		};
+
+		if hud.training {
+			output.push_str(&format!("⌂⌂  {}{:28}", theme::current().ui.highlight.paint("TRAINING"), ""));
+		} else {
+			output.push_str("⌂⌂                                      ");
+		}
+		output.push_str("  Beasts: ");
+		output.push_str(&format!("{ANSI_BOLD}{:>2}{ANSI_RESET}", hud.beasts.to_string()));
+		output.push_str("  Level: ");
+		output.push_str(&format!("{ANSI_BOLD}{:>2}{ANSI_RESET}", hud.level.to_string()));
+		output.push_str("  Time: ");
+		output.push_str(&format!("{ANSI_BOLD}{timer_color}{minutes:02}:{seconds:02}{ANSI_RESET}"));
+		output.push_str("  Lives: ");
+		output.push_str(&format!("{ANSI_BOLD}{lives}{ANSI_RESET}"));
+		output.push_str("  Score: ");
+		output.push_str(&format!("{ANSI_BOLD}{:>4}{ANSI_RESET}", hud.score));
+		// the line below is cleared so no announcement of an earlier frame is left behind
+		output.push_str(&format!(" {}\n\x1b[K\n", Tile::Player));
+
+		output
+	}
+
+	/// render the heads up display for terminals too small for the full one
+	pub fn render_compact_hud(hud: &Hud) -> String {
+		let minutes = hud.secs_remaining / 60;
+		let seconds = hud.secs_remaining % 60;
+		let timer_color = if hud.timer_warning {
+			theme::current().ui.warning.fg()
+		} else {
+			String::from(ANSI_RESET_FONT)
+		};
+
+		let lives = if hud.lives == 1 {
+			theme::current().ui.warning.paint(&hud.lives.to_string())
+		} else {
+			hud.lives.to_string()
+		};
+
+		format!(
+			"Beasts {ANSI_BOLD}{}{ANSI_RESET} Lvl {ANSI_BOLD}{}{ANSI_RESET} {ANSI_BOLD}{timer_color}{minutes:02}:{seconds:02}{ANSI_RESET} Lives {ANSI_BOLD}{lives}{ANSI_RESET} Score {ANSI_BOLD}{}{ANSI_RESET}\x1b[K\n",
+			hud.beasts, hud.level, hud.score
+		)
+	}
+
+	/// render a message box centered on top of a board of the given size in tiles
+	pub fn render_overlay(overlay: &Overlay, columns: usize, rows: usize) -> String {
+		let alert_height = 5;
+		let top_pos = ((rows + ANSI_FRAME_SIZE) / 2) + ANSI_FOOTER_HEIGHT + alert_height;
+		let bottom_pos = top_pos - alert_height;
+		let content_width = overlay.title.len().max(overlay.message.len());
+		let left_pad = format!(
+			"\x1b[{:.0}C",
+			((columns * 2 + ANSI_FRAME_SIZE + ANSI_FRAME_SIZE) / 2).saturating_sub((content_width + 4) / 2)
+		);
+
+		let progress_bar = format!("{:▁<width$}", "", width = (content_width * overlay.progress) / 100);
+		format!(
+			"\x1b[{top_pos}F{left_pad}┌{border:─<width$}┐\n{left_pad}│ {title:^content_width$} │\n{left_pad}│ {message:^content_width$} │\n{left_pad}│ {faint}{progress_bar:<content_width$}{ANSI_RESET_FONT} │\n{left_pad}└{border:─<width$}┘\n\x1b[{bottom_pos:.0}E",
+			border = "",
+			faint = theme::current().ui.faint.fg(),
+			title = overlay.title,
+			message = overlay.message,
+			width = content_width + 2,
+		)
+	}
+
+	/// render a tile with the hint the training overlay has for it, beasts are shown with the tile they move onto
+	/// highlighted, squishable beasts and eggs get the kill background and eggs show when they hatch
+	pub fn render_tile(coord: &Coord, tile: &Tile, training: &Training) -> String {
+		let ui = &theme::current().ui;
+		match training.hint(coord) {
+			Some(Hint::Squishable) => format!("{}{tile}{ANSI_RESET_BG}", ui.kill_flash.bg()),
+			Some(Hint::Hatching(secs)) => ui.warning.paint(&format!("{:>2}", secs.min(99))),
+			Some(Hint::Intent) if *tile == Tile::Empty => ui.highlight.paint("··"),
+			Some(Hint::Intent) => format!("{}{tile}{ANSI_RESET_BG}", ui.highlight.bg()),
+			None => tile.to_string(),
+		}
+	}
+
+	/// render a frame into the full layout with the small logo above the board
+	pub fn render_frame(frame: &Frame) -> String {
+		let bottom_pos = format!("\x1b[{ANSI_FRAME_SIZE}E");
+		let mut output = String::from("\x1b[H");
+
+		let flash = match frame.flash {
+			Some(Flash::Death) => theme::current().ui.death_flash.bg(),
+			Some(Flash::DeathFading) => theme::current().ui.death_fading_flash.bg(),
+			Some(Flash::Kill) => theme::current().ui.kill_flash.bg(),
+			None => String::from(ANSI_RESET_BG),
+		};
+		output.push_str(&Self::render_header());
+		output.push_str(&Self::render_top_frame());
+		output.push_str(&flash);
+		// tiles with a background reset it after themselves so we bring the flash back
+		let board = match &frame.training {
+			Some(training) => frame.board.render_with(|coord, tile| Self::render_tile(coord, tile, training)),
+			None => frame.board.render(),
+		};
+		output.push_str(&board.replace(ANSI_RESET_BG, &flash));
+		output.push_str(ANSI_RESET_BG);
+		output.push_str(&Self::render_bottom_frame());
+		output.push_str(&Self::render_hud(&frame.hud));
+		if let Some(announcement) = &frame.announcement {
+			// the announcement goes into the empty line below the HUD
+			let width = BOARD_WIDTH * 2 + ANSI_FRAME_SIZE + ANSI_FRAME_SIZE;
+			output.push_str(&format!("\x1b[1F{announcement:<width$}\n"));
+		}
+		output.push_str(&bottom_pos);
+
+		if let Some(overlay) = &frame.overlay {
+			output.push_str(&Self::render_overlay(overlay, BOARD_WIDTH, ANSI_BOARD_HEIGHT));
+		}
+
+		output
+	}
+
+	/// render the part of the board the camera shows with a compact HUD instead of the logo for small terminals,
+	/// beasts outside of the viewport are pointed at with arrows on the frame
+	pub fn render_viewport(frame: &Frame, viewport: Viewport, camera: Camera) -> String {
+		let indicators = EdgeIndicators::new(frame, viewport, camera);
+		let warning = &theme::current().ui.warning;
+		// the screen is only cleared when the layout changes so nothing flickers
+		let mut output = String::from("\x1b[H");
+
+		let flash = match frame.flash {
+			Some(Flash::Death) => theme::current().ui.death_flash.bg(),
+			Some(Flash::DeathFading) => theme::current().ui.death_fading_flash.bg(),
+			Some(Flash::Kill) => theme::current().ui.kill_flash.bg(),
+			None => String::from(ANSI_RESET_BG),
+		};
+		output.push_str(&EdgeIndicators::render_frame(&indicators.top, ("▛", "▜"), "▀", "▲"));
+		for row in 0..viewport.rows {
+			output.push_str(&if indicators.left[row] {
+				warning.paint("◀")
+			} else {
+				LEFT_BORDER.to_string()
+			});
+			output.push_str(&flash);
+			for column in camera.column..camera.column + viewport.columns {
+				let coord = Coord {
+					column,
+					row: camera.row + row,
+				};
+				let tile = &frame.board[&coord];
+				let tile = match &frame.training {
+					Some(training) => Self::render_tile(&coord, tile, training),
+					None => tile.to_string(),
+				};
+				// tiles with a background reset it after themselves so we bring the flash back
+				output.push_str(&tile.replace(ANSI_RESET_BG, &flash));
+			}
+			output.push_str(ANSI_RESET_BG);
+			output.push_str(&if indicators.right[row] {
+				warning.paint("▶")
+			} else {
+				RIGHT_BORDER.to_string()
+			});
+			output.push('\n');
+		}
+		output.push_str(&EdgeIndicators::render_frame(&indicators.bottom, ("▙", "▟"), "▄", "▼"));
+		output.push_str(&Self::render_compact_hud(&frame.hud));
+		output.push_str(&format!("{}\x1b[K\n", frame.announcement.as_deref().unwrap_or_default()));
+		output.push_str(&format!("\x1b[{ANSI_FRAME_SIZE}E"));
+
+		if let Some(overlay) = &frame.overlay {
+			output.push_str(&Self::render_overlay(overlay, viewport.columns, viewport.rows));
+		}
+
+		output
+	}
+}
+
+impl AnsiRenderer {
+	/// print output drawn into the given layout, the screen is cleared first when the layout changed
+	fn print(&mut self, viewport: Option<Viewport>, output: &str) {
+		let clear = if self.stale || viewport != self.viewport {
+			"\x1b[2J"
+		} else {
+			""
+		};
+		self.viewport = viewport;
+		self.stale = false;
+		screen::print(&format!("{clear}{output}"));
+	}
+}
+
+impl Renderer for AnsiRenderer {
+	fn render(&mut self, frame: &Frame) {
+		let viewport = screen::size().and_then(|(columns, rows)| Viewport::for_terminal(columns, rows));
+		let output = match viewport {
+			Some(viewport) => {
+				if let Some(player) = frame.board.find(Tile::Player) {
+					self.camera.follow(player, viewport);
+				}
+				Self::render_viewport(frame, viewport, self.camera)
+			},
+			None => Self::render_frame(frame),
+		};
+		self.print(viewport, &output);
+	}
+
+	fn render_page(&mut self, page: &Page) {
+		if page.screen == ScreenType::TooSmall {
+			self.stale = true;
+			screen::print(&Self::render_notice(page));
+			return;
+		}
+		let viewport = screen::size().and_then(|(columns, rows)| Viewport::for_terminal(columns, rows));
+		let output = match viewport {
+			Some(viewport) => Self::render_page_viewport(page, viewport),
+			None => Self::render_page_frame(page),
+		};
+		self.print(viewport, &output);
+	}
+
+	fn clear(&mut self) {
+		self.stale = true;
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		LOGO, board::Board, common::levels::Level, game::ANSI_HEADER_HEIGHT, renderer::strip_ansi,
+		test_common::strip_ansi_border,
+	};
+
+	fn hud() -> Hud {
+		Hud {
+			beasts: 3,
+			level: Level::One,
+			secs_remaining: 75,
+			timer_warning: false,
+			lives: 5,
+			score: 42,
+			training: false,
+		}
+	}
+
+	#[test]
+	fn render_hud_test() {
+		let output = strip_ansi_border(&AnsiRenderer::render_hud(&hud()));
+		assert!(output.contains("Beasts:  3"), "The HUD should show the beasts");
+		assert!(output.contains("Time: 01:15"), "The HUD should show the time");
+		assert!(output.contains("Score:   42"), "The HUD should show the score");
+	}
+
+	#[test]
+	fn header_height_test() {
+		assert_eq!(
+			AnsiRenderer::render_header().lines().count(),
+			ANSI_HEADER_HEIGHT,
+			"There should be exactly ANSI_HEADER_HEIGHT lines in the header"
+		);
+	}
+
+	#[test]
+	fn frame_test() {
+		for (name, output) in [
+			("top", AnsiRenderer::render_top_frame()),
+			("bottom", AnsiRenderer::render_bottom_frame()),
+		] {
+			assert_eq!(output.lines().count(), ANSI_FRAME_SIZE, "The {name} frame should be ANSI_FRAME_SIZE lines high");
+			assert_eq!(
+				strip_ansi(&output).trim_end().chars().count(),
+				BOARD_WIDTH * 2 + ANSI_FRAME_SIZE + ANSI_FRAME_SIZE,
+				"The {name} frame should span the board and its borders"
+			);
+		}
+	}
+
+	#[test]
+	fn render_page_line_test() {
+		let line = strip_ansi(&AnsiRenderer::render_page_line(&Line::centered(vec![Span::bold("Bye")]), 100));
+		assert_eq!(line.chars().count(), 100, "A centered line should be padded to the width");
+		assert_eq!(line.find("Bye"), Some(48), "A centered line should have the content in the middle");
+
+		let line = strip_ansi(&AnsiRenderer::render_page_line(&Line::plain("row").shaded(true), 100));
+		assert_eq!(line.chars().count(), 100, "A shaded line should be padded to the width");
+		assert!(line.starts_with("   row"), "A shaded line should have a margin");
+	}
+
+	#[test]
+	fn render_page_frame_test() {
+		let page = Page {
+			hud: Some(hud()),
+			..Page::new(ScreenType::Help, vec![Line::plain("content")], vec![Line::plain("keys")])
+		};
+		let output = strip_ansi_border(&strip_ansi(&AnsiRenderer::render_page_frame(&page)));
+		let lines = output.lines().collect::<Vec<_>>();
+		let board_top = ANSI_HEADER_HEIGHT + ANSI_FRAME_SIZE;
+
+		assert_eq!(
+			lines.len(),
+			ANSI_HEADER_HEIGHT + ANSI_FRAME_SIZE + ANSI_BOARD_HEIGHT + ANSI_FRAME_SIZE + ANSI_FOOTER_HEIGHT,
+			"A page should fill the full layout"
+		);
+		assert!(lines[board_top + LOGO.len()].starts_with("content"), "The content should follow the logo");
+		assert!(
+			lines[board_top + ANSI_BOARD_HEIGHT - 1].starts_with("keys"),
+			"The footer should be pinned to the bottom of the board"
+		);
+		assert!(lines[board_top + ANSI_BOARD_HEIGHT + 1].contains("Lives:"), "The HUD should be drawn below the frame");
+
+		let scrolled = strip_ansi(&AnsiRenderer::render_page_frame(&Page { scroll: 100, ..page }));
+		assert!(
+			scrolled.lines().nth(board_top + 2).is_some_and(|line| line.contains("HHHH")),
+			"Scrolling should stop once the content fits"
+		);
+	}
+
+	#[test]
+	fn render_frame_test() {
+		let board = Board::new(Board::generate_terrain(Level::One).buffer);
+		let frame = Frame {
+			screen: ScreenType::Paused,
+			board: &board,
+			hud: hud(),
+			overlay: Some(Overlay {
+				title: String::from("PAUSED"),
+				message: String::from("Press [P] to resume"),
+				progress: 0,
+			}),
+			flash: Some(Flash::Kill),
+			announcement: Some(String::from("Beast killed")),
+			announcements: Vec::new(),
+			training: None,
+		};
+		let output = AnsiRenderer::render_frame(&frame);
+
+		assert!(
+			output.contains(&format!("{}\x1b[48;2;51;51;51m", AnsiRenderer::render_top_frame())),
+			"The flash should be drawn right below the top frame"
+		);
+		assert!(output.starts_with("\x1b[H"), "The frame should be drawn from the top of the screen");
+		assert!(output.contains("PAUSED"), "The overlay should be drawn");
+		assert!(output.contains("Lives:"), "The HUD should be drawn");
+		assert!(output.contains("\x1b[1FBeast killed   "), "The announcement should be drawn below the HUD");
+	}
+
+	#[test]
+	fn render_frame_training_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; crate::BOARD_HEIGHT]);
+		board.buffer[0][0] = Tile::CommonBeast;
+		board.buffer[0][1] = Tile::Block;
+		board.buffer[0][2] = Tile::Player;
+		board.buffer[5][5] = Tile::Egg(std::time::Instant::now());
+		board.buffer[9][9] = Tile::SuperBeast;
+		let frame = Frame {
+			screen: ScreenType::Playing,
+			board: &board,
+			hud: Hud {
+				training: true,
+				..hud()
+			},
+			overlay: None,
+			flash: None,
+			announcement: None,
+			announcements: Vec::new(),
+			training: Some(Training {
+				intents: vec![Coord { column: 9, row: 10 }],
+				squishable: vec![Coord { column: 0, row: 0 }],
+				eggs: vec![(Coord { column: 5, row: 5 }, 7)],
+			}),
+		};
+		let output = AnsiRenderer::render_frame(&frame);
+		let lines = strip_ansi_border(&strip_ansi(&output));
+		// skip the header and the top frame so the lines match the rows of the board
+		let lines = lines.lines().skip(ANSI_HEADER_HEIGHT + ANSI_FRAME_SIZE).collect::<Vec<_>>();
+
+		assert!(
+			output.contains(&format!("{}{}", theme::current().ui.kill_flash.bg(), Tile::CommonBeast)),
+			"Squishable beasts should be highlighted"
+		);
+		assert!(lines[5].contains(" 7"), "Eggs should show when they hatch");
+		assert!(
+			!lines[5].contains(&strip_ansi(&Tile::Egg(std::time::Instant::now()).to_string())),
+			"The countdown replaces the egg"
+		);
+		assert!(lines[10].contains("··"), "The tile a beast moves onto next should be marked");
+		assert!(lines.iter().any(|line| line.contains("TRAINING")), "The HUD should show the overlay is on");
+	}
+
+	#[test]
+	fn render_viewport_test() {
+		let mut board = Board::new([[Tile::Empty; BOARD_WIDTH]; crate::BOARD_HEIGHT]);
+		board.buffer[2][3] = Tile::Player;
+		board.buffer[25][5] = Tile::CommonBeast;
+		board.buffer[4][40] = Tile::SuperBeast;
+		let frame = Frame {
+			screen: ScreenType::Playing,
+			board: &board,
+			hud: hud(),
+			overlay: None,
+			flash: None,
+			announcement: None,
+			announcements: Vec::new(),
+			training: None,
+		};
+		let output =
+			strip_ansi(&AnsiRenderer::render_viewport(&frame, Viewport { columns: 20, rows: 10 }, Camera::default()));
+		let lines = output.lines().collect::<Vec<_>>();
+
+		assert_eq!(lines.len(), 1 + 10 + 1 + 2, "The viewport should be framed with the compact HUD below");
+		assert!(
+			lines[1 + 2].contains(&strip_ansi(&Tile::Player.to_string())),
+			"The player should be drawn inside the viewport"
+		);
+		assert_eq!(lines[11], format!("▙{}▼▼{}▟", "▄▄".repeat(5), "▄▄".repeat(14)), "Beasts below should be pointed at");
+		assert!(lines[1 + 4].ends_with('▶'), "Beasts to the right should be pointed at");
+		assert!(lines[12].contains("Beasts 3 Lvl 1 01:15 Lives 5 Score 42"), "The compact HUD should be drawn");
+		assert!(!output.contains("╔╗"), "The logo should not be drawn");
+		assert!(!output.contains("\x1b[2J"), "The viewport should not clear the screen on every frame");
+	}
+
+	#[test]
+	fn render_page_viewport_test() {
+		let page = Page::new(
+			ScreenType::Help,
+			vec![Line::plain("one two three four five six seven eight nine ten eleven"); 12],
+			vec![Line::centered(vec![
+				Span::plain("Press "),
+				Span::bold("[SPACE]"),
+				Span::plain(" key to start"),
+			])],
+		);
+		let viewport = Viewport { columns: 10, rows: 10 };
+		let lines = |page: &Page| {
+			strip_ansi(&AnsiRenderer::render_page_viewport(page, viewport)).lines().map(String::from).collect::<Vec<_>>()
+		};
+
+		let top = lines(&page);
+		assert_eq!(top.len(), 1 + 10 + 1 + 2, "The page should fill the viewport with room for the HUD below");
+		assert!(top.iter().all(|line| line.chars().count() <= 22), "Every line should fit into the viewport");
+		assert!(!top.iter().any(|line| line.contains("HHHH")), "The logo should not be drawn");
+		assert_eq!(top[1], "▌one two three four  ▐", "Long lines should be wrapped");
+		assert_eq!(top[9], "▌Press [SPACE] key to▐", "The footer should be wrapped too");
+		assert_eq!(top[10], "▌       start        ▐", "The footer should be pinned to the bottom");
+		assert!(!top[0].contains('▲'), "There is nothing above to scroll to");
+		assert!(top[11].contains('▼'), "The frame should show there is more below");
+
+		let bottom = lines(&Page { scroll: 100, ..page });
+		assert_eq!(bottom[8], "▌nine ten eleven     ▐", "Scrolling should stop at the last line");
+		assert!(bottom[0].contains('▲'), "The frame should show there is more above");
+		assert!(!bottom[11].contains('▼'), "There is nothing below to scroll to");
+	}
+}
@@ -0,0 +1,42 @@
+//! this module contains the renderer that keeps every frame in memory for snapshot tests
+
+use std::sync::{Arc, Mutex};
+
+use crate::renderer::{Frame, Page, PlainRenderer, Renderer};
+
+/// the renderer that records every frame as plain text, clones share the same recording
+#[derive(Debug, Clone, Default)]
+pub struct MemoryRenderer {
+	frames: Arc<Mutex<Vec<String>>>,
+}
+
+impl MemoryRenderer {
+	/// create a new instance with an empty recording
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// all frames rendered so far
+	pub fn frames(&self) -> Vec<String> {
+		self.frames.lock().unwrap_or_else(|error| error.into_inner()).clone()
+	}
+
+	/// the last frame rendered
+	pub fn last_frame(&self) -> Option<String> {
+		self.frames.lock().unwrap_or_else(|error| error.into_inner()).last().cloned()
+	}
+
+	fn record(&self, frame: String) {
+		self.frames.lock().unwrap_or_else(|error| error.into_inner()).push(frame);
+	}
+}
+
+impl Renderer for MemoryRenderer {
+	fn render(&mut self, frame: &Frame) {
+		self.record(PlainRenderer::<Vec<u8>>::render_frame(frame));
+	}
+
+	fn render_page(&mut self, page: &Page) {
+		self.record(PlainRenderer::<Vec<u8>>::render_page_text(page));
+	}
+}
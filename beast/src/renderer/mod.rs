@@ -0,0 +1,236 @@
+//! this module contains the renderers that turn a frame of the game into output
+
+pub mod ansi;
+pub use ansi::*;
+
+#[cfg(test)]
+pub mod memory;
+#[cfg(test)]
+pub use memory::*;
+
+pub mod page;
+pub use page::*;
+
+pub mod plain;
+pub use plain::*;
+
+pub mod viewport;
+pub use viewport::*;
+
+use serde::{Deserialize, Serialize};
+use std::io;
+
+use crate::{BOARD_WIDTH, Coord, board::Board, common::levels::Level, game::ANSI_BOARD_HEIGHT, screen};
+
+/// the renderers that can be picked in the config
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RendererType {
+	/// draw on the terminal with escape sequences
+	#[default]
+	Ansi,
+	/// write plain text frames to stdout for logs
+	Plain,
+}
+
+impl RendererType {
+	/// parse a renderer from its name as used on the cli
+	pub fn from_name(name: &str) -> Option<Self> {
+		match name.to_ascii_lowercase().as_str() {
+			"ansi" => Some(Self::Ansi),
+			"plain" => Some(Self::Plain),
+			_ => None,
+		}
+	}
+
+	/// create the renderer, with accessibility turned on the plain renderer only writes the announcements
+	pub fn create(self, accessibility: bool) -> Box<dyn Renderer> {
+		match self {
+			Self::Ansi => Box::new(AnsiRenderer::default()),
+			Self::Plain if accessibility => Box::new(PlainRenderer::announcing(io::stdout())),
+			Self::Plain => Box::new(PlainRenderer::new(io::stdout())),
+		}
+	}
+}
+
+/// the screens the game can show
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenType {
+	/// the intro screen
+	Intro,
+	/// the board while playing
+	Playing,
+	/// the board with the pause overlay
+	Paused,
+	/// the board with the level summary overlay
+	LevelComplete,
+	/// the help pages
+	Help,
+	/// the highscore table
+	Highscore,
+	/// the screen after losing
+	GameOver,
+	/// the screen after winning
+	Won,
+	/// the notice that the terminal is too small to play in
+	TooSmall,
+}
+
+/// the values shown in the heads up display below the board
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hud {
+	/// how many beasts are left on the board
+	pub beasts: usize,
+	/// the level being played
+	pub level: Level,
+	/// the seconds left to finish the level
+	pub secs_remaining: u64,
+	/// if the timer should be highlighted because time is running out
+	pub timer_warning: bool,
+	/// the lives the player has left
+	pub lives: u8,
+	/// the score of the player
+	pub score: u16,
+	/// if the training overlay is shown
+	pub training: bool,
+}
+
+/// a message box drawn on top of the board
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overlay {
+	/// the headline of the message
+	pub title: String,
+	/// the message below the headline
+	pub message: String,
+	/// the progress of the bar at the bottom in percent
+	pub progress: usize,
+}
+
+/// the hints of the training overlay drawn onto the board
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Training {
+	/// the tiles beasts move onto next
+	pub intents: Vec<Coord>,
+	/// the beasts and eggs the player can squish with a single push
+	pub squishable: Vec<Coord>,
+	/// the eggs with the seconds left until they hatch
+	pub eggs: Vec<(Coord, u64)>,
+}
+
+/// what the training overlay points out on a tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+	/// a beast moves onto this tile next
+	Intent,
+	/// the beast or egg on this tile can be squished right now
+	Squishable,
+	/// the egg on this tile hatches in this many seconds
+	Hatching(u64),
+}
+
+impl Training {
+	/// the hint for a tile, a beast that can be squished is more important than anything else about it
+	pub fn hint(&self, coord: &Coord) -> Option<Hint> {
+		if self.squishable.contains(coord) {
+			Some(Hint::Squishable)
+		} else if let Some((_, secs)) = self.eggs.iter().find(|(egg, _)| egg == coord) {
+			Some(Hint::Hatching(*secs))
+		} else if self.intents.contains(coord) {
+			Some(Hint::Intent)
+		} else {
+			None
+		}
+	}
+}
+
+/// the background flashes to give feedback on what happened
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Flash {
+	/// the player just died
+	Death,
+	/// the death flash is fading out
+	DeathFading,
+	/// the player just killed something
+	Kill,
+}
+
+/// everything a renderer needs to draw a frame of the game
+#[derive(Debug)]
+pub struct Frame<'a> {
+	/// the screen this frame belongs to
+	pub screen: ScreenType,
+	/// the board with all tiles
+	pub board: &'a Board,
+	/// the values of the heads up display
+	pub hud: Hud,
+	/// a message drawn on top of the board
+	pub overlay: Option<Overlay>,
+	/// a background flash
+	pub flash: Option<Flash>,
+	/// the status line of the accessibility mode
+	pub announcement: Option<String>,
+	/// the announcements made since the last frame, every announcement is only part of one frame
+	pub announcements: Vec<String>,
+	/// the hints of the training overlay
+	pub training: Option<Training>,
+}
+
+/// a frontend that can draw the game
+pub trait Renderer: Send {
+	/// draw a frame of the game
+	fn render(&mut self, frame: &Frame);
+	/// draw a full page like the intro, help or the end screens
+	fn render_page(&mut self, page: &Page);
+	/// forget what was drawn before so the next frame or page is drawn on a cleared screen
+	fn clear(&mut self) {}
+}
+
+/// how far a page can be scrolled in the layout the terminal has room for
+pub fn max_scroll(page: &Page) -> usize {
+	match screen::size().and_then(|(columns, rows)| Viewport::for_terminal(columns, rows)) {
+		Some(viewport) => page.max_scroll(viewport.columns * 2, viewport.rows),
+		None => page.max_scroll(BOARD_WIDTH * 2, ANSI_BOARD_HEIGHT),
+	}
+}
+
+#[cfg(test)]
+/// remove all escape sequences from ANSI text
+pub fn strip_ansi(text: &str) -> String {
+	crate::escape::chars(text).collect()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn hint_test() {
+		let training = Training {
+			intents: vec![Coord { column: 1, row: 1 }, Coord { column: 2, row: 2 }],
+			squishable: vec![Coord { column: 2, row: 2 }, Coord { column: 3, row: 3 }],
+			eggs: vec![(Coord { column: 3, row: 3 }, 5), (Coord { column: 4, row: 4 }, 7)],
+		};
+
+		assert_eq!(training.hint(&Coord { column: 1, row: 1 }), Some(Hint::Intent), "Beasts should show where they go");
+		assert_eq!(
+			training.hint(&Coord { column: 2, row: 2 }),
+			Some(Hint::Squishable),
+			"Squishable beasts should win over intents"
+		);
+		assert_eq!(
+			training.hint(&Coord { column: 3, row: 3 }),
+			Some(Hint::Squishable),
+			"Squishable eggs should win over their countdown"
+		);
+		assert_eq!(training.hint(&Coord { column: 4, row: 4 }), Some(Hint::Hatching(7)), "Eggs should count down");
+		assert_eq!(training.hint(&Coord { column: 5, row: 5 }), None, "Other tiles have no hint");
+	}
+
+	#[test]
+	fn strip_ansi_test() {
+		assert_eq!(
+			strip_ansi("\x1b[34F\x1b[1;31mHELLO\x1b[0m world\x1b[?25l"),
+			"HELLO world",
+			"All escape sequences should be removed"
+		);
+	}
+}
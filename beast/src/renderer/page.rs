@@ -0,0 +1,304 @@
+//! this module contains the pages like the intro, help or the highscores as styled text the renderers lay out
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::{
+	LOGO, Tile,
+	renderer::{Hud, ScreenType},
+};
+
+/// how many columns a tile takes, themes make sure every glyph is this wide
+const TILE_WIDTH: usize = 2;
+
+/// a piece of text on a page and how it is styled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Span {
+	/// text without a style
+	Plain(String),
+	/// text in bold like headlines and keys
+	Bold(String),
+	/// text in the muted colour of the theme like table headers
+	Muted(String),
+	/// text in the faint colour of the theme
+	Faint(String),
+	/// text in the highlight colour of the theme
+	Highlight(String),
+	/// text in the warning colour of the theme
+	Warning(String),
+	/// a tile of the board
+	Tile(Tile),
+}
+
+impl Span {
+	/// text without a style
+	pub fn plain(text: impl Into<String>) -> Self {
+		Self::Plain(text.into())
+	}
+
+	/// text in bold
+	pub fn bold(text: impl Into<String>) -> Self {
+		Self::Bold(text.into())
+	}
+
+	/// text in the muted colour
+	pub fn muted(text: impl Into<String>) -> Self {
+		Self::Muted(text.into())
+	}
+
+	/// text in the faint colour
+	pub fn faint(text: impl Into<String>) -> Self {
+		Self::Faint(text.into())
+	}
+
+	/// text in the highlight colour
+	pub fn highlight(text: impl Into<String>) -> Self {
+		Self::Highlight(text.into())
+	}
+
+	/// text in the warning colour
+	pub fn warning(text: impl Into<String>) -> Self {
+		Self::Warning(text.into())
+	}
+
+	/// the same style with a different text, tiles stay as they are
+	fn with_text(&self, text: String) -> Self {
+		match self {
+			Self::Plain(_) => Self::Plain(text),
+			Self::Bold(_) => Self::Bold(text),
+			Self::Muted(_) => Self::Muted(text),
+			Self::Faint(_) => Self::Faint(text),
+			Self::Highlight(_) => Self::Highlight(text),
+			Self::Warning(_) => Self::Warning(text),
+			Self::Tile(tile) => Self::Tile(*tile),
+		}
+	}
+
+	/// the text without its style, tiles are written with their raw symbol
+	pub fn text(&self) -> &str {
+		match self {
+			Self::Plain(text)
+			| Self::Bold(text)
+			| Self::Muted(text)
+			| Self::Faint(text)
+			| Self::Highlight(text)
+			| Self::Warning(text) => text,
+			Self::Tile(tile) => tile.raw_symbol(),
+		}
+	}
+}
+
+/// a line of a page
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Line {
+	/// the pieces of text the line is made of
+	pub spans: Vec<Span>,
+	/// if the line is centered instead of aligned to the left
+	pub centered: bool,
+	/// if the line is drawn on the highlighted background of every other table row
+	pub shaded: bool,
+}
+
+impl Line {
+	/// a line aligned to the left
+	pub fn new(spans: Vec<Span>) -> Self {
+		Self {
+			spans,
+			centered: false,
+			shaded: false,
+		}
+	}
+
+	/// a centered line
+	pub fn centered(spans: Vec<Span>) -> Self {
+		Self {
+			spans,
+			centered: true,
+			shaded: false,
+		}
+	}
+
+	/// a line of text without a style aligned to the left
+	pub fn plain(text: impl Into<String>) -> Self {
+		Self::new(vec![Span::plain(text)])
+	}
+
+	/// an empty line
+	pub fn blank() -> Self {
+		Self::default()
+	}
+
+	/// draw the line on the highlighted background
+	pub fn shaded(mut self, shaded: bool) -> Self {
+		self.shaded = shaded;
+		self
+	}
+
+	/// the text of the line without any style
+	pub fn text(&self) -> String {
+		self.spans.iter().map(Span::text).collect()
+	}
+
+	/// the columns left empty on both sides of the line in a board of the given width
+	pub fn margin(&self, width: usize) -> usize {
+		if self.shaded { 3.min(width / 4) } else { 0 }
+	}
+
+	/// break the line at spaces into lines no wider than the given columns, words that don't fit on their own are split
+	pub fn wrap(&self, width: usize) -> Vec<Line> {
+		// every character with the span it belongs to, tiles are a single cell that is never split
+		let mut cells = Vec::new();
+		for (index, span) in self.spans.iter().enumerate() {
+			match span {
+				Span::Tile(_) => cells.push((index, None)),
+				_ => cells.extend(span.text().chars().map(|char| (index, Some(char)))),
+			}
+		}
+		let cell_width = |cell: &(usize, Option<char>)| cell.1.map_or(TILE_WIDTH, |char| char.width().unwrap_or(0));
+		let is_space = |cell: &(usize, Option<char>)| cell.1 == Some(' ');
+
+		if cells.iter().map(cell_width).sum::<usize>() <= width {
+			return vec![self.clone()];
+		}
+
+		let mut lines = Vec::new();
+		let mut start = 0;
+		while start < cells.len() {
+			if !lines.is_empty() {
+				while start < cells.len() && is_space(&cells[start]) {
+					start += 1;
+				}
+				if start == cells.len() {
+					break;
+				}
+			}
+
+			let mut end = start;
+			let mut used = 0;
+			while end < cells.len() && (end == start || used + cell_width(&cells[end]) <= width) {
+				used += cell_width(&cells[end]);
+				end += 1;
+			}
+			if end < cells.len()
+				&& let Some(space) = (start + 1..=end).rev().find(|index| is_space(&cells[*index]))
+			{
+				end = space;
+			}
+
+			let spans = cells[start..end]
+				.chunk_by(|a, b| a.0 == b.0 && a.1.is_some() && b.1.is_some())
+				.map(|chunk| self.spans[chunk[0].0].with_text(chunk.iter().filter_map(|cell| cell.1).collect()))
+				.collect();
+			lines.push(Self {
+				spans,
+				centered: self.centered,
+				shaded: self.shaded,
+			});
+			start = end;
+		}
+		lines
+	}
+}
+
+/// a full page like the intro, help or the end screens
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Page {
+	/// the screen this page belongs to
+	pub screen: ScreenType,
+	/// if the logo is drawn above the content, it scrolls with the content
+	pub logo: bool,
+	/// the content of the page which is scrolled when it doesn't fit
+	pub lines: Vec<Line>,
+	/// the lines pinned to the bottom of the page like the keys that can be pressed
+	pub footer: Vec<Line>,
+	/// how many lines of the content are scrolled out at the top
+	pub scroll: usize,
+	/// the heads up display shown below the page
+	pub hud: Option<Hud>,
+}
+
+impl Page {
+	/// a page with the logo above the content
+	pub fn new(screen: ScreenType, lines: Vec<Line>, footer: Vec<Line>) -> Self {
+		Self {
+			screen,
+			logo: true,
+			lines,
+			footer,
+			scroll: 0,
+			hud: None,
+		}
+	}
+
+	/// the content as drawn in a board of the given width, the logo is left out when the board is too narrow for it
+	pub fn content(&self, width: usize) -> Vec<Line> {
+		let logo = if self.logo && LOGO[0].len() <= width {
+			LOGO.iter().map(|line| Line::plain(*line)).collect()
+		} else {
+			Vec::new()
+		};
+		logo.iter().chain(&self.lines).flat_map(|line| line.wrap(width - line.margin(width) * 2)).collect()
+	}
+
+	/// the footer as drawn in a board of the given size, it never takes more than all but one row
+	pub fn footer(&self, width: usize, height: usize) -> Vec<Line> {
+		let footer = self.footer.iter().flat_map(|line| line.wrap(width - line.margin(width) * 2)).collect::<Vec<Line>>();
+		footer[footer.len().saturating_sub(height.saturating_sub(1))..].to_vec()
+	}
+
+	/// how many lines of the content can be scrolled out at the top of a board of the given size
+	pub fn max_scroll(&self, width: usize, height: usize) -> usize {
+		let rows = height - self.footer(width, height).len();
+		self.content(width).len().saturating_sub(rows)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn text_test() {
+		let line = Line::centered(vec![Span::bold("HELP "), Span::Tile(Tile::Player), Span::muted(" Play")]);
+		assert_eq!(line.text(), "HELP ◀▶ Play", "The text should leave out all styles");
+		assert_eq!(Line::blank().text(), "", "Blank lines have no text");
+	}
+
+	#[test]
+	fn wrap_test() {
+		let line = Line::centered(vec![
+			Span::plain("Press "),
+			Span::bold("[SPACE]"),
+			Span::plain(" key to start"),
+		]);
+		assert_eq!(line.wrap(40), vec![line.clone()], "Lines that fit should be left alone");
+
+		let lines = line.wrap(14);
+		assert_eq!(
+			lines.iter().map(Line::text).collect::<Vec<_>>(),
+			vec!["Press [SPACE]", "key to start"],
+			"Lines should break at spaces"
+		);
+		assert_eq!(lines[0].spans[1], Span::bold("[SPACE]"), "Wrapped lines should keep their style");
+		assert!(lines.iter().all(|line| line.centered), "Wrapped lines should keep their alignment");
+
+		let lines = Line::new(vec![Span::plain("abcdef"), Span::Tile(Tile::Player)]).wrap(4);
+		assert_eq!(
+			lines.iter().map(Line::text).collect::<Vec<_>>(),
+			vec!["abcd", "ef◀▶"],
+			"Words that don't fit should be split"
+		);
+		assert_eq!(lines[1].spans[1], Span::Tile(Tile::Player), "Tiles should not be split");
+	}
+
+	#[test]
+	fn max_scroll_test() {
+		let page = Page::new(ScreenType::Help, vec![Line::plain("one two"); 20], vec![Line::plain("keys")]);
+
+		assert_eq!(page.content(100).len(), LOGO.len() + 20, "The logo should be drawn when it fits");
+		assert_eq!(page.max_scroll(100, 30), 1, "The content should scroll past the footer");
+		assert_eq!(page.content(40).len(), 20, "The logo should be left out when it doesn't fit");
+		assert_eq!(page.max_scroll(40, 30), 0, "Content that fits should not scroll");
+		assert_eq!(page.max_scroll(4, 10), 31, "Wrapped lines should be scrolled through");
+		assert_eq!(page.footer(40, 1).len(), 0, "The footer should leave a row for the content");
+	}
+}
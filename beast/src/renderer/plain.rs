@@ -0,0 +1,260 @@
+//! this module contains the renderer that writes plain text for logs and tests
+
+use std::io::Write;
+
+use crate::{
+	Coord, Tile,
+	renderer::{Flash, Frame, Hint, Hud, Page, Renderer},
+};
+
+/// the renderer that writes every frame as plain text without any escape sequences
+#[derive(Debug)]
+pub struct PlainRenderer<W: Write + Send> {
+	writer: W,
+	/// if frames are left out and only their announcements are written for screen readers
+	announcements_only: bool,
+}
+
+impl<W: Write + Send> PlainRenderer<W> {
+	/// create a new instance writing into the given writer
+	pub fn new(writer: W) -> Self {
+		Self {
+			writer,
+			announcements_only: false,
+		}
+	}
+
+	/// create a new instance that writes every announcement once as its own line instead of the frames
+	pub fn announcing(writer: W) -> Self {
+		Self {
+			writer,
+			announcements_only: true,
+		}
+	}
+
+	/// render a frame as plain text
+	pub fn render_frame(frame: &Frame) -> String {
+		let mut output = format!("[{:?}]\n", frame.screen);
+
+		for (row, tiles) in frame.board.buffer.iter().enumerate() {
+			output.push('|');
+			for (column, tile) in tiles.iter().enumerate() {
+				// the training overlay marks squishable beasts with `!!`, hatching eggs with their countdown and the tiles
+				// beasts move onto with `··`
+				let hint = frame.training.as_ref().and_then(|training| training.hint(&Coord { column, row }));
+				match hint {
+					Some(Hint::Squishable) => output.push_str("!!"),
+					Some(Hint::Hatching(secs)) => output.push_str(&format!("{:02}", secs.min(99))),
+					Some(Hint::Intent) if *tile == Tile::Empty => output.push_str("··"),
+					_ => output.push_str(tile.raw_symbol()),
+				}
+			}
+			output.push_str("|\n");
+		}
+
+		output.push_str(&Self::render_hud(&frame.hud));
+		for announcement in &frame.announcements {
+			output.push_str(&format!("Status: {announcement}\n"));
+		}
+		if let Some(overlay) = &frame.overlay {
+			output.push_str(&format!("{}: {}\n", overlay.title, overlay.message));
+		}
+		match frame.flash {
+			Some(Flash::Death | Flash::DeathFading) => output.push_str("* the player died *\n"),
+			Some(Flash::Kill) => output.push_str("* the player killed *\n"),
+			None => {},
+		}
+
+		output
+	}
+
+	/// render the heads up display as plain text
+	fn render_hud(hud: &Hud) -> String {
+		let mut output = format!(
+			"Beasts: {}  Level: {}  Time: {:02}:{:02}  Lives: {}  Score: {}\n",
+			hud.beasts,
+			hud.level,
+			hud.secs_remaining / 60,
+			hud.secs_remaining % 60,
+			hud.lives,
+			hud.score
+		);
+		if hud.training {
+			output.push_str("Training\n");
+		}
+		output
+	}
+
+	/// render a page as plain text without the logo, the whole content is written no matter how far it's scrolled
+	pub fn render_page_text(page: &Page) -> String {
+		let mut output = format!("[{:?}]\n", page.screen);
+		for line in page.lines.iter().chain(&page.footer) {
+			output.push_str(line.text().trim_end());
+			output.push('\n');
+		}
+		if let Some(hud) = &page.hud {
+			output.push_str(&Self::render_hud(hud));
+		}
+		output
+	}
+}
+
+impl<W: Write + Send> Renderer for PlainRenderer<W> {
+	fn render(&mut self, frame: &Frame) {
+		if self.announcements_only {
+			for announcement in &frame.announcements {
+				let _ = writeln!(self.writer, "{announcement}");
+			}
+			return;
+		}
+		let _ = writeln!(self.writer, "{}", Self::render_frame(frame));
+	}
+
+	fn render_page(&mut self, page: &Page) {
+		let _ = writeln!(self.writer, "{}", Self::render_page_text(page));
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{
+		board::Board,
+		common::levels::Level,
+		renderer::{Line, Overlay, ScreenType, Span, Training},
+	};
+
+	#[test]
+	fn render_test() {
+		let board = Board::new(Board::generate_terrain(Level::One).buffer);
+		let frame = Frame {
+			screen: ScreenType::Paused,
+			board: &board,
+			hud: Hud {
+				beasts: 3,
+				level: Level::One,
+				secs_remaining: 75,
+				timer_warning: true,
+				lives: 5,
+				score: 42,
+				training: false,
+			},
+			overlay: Some(Overlay {
+				title: String::from("PAUSED"),
+				message: String::from("Press [P] to resume"),
+				progress: 0,
+			}),
+			flash: None,
+			announcement: Some(String::from("Nearest beast 2 up")),
+			announcements: vec![String::from("Beast killed")],
+			training: None,
+		};
+
+		let mut output = Vec::new();
+		PlainRenderer::new(&mut output).render(&frame);
+		let output = String::from_utf8(output).expect("The output should be valid UTF-8");
+
+		assert!(!output.contains('\x1b'), "Plain text should not contain escape sequences");
+		assert!(output.starts_with("[Paused]\n"), "The screen type should be the first line");
+		assert!(output.contains("◀▶"), "The player should be on the board");
+		assert!(output.contains("Time: 01:15  Lives: 5  Score: 42"), "The HUD should be written");
+		assert!(output.contains("PAUSED: Press [P] to resume"), "The overlay should be written");
+		assert!(output.contains("Status: Beast killed\n"), "New announcements should be written");
+		assert!(!output.contains("Nearest beast"), "The status line should not be repeated in every frame");
+	}
+
+	#[test]
+	fn render_announcing_test() {
+		let board = Board::new(Board::generate_terrain(Level::One).buffer);
+		let hud = Hud {
+			beasts: 3,
+			level: Level::One,
+			secs_remaining: 75,
+			timer_warning: false,
+			lives: 5,
+			score: 0,
+			training: false,
+		};
+		let mut output = Vec::new();
+		let mut renderer = PlainRenderer::announcing(&mut output);
+		for announcements in [
+			vec![String::from("Beast killed"), String::from("60 seconds left")],
+			Vec::new(),
+		] {
+			renderer.render(&Frame {
+				screen: ScreenType::Playing,
+				board: &board,
+				hud: hud.clone(),
+				overlay: None,
+				flash: None,
+				announcement: Some(String::from("Beast killed")),
+				announcements,
+				training: None,
+			});
+		}
+		let output = String::from_utf8(output).expect("The output should be valid UTF-8");
+
+		assert_eq!(
+			output, "Beast killed\n60 seconds left\n",
+			"Every announcement should be written once as its own line without the frame"
+		);
+	}
+
+	#[test]
+	fn render_training_test() {
+		let mut board = Board::new([[Tile::Empty; crate::BOARD_WIDTH]; crate::BOARD_HEIGHT]);
+		board.buffer[0][0] = Tile::CommonBeast;
+		board.buffer[0][2] = Tile::SuperBeast;
+		board.buffer[1][1] = Tile::Egg(std::time::Instant::now());
+		let frame = Frame {
+			screen: ScreenType::Playing,
+			board: &board,
+			hud: Hud {
+				beasts: 2,
+				level: Level::One,
+				secs_remaining: 75,
+				timer_warning: false,
+				lives: 5,
+				score: 0,
+				training: true,
+			},
+			overlay: None,
+			flash: None,
+			announcement: None,
+			announcements: Vec::new(),
+			training: Some(Training {
+				intents: vec![Coord { column: 3, row: 0 }],
+				squishable: vec![Coord { column: 0, row: 0 }],
+				eggs: vec![(Coord { column: 1, row: 1 }, 4)],
+			}),
+		};
+		let output = PlainRenderer::<Vec<u8>>::render_frame(&frame);
+		let lines = output.lines().collect::<Vec<_>>();
+
+		assert!(lines[1].starts_with("|!!  ╟╢··  "), "Squishable beasts and the tiles beasts move onto should be marked");
+		assert!(lines[2].starts_with("|  04  "), "Eggs should show when they hatch");
+		assert!(output.contains("\nTraining\n"), "The HUD should show the overlay is on");
+	}
+
+	#[test]
+	fn render_page_test() {
+		let page = Page {
+			scroll: 3,
+			..Page::new(
+				ScreenType::Help,
+				vec![
+					Line::centered(vec![Span::bold("HELP")]),
+					Line::blank(),
+					Line::new(vec![Span::Tile(Tile::Player)]),
+				],
+				vec![Line::plain("keys   ")],
+			)
+		};
+
+		assert_eq!(
+			PlainRenderer::<Vec<u8>>::render_page_text(&page),
+			"[Help]\nHELP\n\n◀▶\nkeys\n",
+			"Pages should be written as text without the logo and trailing whitespace no matter the scroll"
+		);
+	}
+}
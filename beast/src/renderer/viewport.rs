@@ -0,0 +1,110 @@
+//! this module contains the camera that scrolls the board on terminals too small to show all of it
+
+use crate::{
+	BOARD_HEIGHT, BOARD_WIDTH, Coord,
+	game::{ANSI_FOOTER_HEIGHT, ANSI_FRAME_SIZE, FULL_TERMINAL_HEIGHT, FULL_TERMINAL_WIDTH},
+};
+
+/// the fewest columns of tiles we scroll the board in
+pub const MIN_VIEWPORT_COLUMNS: usize = 20;
+/// the fewest rows of tiles we scroll the board in
+pub const MIN_VIEWPORT_ROWS: usize = 10;
+/// how many tiles the camera keeps between the player and the edge of the viewport
+const CAMERA_MARGIN: usize = 5;
+
+/// the part of the board that fits into the terminal in tiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+	pub columns: usize,
+	pub rows: usize,
+}
+
+impl Viewport {
+	/// the viewport for a terminal of the given size or `None` when the full layout with the logo fits
+	pub fn for_terminal(columns: usize, rows: usize) -> Option<Self> {
+		if columns >= FULL_TERMINAL_WIDTH && rows >= FULL_TERMINAL_HEIGHT {
+			return None;
+		}
+
+		Some(Self {
+			columns: (columns.saturating_sub(ANSI_FRAME_SIZE * 2) / 2).clamp(MIN_VIEWPORT_COLUMNS, BOARD_WIDTH),
+			rows: rows.saturating_sub(ANSI_FRAME_SIZE * 2 + ANSI_FOOTER_HEIGHT).clamp(MIN_VIEWPORT_ROWS, BOARD_HEIGHT),
+		})
+	}
+}
+
+/// the top left tile of the board shown in the viewport
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Camera {
+	pub column: usize,
+	pub row: usize,
+}
+
+impl Camera {
+	/// move the camera only as far as needed to keep the player away from the edges of the viewport
+	pub fn follow(&mut self, player: Coord, viewport: Viewport) {
+		self.column = Self::follow_axis(self.column, player.column, viewport.columns, BOARD_WIDTH);
+		self.row = Self::follow_axis(self.row, player.row, viewport.rows, BOARD_HEIGHT);
+	}
+
+	fn follow_axis(start: usize, player: usize, size: usize, board_size: usize) -> usize {
+		let margin = CAMERA_MARGIN.min(size.saturating_sub(1) / 2);
+		let mut start = start;
+		if player < start + margin {
+			start = player.saturating_sub(margin);
+		} else if player + margin >= start + size {
+			start = player + margin + 1 - size;
+		}
+		start.min(board_size.saturating_sub(size))
+	}
+
+	/// if a tile is inside the viewport
+	pub fn shows(&self, coord: Coord, viewport: Viewport) -> bool {
+		(self.column..self.column + viewport.columns).contains(&coord.column)
+			&& (self.row..self.row + viewport.rows).contains(&coord.row)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn for_terminal_test() {
+		assert_eq!(Viewport::for_terminal(120, 45), None, "The whole board should be shown on big terminals");
+		assert_eq!(
+			Viewport::for_terminal(80, 24),
+			Some(Viewport { columns: 39, rows: 20 }),
+			"The viewport should fill a small terminal"
+		);
+		assert_eq!(
+			Viewport::for_terminal(120, 30),
+			Some(Viewport {
+				columns: BOARD_WIDTH,
+				rows: 26
+			}),
+			"The viewport should never be wider than the board"
+		);
+	}
+
+	#[test]
+	fn follow_test() {
+		let viewport = Viewport { columns: 20, rows: 10 };
+		let mut camera = Camera::default();
+
+		camera.follow(Coord { column: 10, row: 4 }, viewport);
+		assert_eq!(
+			camera,
+			Camera { column: 0, row: 0 },
+			"The camera should not move while the player is inside the margin"
+		);
+
+		camera.follow(Coord { column: 15, row: 5 }, viewport);
+		assert_eq!(camera, Camera { column: 1, row: 0 }, "The camera should keep the margin to the right edge");
+
+		camera.follow(Coord { column: 49, row: 29 }, viewport);
+		assert_eq!(camera, Camera { column: 30, row: 20 }, "The camera should not scroll past the board");
+		assert!(camera.shows(Coord { column: 49, row: 29 }, viewport), "The player should be inside the viewport");
+		assert!(!camera.shows(Coord { column: 0, row: 29 }, viewport), "Tiles left of the viewport should be hidden");
+	}
+}
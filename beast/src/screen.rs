@@ -0,0 +1,512 @@
+//! this module contains the screen buffer that draws the game on the alternate screen and only sends the cells that
+//! changed since the last frame to the terminal
+
+use std::{
+	io::{self, IsTerminal, Write},
+	sync::{Mutex, MutexGuard},
+};
+
+use crate::{
+	capabilities,
+	escape::{self, Token},
+	game::{FULL_TERMINAL_HEIGHT, FULL_TERMINAL_WIDTH},
+	terminal,
+};
+
+/// the screen everything is printed through, without one we print straight to stdout
+static SCREEN: Mutex<Option<Screen>> = Mutex::new(None);
+
+/// the attribute bits of a [Style]
+const BOLD: u8 = 1;
+const DIM: u8 = 1 << 1;
+const ITALIC: u8 = 1 << 2;
+const UNDERLINE: u8 = 1 << 3;
+const BLINK: u8 = 1 << 4;
+const REVERSE: u8 = 1 << 5;
+const STRIKE: u8 = 1 << 6;
+
+/// a foreground or background color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Color {
+	/// the default color of the terminal
+	#[default]
+	Default,
+	/// one of the 256 indexed colors, the first 16 are the basic and bright colors
+	Indexed(u8),
+	/// a true color
+	Rgb(u8, u8, u8),
+}
+
+impl Color {
+	/// parse the parameters following a `38` or `48` like `5;235` or `2;51;51;51`
+	fn from_extended(params: &mut impl Iterator<Item = u16>) -> Self {
+		match params.next() {
+			Some(5) => Self::Indexed(params.next().unwrap_or(0) as u8),
+			Some(2) => {
+				Self::Rgb(params.next().unwrap_or(0) as u8, params.next().unwrap_or(0) as u8, params.next().unwrap_or(0) as u8)
+			},
+			_ => Self::Default,
+		}
+	}
+
+	/// the SGR parameters for this color, `base` is `30` for the foreground and `40` for the background
+	fn to_sgr(self, base: u16) -> String {
+		match self {
+			Self::Default => format!("{}", base + 9),
+			Self::Indexed(index @ 0..8) => format!("{}", base + index as u16),
+			Self::Indexed(index @ 8..16) => format!("{}", base + 60 + index as u16 - 8),
+			Self::Indexed(index) => format!("{};5;{index}", base + 8),
+			Self::Rgb(red, green, blue) => format!("{};2;{red};{green};{blue}", base + 8),
+		}
+	}
+}
+
+/// the colors and attributes of a cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Style {
+	foreground: Color,
+	background: Color,
+	attributes: u8,
+}
+
+impl Style {
+	/// apply the parameters of a SGR escape sequence like `\x1b[1;31m`
+	fn apply_sgr(&mut self, params: &[u16]) {
+		if params.is_empty() {
+			*self = Self::default();
+			return;
+		}
+
+		let mut params = params.iter().copied();
+		while let Some(param) = params.next() {
+			match param {
+				0 => *self = Self::default(),
+				1 => self.attributes |= BOLD,
+				2 => self.attributes |= DIM,
+				3 => self.attributes |= ITALIC,
+				4 => self.attributes |= UNDERLINE,
+				5 => self.attributes |= BLINK,
+				7 => self.attributes |= REVERSE,
+				9 => self.attributes |= STRIKE,
+				22 => self.attributes &= !(BOLD | DIM),
+				23 => self.attributes &= !ITALIC,
+				24 => self.attributes &= !UNDERLINE,
+				25 => self.attributes &= !BLINK,
+				27 => self.attributes &= !REVERSE,
+				29 => self.attributes &= !STRIKE,
+				30..=37 => self.foreground = Color::Indexed((param - 30) as u8),
+				38 => self.foreground = Color::from_extended(&mut params),
+				39 => self.foreground = Color::Default,
+				40..=47 => self.background = Color::Indexed((param - 40) as u8),
+				48 => self.background = Color::from_extended(&mut params),
+				49 => self.background = Color::Default,
+				90..=97 => self.foreground = Color::Indexed((param - 90 + 8) as u8),
+				100..=107 => self.background = Color::Indexed((param - 100 + 8) as u8),
+				_ => {},
+			}
+		}
+	}
+
+	/// the escape sequence that switches the terminal from any style to this one
+	fn to_sgr(self) -> String {
+		let mut params = vec![String::from("0")];
+		for (bit, param) in [
+			(BOLD, "1"),
+			(DIM, "2"),
+			(ITALIC, "3"),
+			(UNDERLINE, "4"),
+			(BLINK, "5"),
+			(REVERSE, "7"),
+			(STRIKE, "9"),
+		] {
+			if self.attributes & bit != 0 {
+				params.push(String::from(param));
+			}
+		}
+		if self.foreground != Color::Default {
+			params.push(self.foreground.to_sgr(30));
+		}
+		if self.background != Color::Default {
+			params.push(self.background.to_sgr(40));
+		}
+		format!("\x1b[{}m", params.join(";"))
+	}
+}
+
+/// a single character on the screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+	symbol: char,
+	style: Style,
+}
+
+impl Default for Cell {
+	fn default() -> Self {
+		Self {
+			symbol: ' ',
+			style: Style::default(),
+		}
+	}
+}
+
+/// a grid of cells that interprets the output of our render functions like a terminal would
+#[derive(Debug)]
+struct Screen {
+	columns: usize,
+	rows: usize,
+	/// the part of the screen that fits into the terminal, everything outside of it is drawn but never shown
+	visible_columns: usize,
+	visible_rows: usize,
+	/// the frame we're drawing
+	cells: Vec<Cell>,
+	/// the frame that is currently shown in the terminal
+	shown: Vec<Cell>,
+	/// if the terminal has to be drawn from scratch with the next frame
+	invalidated: bool,
+	cursor_row: usize,
+	cursor_column: usize,
+	style: Style,
+	/// if the terminal can only display ASCII and every other glyph has to be replaced
+	ascii: bool,
+}
+
+impl Screen {
+	fn new(columns: usize, rows: usize) -> Self {
+		Self {
+			columns,
+			rows,
+			visible_columns: columns,
+			visible_rows: rows,
+			cells: vec![Cell::default(); columns * rows],
+			shown: vec![Cell::default(); columns * rows],
+			invalidated: true,
+			cursor_row: 0,
+			cursor_column: 0,
+			style: Style::default(),
+			ascii: false,
+		}
+	}
+
+	/// change the size of the screen, the content is kept where it fits and the next frame is drawn from scratch
+	fn resize(&mut self, columns: usize, rows: usize) {
+		let mut cells = vec![Cell::default(); columns * rows];
+		for row in 0..rows.min(self.rows) {
+			for column in 0..columns.min(self.columns) {
+				cells[row * columns + column] = self.cells[row * self.columns + column];
+			}
+		}
+
+		self.cells = cells;
+		self.shown = vec![Cell::default(); columns * rows];
+		self.columns = columns;
+		self.rows = rows;
+		self.visible_columns = self.visible_columns.min(columns);
+		self.visible_rows = self.visible_rows.min(rows);
+		self.cursor_row = self.cursor_row.min(rows.saturating_sub(1));
+		self.cursor_column = self.cursor_column.min(columns);
+		self.invalidated = true;
+	}
+
+	/// only show the top left part of the screen, the next frame is drawn from scratch
+	fn clip(&mut self, columns: usize, rows: usize) {
+		self.visible_columns = columns.min(self.columns);
+		self.visible_rows = rows.min(self.rows);
+		self.invalidated = true;
+	}
+
+	/// a screen for a terminal of the given size that is never smaller than the full layout so the render functions can
+	/// move the cursor around like they would on a big terminal
+	fn for_terminal(columns: usize, rows: usize) -> Self {
+		let mut screen = Self::new(columns.max(FULL_TERMINAL_WIDTH), rows.max(FULL_TERMINAL_HEIGHT));
+		screen.clip(columns, rows);
+		screen
+	}
+
+	/// write output into the screen, we understand the escape sequences our render functions use
+	fn write(&mut self, output: &str) {
+		for token in escape::tokenize(output) {
+			match token {
+				Token::Csi { params, action } => self.apply_csi(params, action),
+				Token::Escape => {},
+				Token::Char('\n') => self.line_feed(),
+				Token::Char('\r') => self.cursor_column = 0,
+				Token::Char(c) if c.is_control() => {},
+				Token::Char(c) => {
+					if self.cursor_column < self.columns && self.cursor_row < self.rows {
+						self.cells[self.cursor_row * self.columns + self.cursor_column] = Cell {
+							symbol: if self.ascii { to_ascii(c) } else { c },
+							style: self.style,
+						};
+					}
+					self.cursor_column += 1;
+				},
+			}
+		}
+	}
+
+	fn apply_csi(&mut self, params: &str, action: char) {
+		// private sequences like showing the cursor don't change the screen
+		if params.starts_with('?') {
+			return;
+		}
+
+		let numbers = params.split(';').filter_map(|param| param.parse::<u16>().ok()).collect::<Vec<u16>>();
+		let count = numbers.first().copied().unwrap_or(1).max(1) as usize;
+
+		match action {
+			'm' => self.style.apply_sgr(&numbers),
+			'A' => self.cursor_row = self.cursor_row.saturating_sub(count),
+			'B' => self.cursor_row = (self.cursor_row + count).min(self.rows.saturating_sub(1)),
+			'C' => self.cursor_column = (self.cursor_column + count).min(self.columns.saturating_sub(1)),
+			'D' => self.cursor_column = self.cursor_column.saturating_sub(count),
+			'E' => {
+				self.cursor_row = (self.cursor_row + count).min(self.rows.saturating_sub(1));
+				self.cursor_column = 0;
+			},
+			'F' => {
+				self.cursor_row = self.cursor_row.saturating_sub(count);
+				self.cursor_column = 0;
+			},
+			'G' => self.cursor_column = (count - 1).min(self.columns.saturating_sub(1)),
+			'H' | 'f' => {
+				self.cursor_row = (count - 1).min(self.rows.saturating_sub(1));
+				self.cursor_column =
+					(numbers.get(1).copied().unwrap_or(1).max(1) as usize - 1).min(self.columns.saturating_sub(1));
+			},
+			'J' => {
+				let start = match numbers.first() {
+					Some(2) | Some(3) => 0,
+					_ => (self.cursor_row * self.columns + self.cursor_column).min(self.cells.len()),
+				};
+				self.cells[start..].fill(Cell::default());
+			},
+			'K' if self.cursor_row < self.rows => {
+				let row_start = self.cursor_row * self.columns;
+				let start = match numbers.first() {
+					Some(2) => row_start,
+					_ => row_start + self.cursor_column.min(self.columns),
+				};
+				self.cells[start..row_start + self.columns].fill(Cell::default());
+			},
+			_ => {},
+		}
+	}
+
+	/// move to the start of the next line and scroll once we're past the bottom like a terminal does
+	fn line_feed(&mut self) {
+		self.cursor_column = 0;
+		if self.cursor_row + 1 < self.rows {
+			self.cursor_row += 1;
+		} else {
+			self.cells.drain(..self.columns.min(self.cells.len()));
+			self.cells.resize(self.columns * self.rows, Cell::default());
+		}
+	}
+
+	/// the escape sequences that turn the frame shown in the terminal into the one we drew
+	fn diff(&mut self) -> String {
+		let mut output = String::new();
+		if self.invalidated {
+			output.push_str("\x1b[0m\x1b[2J");
+			self.shown.fill(Cell::default());
+		}
+
+		let mut cursor = None;
+		let mut style = None;
+		for row in 0..self.visible_rows {
+			for column in 0..self.visible_columns {
+				let index = row * self.columns + column;
+				let cell = self.cells[index];
+				if self.shown[index] == cell {
+					continue;
+				}
+
+				if cursor != Some((row, column)) {
+					output.push_str(&format!("\x1b[{};{}H", row + 1, column + 1));
+				}
+				if style != Some(cell.style) {
+					output.push_str(&cell.style.to_sgr());
+					style = Some(cell.style);
+				}
+				output.push(cell.symbol);
+				cursor = Some((row, column + 1));
+				self.shown[index] = cell;
+			}
+		}
+
+		if style.is_some() {
+			output.push_str("\x1b[0m");
+		}
+		self.invalidated = false;
+		output
+	}
+}
+
+/// the ASCII character that looks the most like a glyph
+fn to_ascii(c: char) -> char {
+	match c {
+		c if c.is_ascii() => c,
+		'▌' | '▐' | '│' | '║' | '├' | '┤' | '╟' | '╢' => '|',
+		'▀' | '▄' | '─' | '▁' => '-',
+		'═' => '=',
+		'▛' | '▜' | '▙' | '▟' | '┌' | '┐' | '└' | '┘' | '╔' | '╗' | '╚' | '╝' | '╠' | '╣' | '╦' | '╩' | '╬' => {
+			'+'
+		},
+		'░' => ':',
+		'▓' | '█' => '#',
+		'◀' | '←' => '<',
+		'▶' | '→' => '>',
+		'↑' | '▲' | '⌂' => '^',
+		'↓' | '▼' => 'v',
+		'●' | '◉' => '*',
+		'○' => 'o',
+		_ => '?',
+	}
+}
+
+fn lock() -> MutexGuard<'static, Option<Screen>> {
+	SCREEN.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// switch to the alternate screen and draw through the screen buffer from now on if stdout is a terminal
+pub fn enter() {
+	if !io::stdout().is_terminal() {
+		return;
+	}
+
+	if let Ok((columns, rows)) = terminal::terminal_size() {
+		terminal::enter_alternate_screen();
+		let mut screen = Screen::for_terminal(columns, rows);
+		screen.ascii = !capabilities::current().unicode;
+		*lock() = Some(screen);
+	}
+}
+
+/// leave the alternate screen, everything printed afterwards goes straight to stdout
+pub fn leave() {
+	*lock() = None;
+	terminal::leave_alternate_screen();
+}
+
+/// adjust the screen buffer to a new terminal size, the next frame is drawn from scratch
+pub fn resize(columns: usize, rows: usize) {
+	if let Some(screen) = lock().as_mut() {
+		screen.resize(columns.max(FULL_TERMINAL_WIDTH), rows.max(FULL_TERMINAL_HEIGHT));
+		screen.clip(columns, rows);
+	}
+}
+
+/// the size of the terminal we draw on or `None` when we print straight to stdout
+pub fn size() -> Option<(usize, usize)> {
+	lock().as_ref().map(|screen| (screen.visible_columns, screen.visible_rows))
+}
+
+/// print to the terminal, on the alternate screen only the cells that changed are sent
+pub fn print(output: &str) {
+	let mut screen = lock();
+	let mut stdout = io::stdout().lock();
+
+	let _ = match screen.as_mut() {
+		Some(screen) => {
+			screen.write(output);
+			stdout.write_all(screen.diff().as_bytes())
+		},
+		None => stdout.write_all(output.as_bytes()),
+	};
+	let _ = stdout.flush();
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn write_test() {
+		let mut screen = Screen::new(10, 3);
+		screen.write("ab\ncd");
+
+		assert_eq!(screen.cells[0].symbol, 'a', "The first character should be in the first cell");
+		assert_eq!(screen.cells[10].symbol, 'c', "A new line should start at the beginning of the next row");
+		assert_eq!((screen.cursor_row, screen.cursor_column), (1, 2), "The cursor should be after the last character");
+	}
+
+	#[test]
+	fn cursor_movement_test() {
+		let mut screen = Screen::new(10, 5);
+		screen.write("\n\n\n\x1b[2Fx\x1b[2Ey\x1b[3Cz\x1b[1;5Hw");
+
+		assert_eq!(screen.cells[10].symbol, 'x', "Moving up lines should go to the start of the line");
+		assert_eq!(screen.cells[30].symbol, 'y', "Moving down lines should go to the start of the line");
+		assert_eq!(screen.cells[34].symbol, 'z', "Moving right should skip cells");
+		assert_eq!(screen.cells[4].symbol, 'w', "Positioning the cursor should be one-based");
+	}
+
+	#[test]
+	fn style_test() {
+		let mut screen = Screen::new(10, 1);
+		screen.write("\x1b[1;31ma\x1b[39mb\x1b[48;5;196mc\x1b[0md");
+
+		assert_eq!(screen.cells[0].style.foreground, Color::Indexed(1), "The foreground should be red");
+		assert_eq!(screen.cells[0].style.attributes, BOLD, "The cell should be bold");
+		assert_eq!(screen.cells[1].style.foreground, Color::Default, "The foreground should be reset");
+		assert_eq!(screen.cells[1].style.attributes, BOLD, "Resetting the foreground should keep bold");
+		assert_eq!(screen.cells[2].style.background, Color::Indexed(196), "The extended background should be parsed");
+		assert_eq!(screen.cells[3].style, Style::default(), "Everything should be reset");
+		assert_eq!(screen.cells[2].style.to_sgr(), "\x1b[0;1;48;5;196m", "The style should turn back into SGR");
+	}
+
+	#[test]
+	fn scroll_test() {
+		let mut screen = Screen::new(5, 2);
+		screen.write("a\nb\nc");
+
+		assert_eq!(screen.cells[0].symbol, 'b', "The first line should have scrolled out");
+		assert_eq!(screen.cells[5].symbol, 'c', "The last line should be at the bottom");
+	}
+
+	#[test]
+	fn diff_test() {
+		let mut screen = Screen::new(20, 5);
+		screen.write("\x1b[31mhello\nworld");
+		let first = screen.diff();
+		assert!(first.starts_with("\x1b[0m\x1b[2J"), "The first frame should be drawn from scratch");
+		assert!(first.contains("hello"), "The first frame should contain the text");
+
+		screen.write("\x1b[2Fhello\nworld");
+		assert_eq!(screen.diff(), "", "Drawing the same frame again should send nothing");
+
+		screen.write("\x1b[2Fhello\nwhirl");
+		assert_eq!(screen.diff(), "\x1b[2;2H\x1b[0;31mhirl\x1b[0m", "Only the changed cells should be sent");
+	}
+
+	#[test]
+	fn resize_test() {
+		let mut screen = Screen::new(5, 2);
+		screen.write("abc");
+		screen.diff();
+		screen.resize(2, 3);
+
+		assert_eq!(screen.cells.len(), 6, "The screen should have the new size");
+		assert_eq!(screen.cells[1].symbol, 'b', "The content should be kept where it fits");
+		assert!(screen.diff().starts_with("\x1b[0m\x1b[2J"), "The next frame should be drawn from scratch");
+	}
+
+	#[test]
+	fn ascii_test() {
+		let mut screen = Screen::new(6, 1);
+		screen.ascii = true;
+		screen.write("▌◀▶░ö▐");
+		let symbols = screen.cells.iter().map(|cell| cell.symbol).collect::<String>();
+		assert_eq!(symbols, "|<>:?|", "Glyphs should be replaced with ASCII");
+	}
+
+	#[test]
+	fn clip_test() {
+		let mut screen = Screen::new(4, 3);
+		screen.clip(2, 1);
+		screen.write("abcd\nefgh");
+
+		assert_eq!(screen.cells[5].symbol, 'f', "Everything should be drawn into the screen");
+		assert_eq!(screen.diff(), "\x1b[0m\x1b[2J\x1b[1;1H\x1b[0mab\x1b[0m", "Only the visible cells should be sent");
+	}
+}
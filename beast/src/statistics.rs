@@ -0,0 +1,187 @@
+//! this module keeps track of the statistics of a game broken down per level and per beast type
+
+use std::time::Duration;
+
+use crate::{
+	beasts::{Beast, CommonBeast, Egg, HatchedBeast, SuperBeast},
+	common::levels::Level,
+	player::PlayerAction,
+};
+
+/// the statistics collected while playing a single level
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelStatistics {
+	/// the level these statistics belong to
+	pub level: Level,
+	/// how many common beasts were squished
+	pub common_beasts_killed: u16,
+	/// how many super beasts were squished
+	pub super_beasts_killed: u16,
+	/// how many eggs were squished
+	pub eggs_killed: u16,
+	/// how many hatched beasts were squished
+	pub hatched_beasts_killed: u16,
+	/// how many lives the player lost
+	pub lives_lost: u8,
+	/// how long the player spent in this level
+	pub time_taken: Duration,
+	/// the score awarded for the time left over
+	pub time_bonus: u16,
+	/// the score earned in this level including the time bonus and completion score
+	pub score: u16,
+}
+
+impl LevelStatistics {
+	/// create a new empty instance for a level
+	pub fn new(level: Level) -> Self {
+		Self {
+			level,
+			common_beasts_killed: 0,
+			super_beasts_killed: 0,
+			eggs_killed: 0,
+			hatched_beasts_killed: 0,
+			lives_lost: 0,
+			time_taken: Duration::from_secs(0),
+			time_bonus: 0,
+			score: 0,
+		}
+	}
+
+	/// the sum of all beasts killed in this level
+	pub fn beasts_killed(&self) -> u16 {
+		self.common_beasts_killed + self.super_beasts_killed + self.eggs_killed + self.hatched_beasts_killed
+	}
+}
+
+/// the statistics of an entire game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Statistics {
+	/// one entry per level played with the last one being the current level
+	pub levels: Vec<LevelStatistics>,
+}
+
+impl Statistics {
+	/// create a new instance starting at the given level
+	pub fn new(level: Level) -> Self {
+		Self {
+			levels: vec![LevelStatistics::new(level)],
+		}
+	}
+
+	/// start collecting statistics for a new level
+	pub fn start_level(&mut self, level: Level) {
+		self.levels.push(LevelStatistics::new(level));
+	}
+
+	/// the statistics of the level currently played
+	pub fn current(&self) -> &LevelStatistics {
+		self.levels.last().expect("Statistics always contain at least one level")
+	}
+
+	fn current_mut(&mut self) -> &mut LevelStatistics {
+		self.levels.last_mut().expect("Statistics always contain at least one level")
+	}
+
+	/// record the kills from an action the player took
+	pub fn record_player_action(&mut self, player_action: &PlayerAction) {
+		let current = self.current_mut();
+		match player_action {
+			PlayerAction::KillCommonBeast(_) => {
+				current.common_beasts_killed += 1;
+				current.score += CommonBeast::get_score();
+			},
+			PlayerAction::KillSuperBeast(_) => {
+				current.super_beasts_killed += 1;
+				current.score += SuperBeast::get_score();
+			},
+			PlayerAction::KillEgg(_) => {
+				current.eggs_killed += 1;
+				current.score += Egg::get_score();
+			},
+			PlayerAction::KillHatchedBeast(_) => {
+				current.hatched_beasts_killed += 1;
+				current.score += HatchedBeast::get_score();
+			},
+			PlayerAction::KillPlayer => {
+				current.lives_lost += 1;
+			},
+			PlayerAction::None => {},
+		}
+	}
+
+	/// record the player losing a life
+	pub fn record_life_lost(&mut self) {
+		self.current_mut().lives_lost += 1;
+	}
+
+	/// record the time spent in the current level and the time bonus awarded for it
+	pub fn finish_level(&mut self, time_taken: Duration, time_bonus: u16) {
+		let current = self.current_mut();
+		current.time_taken = time_taken;
+		current.time_bonus = time_bonus;
+		current.score += time_bonus;
+	}
+
+	/// add score to the current level that was not earned through kills or time bonus
+	pub fn add_score(&mut self, score: u16) {
+		self.current_mut().score += score;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Coord;
+
+	#[test]
+	fn record_player_action_test() {
+		let mut statistics = Statistics::new(Level::One);
+		let coord = Coord { column: 0, row: 0 };
+
+		statistics.record_player_action(&PlayerAction::KillCommonBeast(coord));
+		statistics.record_player_action(&PlayerAction::KillCommonBeast(coord));
+		statistics.record_player_action(&PlayerAction::KillSuperBeast(coord));
+		statistics.record_player_action(&PlayerAction::KillEgg(coord));
+		statistics.record_player_action(&PlayerAction::KillHatchedBeast(coord));
+		statistics.record_player_action(&PlayerAction::KillPlayer);
+		statistics.record_player_action(&PlayerAction::None);
+
+		let current = statistics.current();
+		assert_eq!(current.common_beasts_killed, 2, "Two common beasts should have been recorded");
+		assert_eq!(current.super_beasts_killed, 1, "One super beast should have been recorded");
+		assert_eq!(current.eggs_killed, 1, "One egg should have been recorded");
+		assert_eq!(current.hatched_beasts_killed, 1, "One hatched beast should have been recorded");
+		assert_eq!(current.beasts_killed(), 5, "All kills should be summed up");
+		assert_eq!(current.lives_lost, 1, "The player dying should be recorded as a life lost");
+		assert_eq!(
+			current.score,
+			CommonBeast::get_score() * 2 + SuperBeast::get_score() + Egg::get_score() + HatchedBeast::get_score(),
+			"The score should add up the score of each kill"
+		);
+	}
+
+	#[test]
+	fn levels_test() {
+		let mut statistics = Statistics::new(Level::One);
+		statistics.record_player_action(&PlayerAction::KillCommonBeast(Coord { column: 0, row: 0 }));
+		statistics.finish_level(Duration::from_secs(42), 7);
+		statistics.add_score(5);
+
+		statistics.start_level(Level::Two);
+		statistics.record_life_lost();
+		statistics.finish_level(Duration::from_secs(66), 0);
+
+		assert_eq!(statistics.levels.len(), 2, "Each started level should have its own entry");
+		assert_eq!(statistics.levels[0].level, Level::One, "The first entry should be level one");
+		assert_eq!(statistics.levels[0].time_taken, Duration::from_secs(42), "The time taken should be recorded");
+		assert_eq!(statistics.levels[0].time_bonus, 7, "The time bonus should be recorded");
+		assert_eq!(
+			statistics.levels[0].score,
+			CommonBeast::get_score() + 7 + 5,
+			"The score should include kills, time bonus and completion score"
+		);
+		assert_eq!(statistics.current().level, Level::Two, "The current level should be the last level started");
+		assert_eq!(statistics.current().lives_lost, 1, "The lives lost should only count towards the current level");
+		assert_eq!(statistics.current().score, 0, "The score of the second level should start from zero");
+	}
+}
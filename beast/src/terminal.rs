@@ -0,0 +1,304 @@
+//! this module contains helper functions for raw mode and terminal size using termios
+
+use std::{
+	fs::File,
+	io::{self, Write},
+	os::{
+		fd::AsRawFd,
+		raw::{c_int, c_uchar, c_uint, c_ulong, c_ushort, c_void},
+	},
+	panic,
+	sync::{
+		OnceLock,
+		atomic::{AtomicBool, AtomicU64, Ordering},
+	},
+	time::{Duration, Instant},
+};
+
+#[cfg(target_os = "macos")]
+type TcFlag = c_ulong;
+#[cfg(not(target_os = "macos"))]
+type TcFlag = c_uint;
+
+#[cfg(target_os = "macos")]
+const NCCS: usize = 20;
+#[cfg(not(target_os = "macos"))]
+const NCCS: usize = 32;
+
+#[cfg(target_os = "macos")]
+const ICANON: TcFlag = 0x100;
+#[cfg(not(target_os = "macos"))]
+const ICANON: TcFlag = 0o2;
+
+#[cfg(target_os = "macos")]
+const ECHO: TcFlag = 0x8;
+#[cfg(not(target_os = "macos"))]
+const ECHO: TcFlag = 0o10;
+
+#[cfg(target_os = "macos")]
+const VMIN: usize = 16;
+#[cfg(not(target_os = "macos"))]
+const VMIN: usize = 6;
+
+#[cfg(target_os = "macos")]
+const VTIME: usize = 17;
+#[cfg(not(target_os = "macos"))]
+const VTIME: usize = 5;
+
+#[cfg(target_os = "macos")]
+const TIOCGWINSZ: c_ulong = 0x40087468;
+#[cfg(not(target_os = "macos"))]
+const TIOCGWINSZ: c_ulong = 0x5413;
+
+#[cfg(target_os = "macos")]
+const SIGTSTP: c_int = 18;
+#[cfg(not(target_os = "macos"))]
+const SIGTSTP: c_int = 20;
+
+#[cfg(target_os = "macos")]
+const SIGCONT: c_int = 19;
+#[cfg(not(target_os = "macos"))]
+const SIGCONT: c_int = 18;
+
+const STDIN_FILENO: c_int = 0;
+const STDOUT_FILENO: c_int = 1;
+const TCSANOW: c_int = 0;
+const SIGHUP: c_int = 1;
+const SIGINT: c_int = 2;
+const SIGTERM: c_int = 15;
+const SIGWINCH: c_int = 28;
+/// the default action of a signal as passed to `signal`
+const SIG_DFL: usize = 0;
+
+/// the escape sequences we print when entering raw mode
+const ENTER_SEQUENCE: &[u8] = b"\x1b[?25l\x1b[?2004h"; // hide cursor and enable bracketed paste
+/// the escape sequences that undo what we print when entering raw mode
+const RESET_SEQUENCE: &[u8] = b"\x1b[?2004l\x1b[?25h"; // disable bracketed paste and show cursor again
+/// the escape sequence that switches to the alternate screen buffer and saves the cursor
+const ENTER_ALTERNATE_SCREEN: &[u8] = b"\x1b[?1049h";
+/// the escape sequence that restores the normal screen buffer with the contents from before we started
+const LEAVE_ALTERNATE_SCREEN: &[u8] = b"\x1b[?1049l";
+
+/// the terminal attributes as defined in `termios.h`
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct Termios {
+	c_iflag: TcFlag,
+	c_oflag: TcFlag,
+	c_cflag: TcFlag,
+	c_lflag: TcFlag,
+	#[cfg(not(target_os = "macos"))]
+	c_line: c_uchar,
+	c_cc: [c_uchar; NCCS],
+	c_ispeed: TcFlag,
+	c_ospeed: TcFlag,
+}
+
+/// the terminal size as defined in `sys/ioctl.h`
+#[repr(C)]
+#[derive(Debug, Default)]
+struct WinSize {
+	ws_row: c_ushort,
+	ws_col: c_ushort,
+	ws_xpixel: c_ushort,
+	ws_ypixel: c_ushort,
+}
+
+unsafe extern "C" {
+	fn signal(sig: c_int, handler: usize) -> usize;
+	fn raise(sig: c_int) -> c_int;
+	fn tcgetattr(fd: c_int, termios: *mut Termios) -> c_int;
+	fn tcsetattr(fd: c_int, optional_actions: c_int, termios: *const Termios) -> c_int;
+	fn ioctl(fd: c_int, request: c_ulong, ...) -> c_int;
+	fn isatty(fd: c_int) -> c_int;
+	fn write(fd: c_int, buf: *const c_void, count: usize) -> isize;
+}
+
+/// the terminal attributes from before we entered raw mode, kept in a static so signal handlers can reach them
+static ORIGINAL_TERMIOS: OnceLock<Termios> = OnceLock::new();
+/// the terminal attributes of raw mode so we can enter it again after being suspended
+static RAW_TERMIOS: OnceLock<Termios> = OnceLock::new();
+/// if we draw on the alternate screen so we know to switch back to it after being suspended
+static USES_ALTERNATE_SCREEN: AtomicBool = AtomicBool::new(false);
+/// if the terminal currently shows the alternate screen
+static ON_ALTERNATE_SCREEN: AtomicBool = AtomicBool::new(false);
+/// set by the signal handlers whenever the terminal was resized or we were resumed after being suspended
+static NEEDS_REDRAW: AtomicBool = AtomicBool::new(false);
+/// the point in time suspension times are measured from
+static SIGNAL_EPOCH: OnceLock<Instant> = OnceLock::new();
+/// the milliseconds since [SIGNAL_EPOCH] plus one at which we were suspended, zero if we weren't
+static SUSPENDED_AT: AtomicU64 = AtomicU64::new(0);
+
+/// the raw mode struct uses the drop trait to restore the terminal state into cooked mode
+pub struct RawMode;
+
+impl RawMode {
+	/// this method enters the terminal into raw mode, input that isn't a terminal is left alone
+	pub fn enter() -> io::Result<Self> {
+		if unsafe { isatty(STDIN_FILENO) } == 1 {
+			let mut termios = unsafe { std::mem::zeroed::<Termios>() };
+			if unsafe { tcgetattr(STDIN_FILENO, &mut termios) } != 0 {
+				return Err(io::Error::last_os_error());
+			}
+			let original = *ORIGINAL_TERMIOS.get_or_init(|| termios);
+
+			let raw = RAW_TERMIOS.get_or_init(|| {
+				let mut raw = original;
+				raw.c_lflag &= !(ICANON | ECHO);
+				raw.c_cc[VMIN] = 1;
+				raw.c_cc[VTIME] = 0;
+				raw
+			});
+			if unsafe { tcsetattr(STDIN_FILENO, TCSANOW, raw) } != 0 {
+				return Err(io::Error::last_os_error());
+			}
+		}
+
+		io::stdout().write_all(ENTER_SEQUENCE)?;
+		Ok(Self)
+	}
+}
+
+impl Drop for RawMode {
+	/// this method restores the terminal state into cooked mode
+	fn drop(&mut self) {
+		let _ = io::stdout().flush();
+		restore_terminal();
+	}
+}
+
+/// switch to the alternate screen buffer so the terminal contents of the user are restored once we leave
+pub fn enter_alternate_screen() {
+	USES_ALTERNATE_SCREEN.store(true, Ordering::SeqCst);
+	if !ON_ALTERNATE_SCREEN.swap(true, Ordering::SeqCst) {
+		write_raw(ENTER_ALTERNATE_SCREEN);
+	}
+}
+
+/// go back to the normal screen buffer, this only makes async-signal-safe calls so it can run in signal handlers
+pub fn leave_alternate_screen() {
+	if ON_ALTERNATE_SCREEN.swap(false, Ordering::SeqCst) {
+		write_raw(LEAVE_ALTERNATE_SCREEN);
+	}
+}
+
+fn write_raw(bytes: &[u8]) {
+	unsafe {
+		write(STDOUT_FILENO, bytes.as_ptr().cast(), bytes.len());
+	}
+}
+
+/// restore the original terminal attributes, this only makes async-signal-safe calls so it can run in signal handlers
+fn restore_terminal() {
+	leave_alternate_screen();
+	if let Some(original) = ORIGINAL_TERMIOS.get() {
+		unsafe {
+			tcsetattr(STDIN_FILENO, TCSANOW, original);
+		}
+	}
+	write_raw(RESET_SEQUENCE);
+}
+
+/// enter raw mode again after we were suspended, this only makes async-signal-safe calls
+fn reenter_raw_mode() {
+	if let Some(raw) = RAW_TERMIOS.get() {
+		unsafe {
+			tcsetattr(STDIN_FILENO, TCSANOW, raw);
+		}
+		write_raw(ENTER_SEQUENCE);
+	}
+	if USES_ALTERNATE_SCREEN.load(Ordering::SeqCst) && !ON_ALTERNATE_SCREEN.swap(true, Ordering::SeqCst) {
+		write_raw(ENTER_ALTERNATE_SCREEN);
+	}
+}
+
+extern "C" fn handle_exit_signal(sig: c_int) {
+	restore_terminal();
+
+	// the process ends with the default action once we return so whoever sent the signal sees it killed us
+	unsafe {
+		signal(sig, SIG_DFL);
+		raise(sig);
+	}
+}
+
+extern "C" fn handle_sigwinch(_sig: c_int) {
+	NEEDS_REDRAW.store(true, Ordering::SeqCst);
+}
+
+extern "C" fn handle_sigtstp(_sig: c_int) {
+	if let Some(epoch) = SIGNAL_EPOCH.get() {
+		SUSPENDED_AT.store(epoch.elapsed().as_millis() as u64 + 1, Ordering::SeqCst);
+	}
+	restore_terminal();
+
+	// the signal is blocked while we're in its handler so the process stops with the default action once we return
+	unsafe {
+		signal(SIGTSTP, SIG_DFL);
+		raise(SIGTSTP);
+	}
+}
+
+extern "C" fn handle_sigcont(_sig: c_int) {
+	unsafe {
+		signal(SIGTSTP, handle_sigtstp as extern "C" fn(c_int) as usize);
+	}
+	reenter_raw_mode();
+	NEEDS_REDRAW.store(true, Ordering::SeqCst);
+}
+
+/// this method installs signal handlers that restore the terminal state into cooked mode on SIGINT, SIGTERM and SIGHUP,
+/// suspend and resume with job control and record terminal resizes
+pub fn install_raw_mode_signal_handler() {
+	SIGNAL_EPOCH.get_or_init(Instant::now);
+
+	unsafe {
+		for sig in [SIGINT, SIGTERM, SIGHUP] {
+			signal(sig, handle_exit_signal as extern "C" fn(c_int) as usize);
+		}
+		signal(SIGWINCH, handle_sigwinch as extern "C" fn(c_int) as usize);
+		signal(SIGTSTP, handle_sigtstp as extern "C" fn(c_int) as usize);
+		signal(SIGCONT, handle_sigcont as extern "C" fn(c_int) as usize);
+	}
+}
+
+/// check if the screen has to be redrawn because the terminal was resized or we were resumed since the last time we checked
+pub fn take_redraw() -> bool {
+	NEEDS_REDRAW.swap(false, Ordering::SeqCst)
+}
+
+/// how long we were suspended for if we were resumed since the last time we checked
+pub fn take_suspension() -> Option<Duration> {
+	let suspended_at = SUSPENDED_AT.swap(0, Ordering::SeqCst);
+	let epoch = SIGNAL_EPOCH.get()?;
+	if suspended_at == 0 {
+		return None;
+	}
+
+	Some(epoch.elapsed().saturating_sub(Duration::from_millis(suspended_at - 1)))
+}
+
+/// this method installs a panic hook that restores the terminal state before the panic message is printed
+pub fn install_panic_hook() {
+	let default_hook = panic::take_hook();
+	panic::set_hook(Box::new(move |info| {
+		let _ = io::stdout().flush();
+		restore_terminal();
+		default_hook(info);
+	}));
+}
+
+/// a function to get the terminal size
+pub fn terminal_size() -> io::Result<(usize, usize)> {
+	let tty = File::open("/dev/tty")?;
+	let mut size = WinSize::default();
+
+	if unsafe { ioctl(tty.as_raw_fd(), TIOCGWINSZ, &mut size as *mut WinSize) } != 0 {
+		return Err(io::Error::last_os_error());
+	}
+	if size.ws_col == 0 || size.ws_row == 0 {
+		return Err(io::Error::other("the terminal reported a size of zero"));
+	}
+
+	Ok((size.ws_col as usize, size.ws_row as usize))
+}
@@ -0,0 +1,667 @@
+//! this module contains the colour themes and glyphs everything is drawn with
+
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, io, path::Path, sync::OnceLock};
+
+use crate::{
+	Tile,
+	capabilities::{self, ColorDepth},
+	visible_width, xdg,
+};
+
+/// the folder inside our config directory theme files are read from
+const THEME_DIR: &str = "themes";
+
+/// the theme everything is drawn with, falls back to the classic theme until one is set
+static THEME: OnceLock<Theme> = OnceLock::new();
+/// the RGB values of the 16 colours of the terminal palette as xterm defines them
+const ANSI_PALETTE: [(u8, u8, u8); 16] = [
+	(0, 0, 0),
+	(205, 0, 0),
+	(0, 205, 0),
+	(205, 205, 0),
+	(0, 0, 238),
+	(205, 0, 205),
+	(0, 205, 205),
+	(229, 229, 229),
+	(127, 127, 127),
+	(255, 0, 0),
+	(0, 255, 0),
+	(255, 255, 0),
+	(92, 92, 255),
+	(255, 0, 255),
+	(0, 255, 255),
+	(255, 255, 255),
+];
+/// the levels of each channel in the 6x6x6 colour cube of the 256 colour palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+/// the glyphs tiles fall back to on terminals without unicode in the order of [Theme::tile_styles]
+const ASCII_GLYPHS: [&str; 9] = ["  ", "[]", "##", "<>", "%%", "&&", "oo", "OO", "$$"];
+
+/// the errors that can happen while loading a theme
+#[derive(Debug)]
+pub enum ThemeError {
+	/// the theme is neither built-in nor a file in our config directory
+	NotFound(String),
+	/// the theme file exists but could not be read
+	Read(io::Error),
+	/// the theme file could not be parsed
+	Parse(ron::error::SpannedError),
+	/// a glyph does not take exactly two columns in the terminal
+	Glyph(String),
+}
+
+impl fmt::Display for ThemeError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			ThemeError::NotFound(name) => write!(f, "The theme {name} does not exist"),
+			ThemeError::Read(error) => write!(f, "Failed to read theme file: {error}"),
+			ThemeError::Parse(error) => write!(f, "Failed to parse theme file: {error}"),
+			ThemeError::Glyph(glyph) => write!(f, "The glyph \"{glyph}\" has to be two columns wide"),
+		}
+	}
+}
+
+/// a colour as it's written into theme files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Color {
+	/// the default colour of the terminal
+	#[default]
+	Default,
+	/// one of the 16 colours of the terminal palette, 0 to 7 are the normal and 8 to 15 the bright ones
+	Ansi(u8),
+	/// one of the 256 colours of the extended palette
+	Indexed(u8),
+	/// a true colour
+	Rgb(u8, u8, u8),
+}
+
+impl Color {
+	/// the escape sequence that sets this colour as the foreground, empty for the default colour
+	pub fn fg(&self) -> String {
+		match self {
+			Color::Default => String::new(),
+			Color::Ansi(index @ 0..=7) => format!("\x1b[{}m", 30 + index),
+			Color::Ansi(index) => format!("\x1b[{}m", 90 + (index & 7)),
+			Color::Indexed(index) => format!("\x1b[38;5;{index}m"),
+			Color::Rgb(red, green, blue) => format!("\x1b[38;2;{red};{green};{blue}m"),
+		}
+	}
+
+	/// the escape sequence that sets this colour as the background, empty for the default colour
+	pub fn bg(&self) -> String {
+		match self {
+			Color::Default => String::new(),
+			Color::Ansi(index @ 0..=7) => format!("\x1b[{}m", 40 + index),
+			Color::Ansi(index) => format!("\x1b[{}m", 100 + (index & 7)),
+			Color::Indexed(index) => format!("\x1b[48;5;{index}m"),
+			Color::Rgb(red, green, blue) => format!("\x1b[48;2;{red};{green};{blue}m"),
+		}
+	}
+
+	/// the closest colour a terminal with the given colour depth can display
+	pub fn downgrade(self, depth: ColorDepth) -> Self {
+		match (self, depth) {
+			(_, ColorDepth::None) => Color::Default,
+			(Color::Rgb(red, green, blue), ColorDepth::Ansi256) => Color::Indexed(Self::closest_indexed(red, green, blue)),
+			(Color::Indexed(index @ 0..=15), ColorDepth::Ansi16) => Color::Ansi(index),
+			(Color::Indexed(_) | Color::Rgb(..), ColorDepth::Ansi16) => {
+				let (red, green, blue) = self.rgb();
+				Color::Ansi(Self::closest(&ANSI_PALETTE, (red, green, blue)) as u8)
+			},
+			(color, _) => color,
+		}
+	}
+
+	/// the RGB values of the colour, the default colour is treated as black
+	fn rgb(&self) -> (u8, u8, u8) {
+		match *self {
+			Color::Default => (0, 0, 0),
+			Color::Ansi(index) => ANSI_PALETTE[(index & 15) as usize],
+			Color::Indexed(index @ 0..=15) => ANSI_PALETTE[index as usize],
+			Color::Indexed(index @ 16..=231) => {
+				let index = (index - 16) as usize;
+				(CUBE_LEVELS[index / 36], CUBE_LEVELS[(index / 6) % 6], CUBE_LEVELS[index % 6])
+			},
+			Color::Indexed(index) => {
+				let level = 8 + (index - 232) * 10;
+				(level, level, level)
+			},
+			Color::Rgb(red, green, blue) => (red, green, blue),
+		}
+	}
+
+	/// the index of the closest colour of the 256 colour palette leaving out the 16 colours which vary by terminal
+	fn closest_indexed(red: u8, green: u8, blue: u8) -> u8 {
+		let candidates = (16..=255).map(|index| Color::Indexed(index).rgb()).collect::<Vec<(u8, u8, u8)>>();
+		16 + Self::closest(&candidates, (red, green, blue)) as u8
+	}
+
+	/// the position of the colour closest to the target
+	fn closest(colors: &[(u8, u8, u8)], target: (u8, u8, u8)) -> usize {
+		let distance = |(red, green, blue): (u8, u8, u8)| {
+			(red as i32 - target.0 as i32).pow(2)
+				+ (green as i32 - target.1 as i32).pow(2)
+				+ (blue as i32 - target.2 as i32).pow(2)
+		};
+		(0..colors.len()).min_by_key(|&index| distance(colors[index])).unwrap_or_default()
+	}
+
+	/// wrap text in this colour and reset the foreground afterwards
+	pub fn paint(&self, text: &str) -> String {
+		match self {
+			Color::Default => text.to_string(),
+			_ => format!("{}{text}\x1b[39m", self.fg()),
+		}
+	}
+}
+
+/// the look of a tile on the board
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TileStyle {
+	/// the two columns of text the tile is drawn with
+	pub glyph: String,
+	/// the foreground colour
+	#[serde(default)]
+	pub fg: Color,
+	/// the background colour
+	#[serde(default)]
+	pub bg: Color,
+}
+
+impl TileStyle {
+	fn new(glyph: &str, fg: Color) -> Self {
+		Self {
+			glyph: glyph.to_string(),
+			fg,
+			bg: Color::Default,
+		}
+	}
+
+	fn with_bg(mut self, bg: Color) -> Self {
+		self.bg = bg;
+		self
+	}
+}
+
+impl fmt::Display for TileStyle {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}{}{}", self.fg.fg(), self.bg.bg(), self.glyph)?;
+		if self.fg != Color::Default {
+			write!(f, "\x1b[39m")?;
+		}
+		if self.bg != Color::Default {
+			write!(f, "\x1b[49m")?;
+		}
+		Ok(())
+	}
+}
+
+/// the look of every tile on the board
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TileStyles {
+	/// empty space
+	pub empty: TileStyle,
+	/// a block
+	pub block: TileStyle,
+	/// an immovable block
+	pub static_block: TileStyle,
+	/// the player
+	pub player: TileStyle,
+	/// a common beast
+	pub common_beast: TileStyle,
+	/// a super beast
+	pub super_beast: TileStyle,
+	/// an egg
+	pub egg: TileStyle,
+	/// an egg that is about to hatch
+	pub egg_hatching: TileStyle,
+	/// a hatched beast
+	pub hatched_beast: TileStyle,
+}
+
+impl Default for TileStyles {
+	fn default() -> Self {
+		Theme::classic().tiles
+	}
+}
+
+/// the colours of the text and effects around the board
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiColors {
+	/// secondary text like table headers
+	pub muted: Color,
+	/// text that should barely be visible like dates and progress bars
+	pub faint: Color,
+	/// text that should stand out like the sync indicator
+	pub highlight: Color,
+	/// text that warns like the timer running out
+	pub warning: Color,
+	/// the background of every other row in tables
+	pub row_bg: Color,
+	/// the background the board flashes in when the player dies
+	pub death_flash: Color,
+	/// the background the death flash fades out with
+	pub death_fading_flash: Color,
+	/// the background the board flashes in when a beast is killed
+	pub kill_flash: Color,
+}
+
+impl Default for UiColors {
+	fn default() -> Self {
+		Theme::classic().ui
+	}
+}
+
+/// a colour theme with the glyphs of the tiles
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+	/// the tiles on the board
+	pub tiles: TileStyles,
+	/// the frame around the board and every other screen
+	pub frame: Color,
+	/// the text and effects around the board
+	pub ui: UiColors,
+}
+
+impl Default for Theme {
+	fn default() -> Self {
+		Self::classic()
+	}
+}
+
+impl Theme {
+	/// the colours of the original game
+	pub fn classic() -> Self {
+		Self {
+			tiles: TileStyles {
+				empty: TileStyle::new("  ", Color::Default),
+				block: TileStyle::new("░░", Color::Ansi(2)),
+				static_block: TileStyle::new("▓▓", Color::Ansi(3)),
+				player: TileStyle::new("◀▶", Color::Ansi(6)),
+				common_beast: TileStyle::new("├┤", Color::Ansi(1)),
+				super_beast: TileStyle::new("╟╢", Color::Ansi(1)),
+				egg: TileStyle::new("○○", Color::Ansi(1)),
+				egg_hatching: TileStyle::new("○○", Color::Ansi(5)),
+				hatched_beast: TileStyle::new("╬╬", Color::Ansi(1)),
+			},
+			frame: Color::Ansi(3),
+			ui: UiColors {
+				muted: Color::Indexed(241),
+				faint: Color::Indexed(239),
+				highlight: Color::Ansi(3),
+				warning: Color::Ansi(1),
+				row_bg: Color::Indexed(233),
+				death_flash: Color::Indexed(196),
+				death_fading_flash: Color::Indexed(208),
+				kill_flash: Color::Rgb(51, 51, 51),
+			},
+		}
+	}
+
+	/// bright colours on a dark background with the player and eggs set apart by their background
+	pub fn high_contrast() -> Self {
+		Self {
+			tiles: TileStyles {
+				empty: TileStyle::new("  ", Color::Default),
+				block: TileStyle::new("░░", Color::Ansi(10)),
+				static_block: TileStyle::new("██", Color::Ansi(15)),
+				player: TileStyle::new("◀▶", Color::Ansi(0)).with_bg(Color::Ansi(14)),
+				common_beast: TileStyle::new("├┤", Color::Ansi(9)),
+				super_beast: TileStyle::new("╟╢", Color::Ansi(13)),
+				egg: TileStyle::new("○○", Color::Ansi(11)),
+				egg_hatching: TileStyle::new("◉◉", Color::Ansi(0)).with_bg(Color::Ansi(11)),
+				hatched_beast: TileStyle::new("╬╬", Color::Ansi(9)),
+			},
+			frame: Color::Ansi(15),
+			ui: UiColors {
+				muted: Color::Ansi(7),
+				faint: Color::Ansi(7),
+				highlight: Color::Ansi(11),
+				warning: Color::Ansi(9),
+				row_bg: Color::Indexed(236),
+				death_flash: Color::Ansi(1),
+				death_fading_flash: Color::Ansi(3),
+				kill_flash: Color::Indexed(238),
+			},
+		}
+	}
+
+	/// the Okabe-Ito palette which stays distinguishable for deuteranopia and protanopia
+	pub fn colorblind() -> Self {
+		let blue = Color::Rgb(0, 114, 178);
+		let sky_blue = Color::Rgb(86, 180, 233);
+		let orange = Color::Rgb(230, 159, 0);
+		let vermillion = Color::Rgb(213, 94, 0);
+		let yellow = Color::Rgb(240, 228, 66);
+		let purple = Color::Rgb(204, 121, 167);
+
+		Self {
+			tiles: TileStyles {
+				empty: TileStyle::new("  ", Color::Default),
+				block: TileStyle::new("░░", sky_blue),
+				static_block: TileStyle::new("▓▓", blue),
+				player: TileStyle::new("◀▶", yellow),
+				common_beast: TileStyle::new("├┤", orange),
+				super_beast: TileStyle::new("╟╢", vermillion),
+				egg: TileStyle::new("○○", purple),
+				egg_hatching: TileStyle::new("◉◉", purple),
+				hatched_beast: TileStyle::new("╬╬", vermillion),
+			},
+			frame: blue,
+			ui: UiColors {
+				muted: Color::Indexed(245),
+				faint: Color::Indexed(241),
+				highlight: yellow,
+				warning: orange,
+				row_bg: Color::Indexed(234),
+				death_flash: vermillion,
+				death_fading_flash: orange,
+				kill_flash: Color::Rgb(51, 51, 51),
+			},
+		}
+	}
+
+	/// find a built-in theme by its name
+	pub fn builtin(name: &str) -> Option<Self> {
+		match name.to_ascii_lowercase().as_str() {
+			"default" | "classic" => Some(Self::classic()),
+			"high-contrast" | "high_contrast" => Some(Self::high_contrast()),
+			"colorblind" | "colourblind" | "deuteranopia" | "protanopia" => Some(Self::colorblind()),
+			_ => None,
+		}
+	}
+
+	/// load a built-in theme or the theme file `~/.config/beast/themes/<name>.ron`
+	pub fn load(name: &str) -> Result<Self, ThemeError> {
+		if let Some(theme) = Self::builtin(name) {
+			return Ok(theme);
+		}
+
+		match xdg::config_dir() {
+			Some(dir) => Self::from_file(&dir.join(THEME_DIR).join(format!("{name}.ron"))).map_err(|error| match error {
+				ThemeError::Read(error) if error.kind() == io::ErrorKind::NotFound => ThemeError::NotFound(name.to_string()),
+				error => error,
+			}),
+			None => Err(ThemeError::NotFound(name.to_string())),
+		}
+	}
+
+	/// read a theme file, missing fields fall back to the classic theme
+	pub fn from_file(path: &Path) -> Result<Self, ThemeError> {
+		Self::ron_from_str(&fs::read_to_string(path).map_err(ThemeError::Read)?)
+	}
+
+	/// parse the content of a theme file
+	pub fn ron_from_str(content: &str) -> Result<Self, ThemeError> {
+		let theme = ron::from_str::<Self>(content).map_err(ThemeError::Parse)?;
+
+		for style in theme.tile_styles() {
+			if style.glyph.chars().any(char::is_control) || visible_width(&style.glyph) != 2 {
+				return Err(ThemeError::Glyph(style.glyph.clone()));
+			}
+		}
+
+		Ok(theme)
+	}
+
+	/// bring every colour down to what a terminal with the given colour depth can display
+	pub fn with_color_depth(mut self, depth: ColorDepth) -> Self {
+		for style in self.tile_styles_mut() {
+			style.fg = style.fg.downgrade(depth);
+			style.bg = style.bg.downgrade(depth);
+		}
+		self.frame = self.frame.downgrade(depth);
+		let ui = &mut self.ui;
+		for color in [
+			&mut ui.muted,
+			&mut ui.faint,
+			&mut ui.highlight,
+			&mut ui.warning,
+			&mut ui.row_bg,
+			&mut ui.death_flash,
+			&mut ui.death_fading_flash,
+			&mut ui.kill_flash,
+		] {
+			*color = color.downgrade(depth);
+		}
+
+		// eggs about to hatch would only differ from other eggs by their colour
+		if depth == ColorDepth::None && self.tiles.egg_hatching.glyph == self.tiles.egg.glyph {
+			self.tiles.egg_hatching.glyph = String::from("◉◉");
+		}
+		self
+	}
+
+	/// replace glyphs that aren't ASCII for terminals that can't display them
+	pub fn with_ascii_glyphs(mut self) -> Self {
+		for (style, glyph) in self.tile_styles_mut().into_iter().zip(ASCII_GLYPHS) {
+			if !style.glyph.is_ascii() {
+				style.glyph = glyph.to_string();
+			}
+		}
+		self
+	}
+
+	/// the look of a tile
+	pub fn tile(&self, tile: &Tile) -> &TileStyle {
+		match tile {
+			Tile::Empty => &self.tiles.empty,
+			Tile::Block => &self.tiles.block,
+			Tile::StaticBlock => &self.tiles.static_block,
+			Tile::Player => &self.tiles.player,
+			Tile::CommonBeast => &self.tiles.common_beast,
+			Tile::SuperBeast => &self.tiles.super_beast,
+			Tile::Egg(_) => &self.tiles.egg,
+			Tile::EggHatching(_) => &self.tiles.egg_hatching,
+			Tile::HatchedBeast => &self.tiles.hatched_beast,
+		}
+	}
+
+	fn tile_styles(&self) -> [&TileStyle; 9] {
+		let tiles = &self.tiles;
+		[
+			&tiles.empty,
+			&tiles.block,
+			&tiles.static_block,
+			&tiles.player,
+			&tiles.common_beast,
+			&tiles.super_beast,
+			&tiles.egg,
+			&tiles.egg_hatching,
+			&tiles.hatched_beast,
+		]
+	}
+
+	fn tile_styles_mut(&mut self) -> [&mut TileStyle; 9] {
+		let tiles = &mut self.tiles;
+		[
+			&mut tiles.empty,
+			&mut tiles.block,
+			&mut tiles.static_block,
+			&mut tiles.player,
+			&mut tiles.common_beast,
+			&mut tiles.super_beast,
+			&mut tiles.egg,
+			&mut tiles.egg_hatching,
+			&mut tiles.hatched_beast,
+		]
+	}
+}
+
+/// the left or right edge of the frame drawn in the frame colour of the current theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Border {
+	/// the left edge `▌`
+	Left,
+	/// the right edge `▐`
+	Right,
+}
+
+impl fmt::Display for Border {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let glyph = match self {
+			Border::Left => "▌",
+			Border::Right => "▐",
+		};
+		write!(f, "{}", current().frame.paint(glyph))
+	}
+}
+
+/// load the theme by its name, fit it to the capabilities of the terminal and make it the current theme
+pub fn init(name: &str) -> Result<(), ThemeError> {
+	let capabilities = capabilities::current();
+	let theme = Theme::load(name)?.with_color_depth(capabilities.colors);
+	let _ = THEME.set(if capabilities.unicode {
+		theme
+	} else {
+		theme.with_ascii_glyphs()
+	});
+	Ok(())
+}
+
+/// the theme everything is drawn with
+pub fn current() -> &'static Theme {
+	THEME.get_or_init(Theme::classic)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn color_test() {
+		assert_eq!(Color::Ansi(2).fg(), "\x1b[32m", "Normal colours should use the 30 range");
+		assert_eq!(Color::Ansi(9).fg(), "\x1b[91m", "Bright colours should use the 90 range");
+		assert_eq!(Color::Ansi(12).bg(), "\x1b[104m", "Bright backgrounds should use the 100 range");
+		assert_eq!(Color::Indexed(233).bg(), "\x1b[48;5;233m", "Indexed colours should use the 256 palette");
+		assert_eq!(Color::Rgb(51, 51, 51).bg(), "\x1b[48;2;51;51;51m", "Rgb colours should use true colour");
+		assert_eq!(Color::Default.fg(), "", "The default colour should not emit anything");
+		assert_eq!(Color::Default.paint("▌"), "▌", "Painting in the default colour should leave the text alone");
+	}
+
+	#[test]
+	fn classic_tiles_test() {
+		let theme = Theme::classic();
+		assert_eq!(theme.tile(&Tile::Block).to_string(), "\x1b[32m░░\x1b[39m", "Blocks should be green");
+		assert_eq!(theme.tile(&Tile::Empty).to_string(), "  ", "Empty tiles should not emit any colour");
+		assert_eq!(
+			Theme::high_contrast().tile(&Tile::Player).to_string(),
+			"\x1b[30m\x1b[106m◀▶\x1b[39m\x1b[49m",
+			"Backgrounds should be set and reset"
+		);
+	}
+
+	#[test]
+	fn builtin_test() {
+		assert_eq!(Theme::builtin("default"), Some(Theme::classic()), "Default should be the classic theme");
+		assert_eq!(Theme::builtin("High-Contrast"), Some(Theme::high_contrast()), "Names should be case-insensitive");
+		assert_eq!(Theme::builtin("protanopia"), Some(Theme::colorblind()), "Protanopia should be colour blind safe");
+		assert_eq!(Theme::builtin("neon"), None, "Unknown themes should not be built-in");
+		for theme in [Theme::classic(), Theme::high_contrast(), Theme::colorblind()] {
+			assert!(
+				theme.tile_styles().iter().all(|style| visible_width(&style.glyph) == 2),
+				"Every built-in glyph should be two columns wide"
+			);
+		}
+	}
+
+	#[test]
+	fn ron_from_str_test() {
+		let theme = Theme::ron_from_str(
+			r#"(tiles: (player: (glyph: "@@", fg: Rgb(255, 0, 0))), frame: Indexed(21), ui: (warning: Ansi(5)))"#,
+		)
+		.expect("Failed to parse theme");
+
+		assert_eq!(theme.tiles.player.glyph, "@@", "The glyph should be read from the file");
+		assert_eq!(theme.tiles.player.fg, Color::Rgb(255, 0, 0), "The colour should be read from the file");
+		assert_eq!(theme.frame, Color::Indexed(21), "The frame should be read from the file");
+		assert_eq!(theme.ui.warning, Color::Ansi(5), "The ui colours should be read from the file");
+		assert_eq!(theme.tiles.block, Theme::classic().tiles.block, "Missing tiles should fall back to classic");
+		assert_eq!(theme.ui.row_bg, Theme::classic().ui.row_bg, "Missing colours should fall back to classic");
+	}
+
+	#[test]
+	fn ron_from_str_invalid_glyph_test() {
+		assert!(
+			matches!(Theme::ron_from_str(r##"(tiles: (block: (glyph: "#")))"##), Err(ThemeError::Glyph(_))),
+			"Glyphs that aren't two columns wide should error"
+		);
+		assert!(
+			matches!(Theme::ron_from_str(r#"(tiles: (block: (glyph: "🐍🐍")))"#), Err(ThemeError::Glyph(_))),
+			"Two wide characters take four columns and should error"
+		);
+		assert!(
+			matches!(Theme::ron_from_str(r#"(tiles: (block: (glyph: "e\u{301}")))"#), Err(ThemeError::Glyph(_))),
+			"Combining characters don't take a column so two characters can still be too narrow"
+		);
+		assert!(
+			Theme::ron_from_str(r#"(tiles: (block: (glyph: "🐍")))"#).is_ok(),
+			"A single wide character takes two columns and should be allowed"
+		);
+		assert!(
+			matches!(Theme::ron_from_str(r#"(tiles: (block: (glyph: "\u{1b}[")))"#), Err(ThemeError::Glyph(_))),
+			"Glyphs with control characters should error"
+		);
+	}
+
+	#[test]
+	fn load_missing_test() {
+		assert!(
+			matches!(Theme::load("beast_missing_theme"), Err(ThemeError::NotFound(_))),
+			"Themes that don't exist should error"
+		);
+	}
+
+	#[test]
+	fn downgrade_test() {
+		assert_eq!(
+			Color::Rgb(86, 180, 233).downgrade(ColorDepth::Ansi256),
+			Color::Indexed(74),
+			"Rgb colours should become the closest indexed colour"
+		);
+		assert_eq!(
+			Color::Rgb(51, 51, 51).downgrade(ColorDepth::Ansi256),
+			Color::Indexed(236),
+			"Greys should use the grey ramp"
+		);
+		assert_eq!(Color::Indexed(196).downgrade(ColorDepth::Ansi16), Color::Ansi(9), "Indexed red should be bright red");
+		assert_eq!(Color::Indexed(3).downgrade(ColorDepth::Ansi16), Color::Ansi(3), "The basic colours should be kept");
+		assert_eq!(
+			Color::Rgb(230, 159, 0).downgrade(ColorDepth::Ansi16),
+			Color::Ansi(3),
+			"Rgb colours should become the closest palette colour"
+		);
+		assert_eq!(Color::Ansi(2).downgrade(ColorDepth::TrueColor), Color::Ansi(2), "Colours should never be upgraded");
+		assert_eq!(Color::Ansi(2).downgrade(ColorDepth::None), Color::Default, "No colours should be left");
+	}
+
+	#[test]
+	fn with_color_depth_test() {
+		let theme = Theme::classic().with_color_depth(ColorDepth::Ansi16);
+		assert_eq!(theme.ui.row_bg, Color::Ansi(0), "The row background should fit into 16 colours");
+		assert_eq!(theme.ui.death_flash, Color::Ansi(9), "The death flash should fit into 16 colours");
+
+		let theme = Theme::classic().with_color_depth(ColorDepth::None);
+		assert_eq!(theme.tile(&Tile::Player).to_string(), "◀▶", "Tiles should be drawn without colours");
+		assert_eq!(theme.tiles.egg_hatching.glyph, "◉◉", "Hatching eggs should get their own glyph");
+		assert_eq!(theme.ui.death_flash, Color::Default, "The flashes should lose their colour");
+		assert_eq!(theme.frame, Color::Default, "The frame should lose its colour");
+	}
+
+	#[test]
+	fn with_ascii_glyphs_test() {
+		let theme =
+			Theme::ron_from_str(r#"(tiles: (player: (glyph: "@@")))"#).expect("Failed to parse theme").with_ascii_glyphs();
+		assert_eq!(theme.tiles.player.glyph, "@@", "ASCII glyphs should be kept");
+		assert_eq!(theme.tiles.block.glyph, "[]", "Other glyphs should fall back to ASCII");
+		assert!(theme.tile_styles().iter().all(|style| style.glyph.is_ascii()), "Every glyph should be ASCII");
+		let mut glyphs = theme.tile_styles().iter().map(|style| style.glyph.clone()).collect::<Vec<String>>();
+		glyphs.sort();
+		glyphs.dedup();
+		assert_eq!(glyphs.len(), 9, "Every tile should keep its own glyph");
+	}
+}
@@ -0,0 +1,24 @@
+//! this module resolves the XDG base directories we keep our files in
+
+use std::{env, path::PathBuf};
+
+/// the name of the folder we create inside the XDG base directories
+const APP_DIR: &str = "beast";
+
+fn base_dir(xdg_var: &str, home_fallback: &str) -> Option<PathBuf> {
+	env::var_os(xdg_var)
+		.map(PathBuf::from)
+		.filter(|dir| dir.is_absolute())
+		.or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(home_fallback)))
+		.map(|dir| dir.join(APP_DIR))
+}
+
+/// the directory we store data like the local highscores in
+pub fn data_dir() -> Option<PathBuf> {
+	base_dir("XDG_DATA_HOME", ".local/share")
+}
+
+/// the directory we read the user config from
+pub fn config_dir() -> Option<PathBuf> {
+	base_dir("XDG_CONFIG_HOME", ".config")
+}
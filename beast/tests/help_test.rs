@@ -53,7 +53,7 @@ mod test {
 		let child_stdout = child_guard.child_mut().stdout.take().expect("Failed to open child's stdout");
 		let mut reader = BufReader::new(child_stdout);
 
-		let output = helper::get_output(&mut reader, 36);
+		let output = helper::get_output(&mut reader, 38);
 
 		assert!(
 			output.contains("Faithfully recreated from the work of"),
@@ -64,7 +64,7 @@ mod test {
 		child_stdin.write_all(b"h").expect("Failed to write 'h' to child's stdin");
 		child_stdin.flush().expect("Failed to flush stdin");
 		thread::sleep(Duration::from_millis(100));
-		let output = helper::get_output(&mut reader, 32);
+		let output = helper::get_output(&mut reader, 38);
 
 		assert!(output.contains("GENERAL"), "Should contain help page one heading in output:\n\"{output}\"");
 		assert!(output.contains("● ○ ○"), "Should contain help page one pagination in output:\n\"{output}\"");
@@ -73,7 +73,7 @@ mod test {
 		child_stdin.write_all(b"\x1B[C").expect("Failed to write '→' to child's stdin");
 		child_stdin.flush().expect("Failed to flush stdin");
 		thread::sleep(Duration::from_millis(100));
-		let output = helper::get_output(&mut reader, 17);
+		let output = helper::get_output(&mut reader, 38);
 
 		assert!(output.contains("ENEMIES"), "Should contain help page two heading in output:\n\"{output}\"");
 		assert!(output.contains("○ ● ○"), "Should contain help page two pagination in output:\n\"{output}\"");
@@ -82,7 +82,7 @@ mod test {
 		child_stdin.write_all(b"\x1B[C").expect("Failed to write '→' to child's stdin");
 		child_stdin.flush().expect("Failed to flush stdin");
 		thread::sleep(Duration::from_millis(100));
-		let output = helper::get_output(&mut reader, 17);
+		let output = helper::get_output(&mut reader, 38);
 
 		assert!(output.contains("SCORING"), "Should contain help page three heading in output:\n\"{output}\"");
 		assert!(output.contains("○ ○ ●"), "Should contain help page three pagination in output:\n\"{output}\"");
@@ -91,7 +91,7 @@ mod test {
 		child_stdin.write_all(b"\x1B[C").expect("Failed to write '→' to child's stdin");
 		child_stdin.flush().expect("Failed to flush stdin");
 		thread::sleep(Duration::from_millis(100));
-		let output = helper::get_output(&mut reader, 30);
+		let output = helper::get_output(&mut reader, 38);
 
 		assert!(output.contains("GENERAL"), "Should contain help page one heading in output:\n\"{output}\"");
 		assert!(output.contains("● ○ ○"), "Should contain help page one pagination in output:\n\"{output}\"");
@@ -100,7 +100,7 @@ mod test {
 		child_stdin.write_all(b"\x1B[D").expect("Failed to write '←' to child's stdin");
 		child_stdin.flush().expect("Failed to flush stdin");
 		thread::sleep(Duration::from_millis(100));
-		let output = helper::get_output(&mut reader, 17);
+		let output = helper::get_output(&mut reader, 38);
 
 		assert!(output.contains("SCORING"), "Should contain help page three heading in output:\n\"{output}\"");
 		assert!(output.contains("○ ○ ●"), "Should contain help page three pagination in output:\n\"{output}\"");
@@ -132,7 +132,7 @@ mod test {
 		let child_stdout = child_guard.child_mut().stdout.take().expect("Failed to open child's stdout");
 		let mut reader = BufReader::new(child_stdout);
 
-		let output = helper::get_output(&mut reader, 36);
+		let output = helper::get_output(&mut reader, 38);
 
 		assert!(
 			output.contains("Faithfully recreated from the work of"),
@@ -143,7 +143,7 @@ mod test {
 		child_stdin.write_all(b"h").expect("Failed to write 'h' to child's stdin");
 		child_stdin.flush().expect("Failed to flush stdin");
 		thread::sleep(Duration::from_millis(100));
-		let output = helper::get_output(&mut reader, 33);
+		let output = helper::get_output(&mut reader, 38);
 
 		assert!(output.contains("GENERAL"), "Should contain help page one heading in output:\n\"{output}\"");
 		assert!(output.contains("● ○ ○"), "Should contain help page one pagination in output:\n\"{output}\"");
@@ -152,7 +152,7 @@ mod test {
 		child_stdin.write_all(b" ").expect("Failed to write ' ' to child's stdin");
 		child_stdin.flush().expect("Failed to flush stdin");
 		thread::sleep(Duration::from_millis(300));
-		let output = helper::get_output(&mut reader, 33);
+		let output = helper::get_output(&mut reader, 38);
 
 		assert!(output.contains("Level:"), "Should contain level in footer in output:\n\"{output}\"");
 		assert!(output.contains("Beasts:"), "Should contain beasts in footer in output:\n\"{output}\"");
@@ -163,13 +163,13 @@ mod test {
 
 		// starting help
 		child_stdin.flush().expect("Failed to flush stdin");
-		let _output = helper::get_output(&mut reader, 33);
+		let _output = helper::get_output(&mut reader, 38);
 		child_stdin.flush().expect("Failed to flush stdin");
-		let _output = helper::get_output(&mut reader, 33);
+		let _output = helper::get_output(&mut reader, 38);
 		child_stdin.write_all(b"h").expect("Failed to write 'h' to child's stdin");
 		child_stdin.flush().expect("Failed to flush stdin");
 		thread::sleep(Duration::from_millis(100));
-		let output = helper::get_output(&mut reader, 31);
+		let output = helper::get_output(&mut reader, 38);
 
 		assert!(output.contains("GENERAL"), "Should contain help page one heading after game in output:\n\"{output}\"");
 		assert!(output.contains("● ○ ○"), "Should contain help page one pagination after game in output:\n\"{output}\"");
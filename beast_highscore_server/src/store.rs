@@ -1,6 +1,6 @@
 //! this module contains all logic to store and retrieve the highscore data
 
-use beast1984::common::{Highscore, Highscores, MAX_NAME_LENGTH, MAX_SCORES, Score};
+use beast1984::common::{Highscore, Highscores, MAX_NAME_LENGTH, Score};
 use ron::{de::from_str, ser::to_string};
 use std::{fs, path::PathBuf, sync::Arc};
 use tokio::sync::Mutex;
@@ -51,12 +51,7 @@ impl HighscoreStore {
 
 		{
 			let mut scores = self.inner.lock().await;
-			scores.scores.push(new_entry);
-
-			scores.scores.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.timestamp.cmp(&b.timestamp)));
-			if scores.scores.len() > MAX_SCORES {
-				scores.scores.truncate(MAX_SCORES);
-			}
+			scores.add(new_entry);
 
 			let ron_str = to_string(&*scores).map_err(HighscoreError::SerializationError)?;
 
@@ -71,7 +66,7 @@ impl HighscoreStore {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use beast1984::common::levels::Level;
+	use beast1984::common::{MAX_SCORES, levels::Level};
 
 	use crate::common::TempFile;
 